@@ -1,7 +1,558 @@
 use pagetop_build::StaticFilesBundle;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 fn main() -> std::io::Result<()> {
+    languages()?;
+    themes()?;
+
     StaticFilesBundle::from_dir("./static")
         .with_name("hljs")
         .build()
 }
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+}
+
+fn out_file(name: &str) -> std::io::Result<fs::File> {
+    fs::File::create(Path::new(&env::var("OUT_DIR").unwrap()).join(name))
+}
+
+// LANGUAGES ***************************************************************************************
+
+struct Language {
+    name: String,
+    variant: String,
+    display_name: Option<String>,
+    script: String,
+    requires: Vec<String>,
+    aliases: Vec<String>,
+    deprecated: Vec<String>,
+}
+
+// Overrides in `build/languages.toml`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LanguageOverrides {
+    #[serde(default = "plaintext")]
+    default: String,
+    #[serde(default)]
+    common: Vec<String>,
+    #[serde(default)]
+    languages: BTreeMap<String, LanguageOverride>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LanguageOverride {
+    variant: Option<String>,
+    name: Option<String>,
+    script: Option<String>,
+    #[serde(default)]
+    requires: Vec<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    deprecated: Vec<String>,
+}
+
+fn plaintext() -> String {
+    String::from("plaintext")
+}
+
+// Generates the `HljsLang` enum and its maps from the scripts in `static/js/languages`, with the
+// overrides in `build/languages.toml`.
+fn languages() -> std::io::Result<()> {
+    let dir = manifest_dir().join("static/js/languages");
+    let scripts = file_names(&dir, ".min.js")?;
+    let overrides: LanguageOverrides =
+        read_overrides(&manifest_dir().join("build/languages.toml"))?;
+
+    let mut languages: Vec<Language> = Vec::new();
+    for script in scripts.iter() {
+        let source = fs::read_to_string(dir.join(format!("{script}.min.js")))?;
+        languages.push(Language {
+            name: script.to_owned(),
+            variant: pascal_case(script),
+            display_name: None,
+            script: script.to_owned(),
+            requires: Vec::new(),
+            aliases: script_aliases(&source),
+            deprecated: Vec::new(),
+        });
+    }
+    for (name, entry) in overrides.languages {
+        let index = match languages.iter().position(|language| language.name == name) {
+            Some(index) => index,
+            None => {
+                languages.push(Language {
+                    name: name.to_owned(),
+                    variant: pascal_case(&name),
+                    display_name: None,
+                    script: name.to_owned(),
+                    requires: Vec::new(),
+                    aliases: Vec::new(),
+                    deprecated: Vec::new(),
+                });
+                languages.len() - 1
+            }
+        };
+        let language = &mut languages[index];
+        if let Some(variant) = entry.variant {
+            language.variant = variant;
+        }
+        if let Some(script) = entry.script {
+            language.script = script;
+        }
+        language.display_name = entry.name;
+        language.requires = entry.requires;
+        language.aliases.extend(entry.aliases);
+        language.deprecated = entry.deprecated;
+        if !scripts.contains(&language.script) {
+            panic!(
+                "Missing script for language \"{name}\": static/js/languages/{}.min.js",
+                language.script
+            );
+        }
+    }
+    languages.sort_by(|a, b| a.name.cmp(&b.name));
+    check_variants(
+        languages
+            .iter()
+            .flat_map(|language| [&language.variant].into_iter().chain(&language.deprecated)),
+    );
+    check_requires(&languages);
+    check_aliases(&mut languages);
+
+    let find = |name: &str| {
+        languages
+            .iter()
+            .find(|language| language.name == name)
+            .unwrap_or_else(|| panic!("Unknown language \"{name}\" in build/languages.toml"))
+    };
+    let default = find(&overrides.default);
+    let common: Vec<&Language> = overrides.common.iter().map(|name| find(name)).collect();
+    let additional: Vec<&Language> = languages
+        .iter()
+        .filter(|language| !common.iter().any(|c| c.name == language.name))
+        .collect();
+
+    let mut file = out_file("lang.rs")?;
+    writeln!(file, "{LANG_DOC}")?;
+    writeln!(
+        file,
+        "#[derive(AutoDefault, Clone, Copy, Debug, Eq, PartialEq, Hash)]"
+    )?;
+    writeln!(file, "pub enum HljsLang {{")?;
+    if let (Some(first), Some(last)) = (common.first(), common.last()) {
+        writeln!(file, "    // Common languages.")?;
+        for language in common.iter() {
+            if language.name == last.name {
+                writeln!(
+                    file,
+                    "{}",
+                    COMMON_DOC
+                        .replace("{first}", &first.variant)
+                        .replace("{last}", &last.variant)
+                )?;
+            }
+            if language.name == default.name {
+                writeln!(file, "    #[default]")?;
+            }
+            writeln!(file, "    {},", language.variant)?;
+        }
+        writeln!(file, "    // Additional languages.")?;
+    }
+    for language in additional.iter() {
+        if language.name == default.name {
+            writeln!(file, "    #[default]")?;
+        }
+        writeln!(file, "    {},", language.variant)?;
+    }
+    writeln!(file, "}}")?;
+    writeln!(file)?;
+    writeln!(file, "impl HljsLang {{")?;
+    for language in languages.iter() {
+        let variant = &language.variant;
+        for deprecated in language.deprecated.iter() {
+            writeln!(
+                file,
+                "    #[deprecated(note = \"Use `HljsLang::{variant}` instead\")]"
+            )?;
+            writeln!(file, "    #[allow(non_upper_case_globals)]")?;
+            writeln!(
+                file,
+                "    pub const {deprecated}: HljsLang = HljsLang::{variant};"
+            )?;
+        }
+    }
+    writeln!(file, "}}")?;
+
+    write_map(
+        &mut file,
+        "HLJS_LANGS",
+        ("HljsLang", "&'static str"),
+        languages
+            .iter()
+            .map(|l| (&l.variant, format!("{:?}", l.name))),
+    )?;
+    write_map(
+        &mut file,
+        "HLJS_SCRIPTS",
+        ("HljsLang", "&'static str"),
+        languages
+            .iter()
+            .filter(|l| l.script != l.name)
+            .map(|l| (&l.variant, format!("{:?}", l.script))),
+    )?;
+    write_map(
+        &mut file,
+        "HLJS_REQUIRES",
+        ("HljsLang", "Vec<HljsLang>"),
+        languages
+            .iter()
+            .filter(|l| !l.requires.is_empty())
+            .map(|l| {
+                let requires: Vec<&str> = l
+                    .requires
+                    .iter()
+                    .map(|name| variant_of(&languages, name))
+                    .collect();
+                (&l.variant, format!("vec![{}]", requires.join(", ")))
+            }),
+    )?;
+    write_map(
+        &mut file,
+        "HLJS_ALIASES",
+        ("HljsLang", "Vec<&'static str>"),
+        languages
+            .iter()
+            .filter(|l| !l.aliases.is_empty())
+            .map(|l| (&l.variant, format!("vec!{:?}", l.aliases))),
+    )?;
+    write_map(
+        &mut file,
+        "HLJS_LANG_NAMES",
+        ("HljsLang", "&'static str"),
+        languages.iter().map(|l| {
+            let display_name = l.display_name.as_ref().unwrap_or(&l.variant);
+            (&l.variant, format!("{display_name:?}"))
+        }),
+    )
+}
+
+fn variant_of<'a>(languages: &'a [Language], name: &str) -> &'a str {
+    match languages.iter().find(|language| language.name == name) {
+        Some(language) => &language.variant,
+        None => panic!("Unknown language \"{name}\" in build/languages.toml"),
+    }
+}
+
+// Returns the aliases registered by the script, like `aliases:["rs"]`. Scripts that embed the
+// grammar of another language also contain its aliases, so only the last list is taken.
+fn script_aliases(source: &str) -> Vec<String> {
+    source
+        .match_indices("aliases")
+        .filter_map(|(i, _)| {
+            let list = source[i + "aliases".len()..]
+                .strip_prefix([':', '='])?
+                .strip_prefix('[')?;
+            list.split_once(']').map(|(list, _)| strings(list))
+        })
+        .last()
+        .unwrap_or_default()
+}
+
+// Removes the aliases that are the name of a language, and checks that no alias is shared by two
+// languages.
+fn check_aliases(languages: &mut [Language]) {
+    let names: Vec<String> = languages.iter().map(|l| l.name.to_owned()).collect();
+    let mut seen: HashMap<String, String> = HashMap::new();
+    for language in languages.iter_mut() {
+        language.aliases.retain(|alias| !names.contains(alias));
+        for alias in language.aliases.iter() {
+            if let Some(other) = seen.insert(alias.to_owned(), language.name.to_owned()) {
+                panic!(
+                    "Alias \"{alias}\" is shared by languages \"{other}\" and \"{}\"",
+                    language.name
+                );
+            }
+        }
+    }
+}
+
+// Checks that the required languages exist and don't require each other in a cycle.
+fn check_requires(languages: &[Language]) {
+    fn visit<'a>(languages: &'a [Language], name: &'a str, path: &mut Vec<&'a str>) {
+        if path.contains(&name) {
+            panic!(
+                "Languages require each other: {} > {name}",
+                path.join(" > ")
+            );
+        }
+        variant_of(languages, name);
+        path.push(name);
+        for language in languages.iter().filter(|language| language.name == name) {
+            for required in language.requires.iter() {
+                visit(languages, required, path);
+            }
+        }
+        path.pop();
+    }
+    for language in languages.iter() {
+        visit(languages, &language.name, &mut Vec::new());
+    }
+}
+
+const LANG_DOC: &str = r#"/// Supported coding languages.
+///
+/// Languages are represented by *PascalCase* enums within the code and are mapped to corresponding
+/// [highlight.js](https://highlightjs.org/) language names.
+///
+/// ```rust
+/// use pagetop_hljs::HljsLang;
+///
+/// assert_eq!(HljsLang::CoffeeScript.to_string(), "coffeescript".to_string());
+/// ```"#;
+
+const COMMON_DOC: &str = r#"    /// Enum variants for languages ranging from `{first}` to `{last}` are all preloaded in the
+    /// ***common*** mode. To include additional languages, use the default ***core*** mode.
+    ///
+    /// See [`config::SETTINGS.hljs.mode`](crate::config::Hljs#structfield.mode)."#;
+
+// THEMES ******************************************************************************************
+
+// Overrides in `build/themes.toml`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeOverrides {
+    #[serde(default)]
+    themes: BTreeMap<String, ThemeOverride>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeOverride {
+    variant: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+// Generates the `HljsTheme` enum and its map from the stylesheets in `static/css`, with the
+// overrides in `build/themes.toml`, and embeds the CSS of the themes so they can be processed when
+// served.
+fn themes() -> std::io::Result<()> {
+    let dir = manifest_dir().join("static/css");
+    let names = file_names(&dir, ".min.css")?;
+    let overrides: ThemeOverrides = read_overrides(&manifest_dir().join("build/themes.toml"))?;
+
+    let mut themes: Vec<(String, String)> = names
+        .iter()
+        .map(|name| (pascal_case(name), name.to_owned()))
+        .collect();
+    // Former names of the themes, by name.
+    let mut aliases: Vec<(String, String)> = Vec::new();
+    for (name, entry) in overrides.themes {
+        let Some(theme) = themes.iter_mut().find(|(_, theme)| *theme == name) else {
+            panic!("Missing stylesheet for theme \"{name}\": static/css/{name}.min.css");
+        };
+        if let Some(variant) = entry.variant {
+            theme.0 = variant;
+        }
+        aliases.extend(entry.aliases.into_iter().map(|alias| (alias, name.clone())));
+    }
+    // Aliases keep the former variants as deprecated constants.
+    let aliases: Vec<(String, String, String)> = aliases
+        .into_iter()
+        .map(|(alias, name)| {
+            if names.contains(&alias) {
+                panic!("Alias \"{alias}\" of theme \"{name}\" is the name of another theme");
+            }
+            let (variant, _) = themes.iter().find(|(_, theme)| *theme == name).unwrap();
+            (pascal_case(&alias), alias, variant.to_owned())
+        })
+        .collect();
+    let custom = String::from("Custom");
+    check_variants(
+        themes
+            .iter()
+            .map(|(variant, _)| variant)
+            .chain(aliases.iter().map(|(deprecated, _, _)| deprecated))
+            .chain([&custom]),
+    );
+
+    // Images referenced by the themes, like `url(./pojoaque.jpg)`.
+    let mut missing = Vec::new();
+    for name in names.iter() {
+        let css = fs::read_to_string(dir.join(format!("{name}.min.css")))?;
+        for image in css
+            .split("url(./")
+            .skip(1)
+            .filter_map(|url| url.split(')').next())
+        {
+            if !dir.join(image).is_file() {
+                missing.push(format!("{name}.min.css => static/css/{image}"));
+            }
+        }
+    }
+    if !missing.is_empty() {
+        panic!("Missing theme assets:\n  {}", missing.join("\n  "));
+    }
+
+    let mut file = out_file("theme.rs")?;
+    writeln!(file, "{THEME_DOC}")?;
+    writeln!(file, "#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]")?;
+    writeln!(file, "pub enum HljsTheme {{")?;
+    for (variant, _) in themes.iter() {
+        writeln!(file, "    {variant},")?;
+    }
+    writeln!(file, "    /// Custom theme registered by name.")?;
+    writeln!(file, "    Custom(HljsCustomTheme),")?;
+    writeln!(file, "}}")?;
+    writeln!(file)?;
+    writeln!(file, "impl HljsTheme {{")?;
+    for (deprecated, _, variant) in aliases.iter() {
+        writeln!(
+            file,
+            "    #[deprecated(note = \"Use `HljsTheme::{variant}` instead\")]"
+        )?;
+        writeln!(file, "    #[allow(non_upper_case_globals)]")?;
+        writeln!(
+            file,
+            "    pub const {deprecated}: HljsTheme = HljsTheme::{variant};"
+        )?;
+    }
+    writeln!(file, "}}")?;
+    writeln!(file)?;
+    writeln!(
+        file,
+        "// Former names of the themes, still accepted when parsing."
+    )?;
+    writeln!(file, "static HLJS_THEME_ALIASES: &[(&str, HljsTheme)] = &[")?;
+    for (_, alias, variant) in aliases.iter() {
+        writeln!(file, "    ({alias:?}, HljsTheme::{variant}),")?;
+    }
+    writeln!(file, "];")?;
+    write_map(
+        &mut file,
+        "HLJS_THEMES",
+        ("HljsTheme", "&'static str"),
+        themes.iter().map(|(v, n)| (v, format!("{n:?}"))),
+    )?;
+
+    let mut file = out_file("theme_css.rs")?;
+    writeln!(file, "pub(crate) static THEME_CSS: &[(&str, &str)] = &[")?;
+    for name in names {
+        let path = dir.join(format!("{name}.min.css")).display().to_string();
+        writeln!(file, "    ({name:?}, include_str!({path:?})),")?;
+    }
+    writeln!(file, "];")
+}
+
+const THEME_DOC: &str = r#"/// Supported themes.
+///
+/// Themes are defined as *PascalCase* enums in the code and correspond to *kebab-case* string
+/// identifiers.
+///
+/// ```rust
+/// use pagetop_hljs::HljsTheme;
+///
+/// assert_eq!(HljsTheme::AtelierPlateauLight.to_string(), "atelier-plateau-light".to_string());
+/// ```
+///
+/// Additional themes can be registered with [`HljsTheme::register_custom()`] or in the
+/// [`hljs.custom_themes`](crate::config::Hljs#structfield.custom_themes) configuration setting,
+/// and then used by name like any other theme."#;
+
+// HELPERS *****************************************************************************************
+
+// Returns the sorted names of the files in the directory with the given suffix, without it.
+fn file_names(dir: &Path, suffix: &str) -> std::io::Result<Vec<String>> {
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let mut names: Vec<String> = fs::read_dir(dir)?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            Some(
+                path.file_name()?
+                    .to_str()?
+                    .strip_suffix(suffix)?
+                    .to_string(),
+            )
+        })
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+// Returns the PascalCase variant for a kebab-case name, like "AtomOneDark" for "atom-one-dark".
+fn pascal_case(name: &str) -> String {
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn check_variants<'a>(variants: impl Iterator<Item = &'a String>) {
+    let mut seen: Vec<&String> = Vec::new();
+    for variant in variants {
+        if !variant.starts_with(|c: char| c.is_ascii_uppercase())
+            || !variant.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            panic!("Invalid enum variant \"{variant}\"");
+        }
+        if seen.contains(&variant) {
+            panic!("Duplicated enum variant \"{variant}\", override it with a different one");
+        }
+        seen.push(variant);
+    }
+}
+
+// Writes a `kv!` map from the enum variants to the given value expressions.
+fn write_map<'a>(
+    file: &mut fs::File,
+    name: &str,
+    (enumeration, value_type): (&str, &str),
+    entries: impl Iterator<Item = (&'a String, String)>,
+) -> std::io::Result<()> {
+    writeln!(file)?;
+    writeln!(
+        file,
+        "static {name}: LazyLock<HashMap<{enumeration}, {value_type}>> = LazyLock::new(|| {{"
+    )?;
+    writeln!(file, "    use {enumeration}::*;")?;
+    writeln!(file, "    kv![")?;
+    for (variant, value) in entries {
+        writeln!(file, "        {variant} => {value},")?;
+    }
+    writeln!(file, "    ]")?;
+    writeln!(file, "}});")
+}
+
+// Reads an override file, panicking if it is not valid.
+fn read_overrides<T: DeserializeOwned>(path: &Path) -> std::io::Result<T> {
+    println!("cargo:rerun-if-changed={}", path.display());
+    let source = fs::read_to_string(path)?;
+    Ok(toml::from_str(&source).unwrap_or_else(|e| panic!("Invalid {}: {e}", path.display())))
+}
+
+// Returns the quoted strings of a list, like `"rs","rust"`.
+fn strings(value: &str) -> Vec<String> {
+    value
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .map(String::from)
+        .collect()
+}
@@ -0,0 +1,237 @@
+// Regenerates `src/highlight/grammars.rs`, the grammars of the server-side highlighting, from the
+// bundled highlight.js library: `node build/grammars.js`.
+//
+// The languages of the *common* library are compiled by highlight.js itself, and their modes are
+// written as tables indexed by position, the language first, with the same regular expressions,
+// keywords and options the library uses in the browser. The callbacks of the modes are mapped to
+// the `Callback` variants implemented by the engine.
+
+const fs = require('fs');
+const path = require('path');
+const vm = require('vm');
+
+const root = path.join(__dirname, '..');
+const context = {};
+vm.createContext(context);
+vm.runInContext(
+  fs.readFileSync(path.join(root, 'static', 'js', 'highlight.min.js'), 'utf8') + ';this.hljs = hljs;',
+  context,
+);
+const hljs = context.hljs;
+
+// Callbacks of the modes by their source code.
+const CALLBACKS = {
+  '(e,n)=>{0!==e.index&&n.ignoreMatch()}': 'IgnoreUnlessFirst',
+  '(e,n)=>{n.data._beginMatch=e[1]}': 'SaveBeginMatch',
+  '(e,n)=>{\nn.data._beginMatch!==e[1]&&n.ignoreMatch()}': 'IgnoreUnlessSameAsBegin',
+  'function R(e,n){\n"."===e.input[e.index-1]&&n.ignoreMatch()}': 'IgnoreAfterDot',
+};
+
+function callback(fn) {
+  if (!fn) {
+    return null;
+  }
+  const source = fn.toString();
+  if (CALLBACKS[source]) {
+    return 'Callback::' + CALLBACKS[source];
+  }
+  if (source.includes('"</"+e[0].slice(1)')) {
+    return 'Callback::IgnoreUnlessJsxTag';
+  }
+  throw new Error('Unknown callback: ' + source);
+}
+
+// Rust literal of a string, raw unless it has control characters.
+function str(s) {
+  if (/[\u0000-\u001f\u007f]/.test(s)) {
+    return '"' + [...s].map((c) => {
+      const code = c.codePointAt(0);
+      if (c === '"' || c === '\\') {
+        return '\\' + c;
+      }
+      return code < 0x20 || code === 0x7f ? '\\u{' + code.toString(16) + '}' : c;
+    }).join('') + '"';
+  }
+  let hashes = '';
+  while (s.includes('"' + hashes)) {
+    hashes += '#';
+  }
+  return 'r' + hashes + '"' + s + '"' + hashes;
+}
+
+const source = (re) => (re == null ? null : typeof re === 'string' ? re : re.source);
+const option = (value, f = str) => (value == null ? 'None' : 'Some(' + f(value) + ')');
+const number = (n) => (Number.isInteger(n) ? n.toFixed(1) : String(n));
+
+function scopes(scope) {
+  if (!scope) {
+    return null;
+  }
+  if (scope._wrap) {
+    return 'Scopes::Wrap(' + str(scope._wrap) + ')';
+  }
+  const groups = Object.keys(scope._emit)
+    .map(Number)
+    .sort((a, b) => a - b)
+    .map((group) => '(' + group + ', ' + option(scope[group] || null) + ')');
+  return 'Scopes::Multi(&[' + groups.join(', ') + '])';
+}
+
+const keywordTables = [];
+
+function keywords(table) {
+  if (!table) {
+    return null;
+  }
+  const entries = Object.keys(table)
+    .sort()
+    .map((word) => '(' + str(word) + ', ' + str(table[word][0]) + ', ' + table[word][1] + ')');
+  const text = entries.join(', ');
+  let index = keywordTables.indexOf(text);
+  if (index < 0) {
+    index = keywordTables.push(text) - 1;
+  }
+  return 'KEYWORDS_' + index;
+}
+
+const properties = new Set();
+
+function regex(re) {
+  const s = source(re);
+  if (s != null) {
+    for (const [, name] of s.matchAll(/\\[pP]\{([^}]*)\}/g)) {
+      properties.add(name);
+    }
+  }
+  return s;
+}
+
+function mode(m, ids) {
+  const fields = [];
+  const field = (name, value, fallback) => {
+    if (value !== fallback) {
+      fields.push(name + ': ' + value);
+    }
+  };
+  const flag = (name, value) => field(name, value ? 'true' : 'false', 'false');
+  field('scope', option(typeof m.scope === 'string' ? m.scope : null), 'None');
+  field('begin_scope', option(scopes(m.beginScope), (s) => s), 'None');
+  field('end_scope', option(scopes(m.endScope), (s) => s), 'None');
+  field('begin', str(regex(m.begin) || ''), 'r""');
+  field('end', option(regex(m.endRe)), 'None');
+  field('terminator_end', str(regex(m.terminatorEnd) || ''), 'r""');
+  field('illegal', option(regex(m.illegal)), 'None');
+  field('keywords', option(keywords(m.keywords), (s) => s), 'None');
+  if (m.keywords) {
+    field('keyword_pattern', str(regex(m.keywordPatternRe)), 'r"\\w+"');
+  }
+  field('contains', '&[' + (m.contains || []).map((c) => ids.get(c)).join(', ') + ']', '&[]');
+  field('starts', option(m.starts ? ids.get(m.starts) : null, String), 'None');
+  field('relevance', number(m.relevance), '1.0');
+  if (m.subLanguage != null) {
+    field(
+      'sub_language',
+      typeof m.subLanguage === 'string'
+        ? 'Some(SubLanguage::One(' + str(m.subLanguage) + '))'
+        : 'Some(SubLanguage::Any(&[' + m.subLanguage.map(str).join(', ') + ']))',
+      'None',
+    );
+  }
+  flag('exclude_begin', m.excludeBegin);
+  flag('exclude_end', m.excludeEnd);
+  flag('return_begin', m.returnBegin);
+  flag('return_end', m.returnEnd);
+  flag('ends_with_parent', m.endsWithParent);
+  flag('ends_parent', m.endsParent);
+  flag('skip', m.skip);
+  field('before_begin', option(callback(m.__beforeBegin), (s) => s), 'None');
+  field('on_begin', option(callback(m['on:begin']), (s) => s), 'None');
+  field('on_end', option(callback(m['on:end']), (s) => s), 'None');
+  return 'Mode { ' + fields.concat(['..MODE']).join(', ') + ' }';
+}
+
+const constant = (name) => name.toUpperCase().replace(/-/g, '_');
+
+const languages = [];
+const tables = [];
+for (const name of hljs.listLanguages()) {
+  hljs.highlight('', { language: name });
+  const language = hljs.getLanguage(name);
+  // Modes by id, in the order they are found from the language.
+  const ids = new Map();
+  const modes = [];
+  const queue = [language];
+  while (queue.length) {
+    const m = queue.shift();
+    if (ids.has(m)) {
+      continue;
+    }
+    ids.set(m, modes.push(m) - 1);
+    queue.push(...(m.contains || []));
+    if (m.starts) {
+      queue.push(m.starts);
+    }
+  }
+  const aliases = Object.entries(language.classNameAliases || {})
+    .map(([scope, alias]) => '(' + str(scope) + ', ' + str(alias) + ')');
+  languages.push([
+    '    Language {',
+    '        name: ' + str(name) + ',',
+    '        aliases: &[' + (language.aliases || []).map((a) => str(a.toLowerCase())).join(', ') + '],',
+    '        case_insensitive: ' + !!language.case_insensitive + ',',
+    '        unicode: ' + !!language.unicodeRegex + ',',
+    '        disable_autodetect: ' + !!language.disableAutodetect + ',',
+    '        superset_of: ' + option(language.supersetOf) + ',',
+    '        class_name_aliases: &[' + aliases.join(', ') + '],',
+    '        modes: ' + constant(name) + ',',
+    '    },',
+  ].join('\n'));
+  tables.push(
+    '\nstatic ' + constant(name) + ': &[Mode] = &[\n' +
+    modes.map((m, id) => '    /* ' + id + ' */ ' + mode(m, ids) + ',\n').join('') +
+    '];\n',
+  );
+}
+
+// Ranges of the Unicode properties used by the expressions, like `\p{L}`.
+const ranges = [...properties].sort().map((name) => {
+  const re = new RegExp('\\p{' + name + '}', 'u');
+  const list = [];
+  for (let c = 0; c <= 0x10ffff; c++) {
+    if (re.test(String.fromCodePoint(c))) {
+      const last = list[list.length - 1];
+      if (last && last[1] === c - 1) {
+        last[1] = c;
+      } else {
+        list.push([c, c]);
+      }
+    }
+  }
+  const hex = (c) => '0x' + c.toString(16).toUpperCase();
+  return '    (\n        ' + str(name) + ',\n        &[' +
+    list.map(([from, to]) => '(' + hex(from) + ', ' + hex(to) + ')').join(', ') + '],\n    ),';
+});
+
+fs.writeFileSync(
+  path.join(root, 'src', 'highlight', 'grammars.rs'),
+  [
+    '// Grammars of the highlight.js common languages, compiled by highlight.js ' +
+      hljs.versionString + '. Generated by',
+    '// `node build/grammars.js`, don\'t edit.',
+    '',
+    'use super::{Callback, Language, Mode, Scopes, SubLanguage, MODE};',
+    '',
+    'pub(super) static LANGUAGES: &[Language] = &[',
+    languages.join('\n'),
+    '];',
+    '',
+    '// Unicode properties used by the regular expressions.',
+    'pub(super) static PROPERTIES: &[(&str, &[(u32, u32)])] = &[',
+    ranges.join('\n'),
+    '];',
+    tables.join(''),
+    keywordTables
+      .map((entries, i) => '\nstatic KEYWORDS_' + i + ': &[(&str, &str, u8)] = &[' + entries + '];\n')
+      .join(''),
+  ].join('\n'),
+);
@@ -0,0 +1,292 @@
+//! Generate themes from [base16](https://github.com/tinted-theming/home) color schemes.
+
+use pagetop::prelude::*;
+
+use crate::{Base16Error, HljsError, HljsTheme, HljsThemeSource};
+
+/// Color scheme with the 16 colors of the base16 styling guidelines, from `base00` to `base0F`.
+///
+/// The scheme produces a stylesheet with the same token colors as the base16 themes bundled with
+/// highlight.js, and can be registered as a custom theme:
+///
+/// ```rust
+/// use pagetop_hljs::{Base16Scheme, HljsTheme};
+///
+/// let scheme = Base16Scheme::from_yaml(r#"
+/// scheme: "Ocean"
+/// author: "Chris Kempson (http://chriskempson.com)"
+/// base00: "2b303b"
+/// base01: "343d46"
+/// base02: "4f5b66"
+/// base03: "65737e"
+/// base04: "a7adba"
+/// base05: "c0c5ce"
+/// base06: "dfe1e8"
+/// base07: "eff1f5"
+/// base08: "bf616a"
+/// base09: "d08770"
+/// base0A: "ebcb8b"
+/// base0B: "a3be8c"
+/// base0C: "96b5b4"
+/// base0D: "8fa1b3"
+/// base0E: "b48ead"
+/// base0F: "ab7967"
+/// "#).unwrap();
+///
+/// assert_eq!(scheme.name(), "Ocean");
+/// assert_eq!(scheme.color(0x0D), Some("#8fa1b3"));
+///
+/// let theme = scheme.register("my-ocean").unwrap();
+/// assert_eq!(theme.to_string(), "my-ocean");
+/// assert_eq!("my-ocean".parse::<HljsTheme>(), Ok(theme));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Base16Scheme {
+    name: String,
+    author: Option<String>,
+    colors: [String; 16],
+}
+
+impl Base16Scheme {
+    /// Creates a scheme from its 16 colors in hexadecimal, with or without the leading `#`.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::{Base16Error, Base16Scheme};
+    ///
+    /// let scheme = Base16Scheme::new("Mono", [
+    ///     "#000000", "#111111", "#222222", "#333333", "#444444", "#555555", "#666666", "#777777",
+    ///     "#888888", "#999999", "#aaaaaa", "#bbbbbb", "#cccccc", "#dddddd", "#eeeeee", "#ffffff",
+    /// ]);
+    /// assert!(scheme.is_ok());
+    ///
+    /// let error = Base16Scheme::new("Wrong", ["#00"; 16]).unwrap_err();
+    /// assert_eq!(error, Base16Error::InvalidColor { key: "base00".into(), value: "#00".into() });
+    /// ```
+    pub fn new(name: impl Into<String>, colors: [&str; 16]) -> Result<Self, Base16Error> {
+        let mut parsed: [String; 16] = Default::default();
+        for (index, (color, value)) in parsed.iter_mut().zip(colors).enumerate() {
+            *color = parse_color(index, value)?;
+        }
+        Ok(Base16Scheme {
+            name: name.into(),
+            author: None,
+            colors: parsed,
+        })
+    }
+
+    /// Creates a scheme from the standard base16 YAML format, with the colors at the top level
+    /// (`scheme`, `author`, `base00`…) or in a `palette` section (`name`, `author`, `palette`).
+    /// Fails with the key of the first color that is missing or invalid.
+    pub fn from_yaml(yaml: &str) -> Result<Self, Base16Error> {
+        let mut name = None;
+        let mut author = None;
+        let mut colors: [Option<String>; 16] = Default::default();
+
+        for line in yaml.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = yaml_value(value);
+            match key.trim() {
+                "scheme" | "name" => name = Some(value.to_string()),
+                "author" => author = Some(value.to_string()),
+                key => {
+                    let index = key
+                        .strip_prefix("base0")
+                        .filter(|digit| digit.len() == 1)
+                        .and_then(|digit| usize::from_str_radix(digit, 16).ok());
+                    if let Some(index) = index {
+                        colors[index] = Some(parse_color(index, value)?);
+                    }
+                }
+            }
+        }
+
+        let mut parsed: [String; 16] = Default::default();
+        for (index, (color, value)) in parsed.iter_mut().zip(colors).enumerate() {
+            *color = value.ok_or_else(|| Base16Error::MissingColor { key: key(index) })?;
+        }
+        Ok(Base16Scheme {
+            name: name.unwrap_or_default(),
+            author: author.filter(|author| !author.is_empty()),
+            colors: parsed,
+        })
+    }
+
+    // Base16Scheme GETTERS.
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// Returns the color from `0x00` (*base00*) to `0x0F` (*base0F*), like `"#2b303b"`, or `None`
+    /// if the index is greater than `0x0F`.
+    pub fn color(&self, index: usize) -> Option<&str> {
+        self.colors.get(index).map(String::as_str)
+    }
+
+    /// Returns the highlight.js stylesheet for the scheme.
+    pub fn to_css(&self) -> String {
+        let c = |index: usize| self.colors[index].as_str();
+        let mut css = String::from("/*!\n  Theme: ");
+        css.push_str(self.name());
+        if let Some(author) = self.author() {
+            css.push_str("\n  Author: ");
+            css.push_str(author);
+        }
+        css.push_str("\n*/");
+        #[rustfmt::skip]
+        css.push_str(&concat_string!(
+            "pre code.hljs{display:block;overflow-x:auto;padding:1em}",
+            "code.hljs{padding:3px 5px}",
+            ".hljs{color:", c(0x05), ";background:", c(0x00), "}",
+            ".hljs ::selection,.hljs::selection{background-color:", c(0x02), ";color:", c(0x05), "}",
+            ".hljs-comment{color:", c(0x03), "}",
+            ".hljs-tag{color:", c(0x04), "}",
+            ".hljs-operator,.hljs-punctuation,.hljs-subst{color:", c(0x05), "}",
+            ".hljs-operator{opacity:.7}",
+            ".hljs-bullet,.hljs-deletion,.hljs-name,.hljs-selector-tag,.hljs-template-variable,",
+            ".hljs-variable{color:", c(0x08), "}",
+            ".hljs-attr,.hljs-link,.hljs-literal,.hljs-number,.hljs-symbol,",
+            ".hljs-variable.constant_{color:", c(0x09), "}",
+            ".hljs-class .hljs-title,.hljs-title,.hljs-title.class_{color:", c(0x0A), "}",
+            ".hljs-strong{font-weight:700;color:", c(0x0A), "}",
+            ".hljs-addition,.hljs-code,.hljs-string,",
+            ".hljs-title.class_.inherited__{color:", c(0x0B), "}",
+            ".hljs-built_in,.hljs-doctag,.hljs-keyword.hljs-atrule,.hljs-quote,",
+            ".hljs-regexp{color:", c(0x0C), "}",
+            ".hljs-attribute,.hljs-function .hljs-title,.hljs-section,.hljs-title.function_,",
+            ".ruby .hljs-property{color:", c(0x0D), "}",
+            ".diff .hljs-meta,.hljs-keyword,.hljs-template-tag,.hljs-type{color:", c(0x0E), "}",
+            ".hljs-emphasis{color:", c(0x0E), ";font-style:italic}",
+            ".hljs-meta,.hljs-meta .hljs-keyword,.hljs-meta .hljs-string{color:", c(0x0F), "}",
+            ".hljs-meta .hljs-keyword,.hljs-meta-keyword{font-weight:700}"
+        ));
+        css
+    }
+
+    /// Registers the scheme as a custom theme with the given *kebab-case* name, served at
+    /// `/hljs/css/custom/<name>.css` (see [`HljsTheme::register_custom()`]).
+    pub fn register(&self, name: impl Into<String>) -> Result<HljsTheme, HljsError> {
+        HljsTheme::register_custom(name, HljsThemeSource::Css(self.to_css()))
+    }
+}
+
+// Returns the YAML value without the trailing comment and the enclosing quotes.
+fn yaml_value(value: &str) -> &str {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(quoted) = value.strip_prefix(quote) {
+            return quoted.split(quote).next().unwrap_or_default();
+        }
+    }
+    value.split(" #").next().unwrap_or_default().trim()
+}
+
+// Returns the key of the color with the given index, like `base0A`.
+fn key(index: usize) -> String {
+    format!("base{index:02X}")
+}
+
+// Returns the color with the given index as `#rrggbb` in lowercase.
+fn parse_color(index: usize, color: &str) -> Result<String, Base16Error> {
+    let hex = color.trim().trim_start_matches('#');
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(concat_string!("#", hex.to_ascii_lowercase()))
+    } else {
+        Err(Base16Error::InvalidColor {
+            key: key(index),
+            value: color.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the YAML of a scheme with the given lines and the colors not in them.
+    fn yaml(lines: &[&str]) -> String {
+        let mut yaml = lines.join("\n");
+        for index in 0..16 {
+            if !lines
+                .iter()
+                .any(|line| line.trim_start().starts_with(&key(index)))
+            {
+                let color = format!("{index:x}").repeat(6);
+                yaml.push_str(&format!("\n{}: \"{color}\"", key(index)));
+            }
+        }
+        yaml
+    }
+
+    #[test]
+    fn yaml_values_can_be_quoted() {
+        let scheme = Base16Scheme::from_yaml(&yaml(&[
+            "scheme: \"Double: quoted\"",
+            "author: 'Single'",
+            "base00: \"2B303B\"",
+            "base01: '343d46'",
+            "base02: 4f5b66",
+        ]))
+        .unwrap();
+        assert_eq!(scheme.name(), "Double: quoted");
+        assert_eq!(scheme.author(), Some("Single"));
+        assert_eq!(scheme.color(0x00), Some("#2b303b"));
+        assert_eq!(scheme.color(0x01), Some("#343d46"));
+        assert_eq!(scheme.color(0x02), Some("#4f5b66"));
+        assert_eq!(scheme.color(0x10), None);
+    }
+
+    #[test]
+    fn yaml_comments_are_ignored() {
+        let scheme = Base16Scheme::from_yaml(&yaml(&[
+            "# Comment line, base00: \"000000\"",
+            "scheme: \"Commented\" # Trailing comment",
+            "base00: 2b303b # Default background",
+            "base01: \"343d46\" # Lighter background",
+        ]))
+        .unwrap();
+        assert_eq!(scheme.name(), "Commented");
+        assert_eq!(scheme.color(0x00), Some("#2b303b"));
+        assert_eq!(scheme.color(0x01), Some("#343d46"));
+    }
+
+    #[test]
+    fn yaml_colors_can_start_with_hash() {
+        let scheme = Base16Scheme::from_yaml(&yaml(&[
+            "palette:",
+            "  base00: \"#2b303b\"",
+            "  base01: '#343d46'",
+        ]))
+        .unwrap();
+        assert_eq!(scheme.color(0x00), Some("#2b303b"));
+        assert_eq!(scheme.color(0x01), Some("#343d46"));
+    }
+
+    #[test]
+    fn yaml_errors_name_the_color() {
+        let missing = yaml(&["scheme: Missing"]).replace("base0E", "other");
+        assert_eq!(
+            Base16Scheme::from_yaml(&missing).unwrap_err(),
+            Base16Error::MissingColor {
+                key: "base0E".to_string()
+            },
+        );
+        assert_eq!(
+            Base16Scheme::from_yaml(&yaml(&["base0A: \"ebcb8\""])).unwrap_err(),
+            Base16Error::InvalidColor {
+                key: "base0A".to_string(),
+                value: "ebcb8".to_string()
+            },
+        );
+    }
+}
@@ -6,7 +6,25 @@
 //! [hljs]
 //! mode = "core"
 //! theme = "zenburn"
+//! dark_theme = "github-dark"
 //! tabsize = 8
+//! expand_tabs = true
+//! line_numbers = true
+//! copy_button = true
+//! gallery = true
+//! font_family = "'JetBrains Mono', monospace"
+//! font_url = "/fonts/jetbrains-mono.css"
+//! font_size = "0.9rem"
+//! line_height = "1.5"
+//! ligatures = false
+//!
+//! [hljs.token_colors]
+//! keyword = "#e63946"
+//! comment = "#6a737d"
+//!
+//! [hljs.custom_themes]
+//! brand = { url = "/css/brand-theme.css" }
+//! plain = { css = ".hljs { color: #333; background: #fafafa; }" }
 //! ```
 //!
 //! Usage:
@@ -20,16 +38,21 @@
 
 use pagetop::prelude::*;
 
-use crate::{HljsMode, HljsTheme};
+use crate::css;
+use crate::{HljsError, HljsMode, HljsTheme, HljsThemeSource, HljsToken};
 
 use serde::Deserialize;
 
+use std::collections::HashMap;
+use std::str::FromStr;
+
 #[derive(Debug, Deserialize)]
 /// Configuration settings for the [`[hljs]`](Hljs) section (see [`SETTINGS`] package).
 pub struct Settings {
     pub hljs: Hljs,
 }
 #[derive(Debug, Deserialize)]
+#[serde(try_from = "HljsSettings")]
 /// Section `[hljs]` of the configuration settings.
 ///
 /// See [`Settings`].
@@ -38,20 +61,180 @@ pub struct Hljs {
     /// [`add_hljs_language()`](crate::HljsContext::add_hljs_language). Alternatively, ***common***
     /// imports an extended library containing around 40 popular languages (see
     /// [`HljsLang`](crate::HljsLang)). Note that using the *common* library restricts you to the
-    /// languages that are preloaded.
+    /// languages that are preloaded. Finally, ***server*** highlights the code on the server
+    /// without sending any JavaScript to the browser, supporting the same languages as the *common*
+    /// library, other languages are displayed as plain text.
     /// Default value: *"core"*
     pub mode: HljsMode,
-    /// Default theme in kebab-case used to display code snippets on web pages (see [`HljsTheme`]).
+    /// Default theme in kebab-case used to display code snippets on web pages (see [`HljsTheme`]),
+    /// which can also be one of the [`custom_themes`](Self::custom_themes).
     /// Default value: *"default"*
     pub theme: HljsTheme,
+    /// Optional theme in kebab-case used instead of [`theme`](Self::theme) when the reader prefers
+    /// a dark color scheme.
+    /// Default value: *none*
+    pub dark_theme: Option<HljsTheme>,
+    /// When a [`dark_theme`](Self::dark_theme) is set, follow the `data-theme="light"` or
+    /// `data-theme="dark"` attribute of the `html` element, usually toggled by JavaScript, over
+    /// the color scheme preferred by the reader.
+    /// Default value: *false*
+    pub data_theme: bool,
     /// Number of spaces for *tab* character.
     /// Default value: *4*
     pub tabsize: usize,
+    /// Expand *tab* characters in snippets to spaces on the server, using
+    /// [`tabsize`](Self::tabsize) columns per tab stop.
+    /// Default value: *false*
+    pub expand_tabs: bool,
+    /// Show a line-number gutter next to every [`Snippet`](crate::Snippet) by default. Each snippet
+    /// can override it with [`with_line_numbers()`](crate::Snippet::with_line_numbers).
+    /// Default value: *false*
+    pub line_numbers: bool,
+    /// Show a button to copy the code of every [`Snippet`](crate::Snippet) to the clipboard by
+    /// default. Each snippet can override it with
+    /// [`with_copy_button()`](crate::Snippet::with_copy_button).
+    /// Default value: *false*
+    pub copy_button: bool,
+    /// Font family of the code, like `"'Fira Code', monospace"`. Otherwise the font given by the
+    /// theme or the page is used.
+    /// Default value: *none*
+    pub font_family: Option<String>,
+    /// URL of a stylesheet declaring the web fonts used by [`font_family`](Self::font_family), like
+    /// a self-hosted `@font-face` stylesheet. It must be an absolute path or an HTTP(S) URL.
+    /// Default value: *none*
+    pub font_url: Option<String>,
+    /// Font size of the code blocks, like `"14px"` or `"0.9rem"`.
+    /// Default value: *none*
+    pub font_size: Option<String>,
+    /// Line height of the code blocks, like `"1.5"`.
+    /// Default value: *none*
+    pub line_height: Option<String>,
+    /// Enable or disable the font ligatures in the code, like `=>` or `!=` displayed as a single
+    /// glyph by fonts such as *Fira Code*.
+    /// Default value: *none*
+    pub ligatures: Option<bool>,
+    /// Mount a gallery at `/hljs/gallery` displaying a sample snippet in every theme. The
+    /// `lang` query parameter selects the language of the sample, like `/hljs/gallery?lang=python`,
+    /// among Bash, CSS, HTML, JavaScript, Python, Rust and SQL. Other languages are not found.
+    /// Default value: *false*
+    pub gallery: bool,
+    /// Only allow themes whose token colors reach the WCAG AA contrast level against the theme
+    /// background (see [`HljsTheme::accessible_themes()`]), both in these settings and when the
+    /// theme is changed for a context or chosen by the reader. The contrast of custom themes given
+    /// by `url` can't be checked, so they are not allowed either.
+    /// Default value: *false*
+    pub accessible_only: bool,
+    /// Custom themes given by the *kebab-case* name used to select them, and the `url` or `css` of
+    /// their stylesheet (see [`HljsThemeSource`]). They are registered when the settings are
+    /// loaded, so [`theme`](Self::theme) and [`dark_theme`](Self::dark_theme) can use them.
+    /// Default value: *none*
+    pub custom_themes: HashMap<String, HljsThemeSource>,
+    /// Token colors overriding those of any theme, given by the *kebab-case* name of the token
+    /// (see [`HljsToken`]) and a CSS color value. They are served in a stylesheet linked after the
+    /// theme stylesheet.
+    /// Default value: *none*
+    pub token_colors: HashMap<HljsToken, String>,
+}
+
+// Section `[hljs]` as given in the configuration settings, with the names of the themes checked
+// once the custom themes are registered.
+#[derive(Deserialize)]
+struct HljsSettings {
+    mode: HljsMode,
+    theme: String,
+    dark_theme: Option<String>,
+    data_theme: bool,
+    tabsize: usize,
+    expand_tabs: bool,
+    line_numbers: bool,
+    copy_button: bool,
+    font_family: Option<String>,
+    font_url: Option<String>,
+    font_size: Option<String>,
+    line_height: Option<String>,
+    ligatures: Option<bool>,
+    gallery: bool,
+    accessible_only: bool,
+    #[serde(default)]
+    custom_themes: HashMap<String, HljsThemeSource>,
+    #[serde(default)]
+    token_colors: HashMap<HljsToken, String>,
+}
+
+impl TryFrom<HljsSettings> for Hljs {
+    type Error = HljsError;
+
+    fn try_from(settings: HljsSettings) -> Result<Self, Self::Error> {
+        for (name, source) in settings.custom_themes.iter() {
+            HljsTheme::register_custom(name, source.clone())?;
+        }
+        for (token, color) in settings.token_colors.iter() {
+            if !HljsToken::is_valid_color(color) {
+                return Err(HljsError::TokenColor {
+                    token: token.to_string(),
+                    value: color.to_string(),
+                });
+            }
+        }
+        let fonts = [
+            ("hljs.font_family", &settings.font_family),
+            ("hljs.font_size", &settings.font_size),
+            ("hljs.line_height", &settings.line_height),
+        ];
+        for (name, value) in fonts {
+            check(name, value, css::is_safe_value)?;
+        }
+        check("hljs.font_url", &settings.font_url, css::is_safe_url)?;
+        let accessible_only = settings.accessible_only;
+        let theme = |name: &str| {
+            let theme = HljsTheme::from_str(name)?;
+            if accessible_only {
+                theme.check_contrast()?;
+            }
+            Ok(theme)
+        };
+        Ok(Hljs {
+            mode: settings.mode,
+            theme: theme(&settings.theme)?,
+            dark_theme: settings.dark_theme.as_deref().map(theme).transpose()?,
+            data_theme: settings.data_theme,
+            tabsize: settings.tabsize,
+            expand_tabs: settings.expand_tabs,
+            line_numbers: settings.line_numbers,
+            copy_button: settings.copy_button,
+            font_family: settings.font_family,
+            font_url: settings.font_url,
+            font_size: settings.font_size,
+            line_height: settings.line_height,
+            ligatures: settings.ligatures,
+            gallery: settings.gallery,
+            accessible_only: settings.accessible_only,
+            custom_themes: settings.custom_themes,
+            token_colors: settings.token_colors,
+        })
+    }
+}
+
+// Returns an error if the optional value of the setting is given and is not valid.
+fn check(name: &str, value: &Option<String>, is_valid: fn(&str) -> bool) -> Result<(), HljsError> {
+    match value.as_deref().filter(|value| !is_valid(value)) {
+        Some(value) => Err(HljsError::Setting {
+            name: name.to_string(),
+            value: value.to_string(),
+        }),
+        None => Ok(()),
+    }
 }
 
 default_settings!(
     // [hljs]
-    "hljs.mode"    => "core",
-    "hljs.theme"   => "default",
-    "hljs.tabsize" => 4,
+    "hljs.mode"            => "core",
+    "hljs.theme"           => "default",
+    "hljs.data_theme"      => false,
+    "hljs.tabsize"         => 4,
+    "hljs.expand_tabs"     => false,
+    "hljs.line_numbers"    => false,
+    "hljs.copy_button"     => false,
+    "hljs.gallery"         => false,
+    "hljs.accessible_only" => false,
 );
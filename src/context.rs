@@ -1,14 +1,32 @@
 use pagetop::prelude::*;
 
-use crate::{config, HljsLang, HljsMode, HljsTheme};
+use crate::css;
+use crate::{config, HljsLang, HljsMode, HljsTheme, HljsToken};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 
 // Context parameters.
 const PARAM_HLJS_ENABLED: &str = "hljs.enabled";
 const PARAM_HLJS_MODE: &str = "hljs.mode";
 const PARAM_HLJS_LANGS: &str = "hljs.langs";
 const PARAM_HLJS_THEME: &str = "hljs.theme";
+const PARAM_HLJS_DARK_THEME: &str = "hljs.dark_theme";
+const PARAM_HLJS_TOKEN_COLORS: &str = "hljs.token_colors";
+const PARAM_HLJS_FONT_FAMILY: &str = "hljs.font_family";
+const PARAM_HLJS_FONT_SIZE: &str = "hljs.font_size";
+const PARAM_HLJS_LINE_HEIGHT: &str = "hljs.line_height";
+const PARAM_HLJS_LIGATURES: &str = "hljs.ligatures";
+
+// Cookie with the theme chosen by the reader using a ThemeSwitcher, only applied to the pages
+// where a ThemeSwitcher is rendered.
+const COOKIE_HLJS_THEME: &str = "hljs-theme";
+
+// Context flags set by components to request optional assets.
+pub(crate) const PARAM_HLJS_COPY: &str = "hljs.copy";
+pub(crate) const PARAM_HLJS_TABS: &str = "hljs.tabs";
+pub(crate) const PARAM_HLJS_SWITCHER: &str = "hljs.switcher";
+pub(crate) const PARAM_HLJS_SCOPED_THEMES: &str = "hljs.scoped_themes";
 
 /// Extend Context with HighlightJS features.
 pub trait HljsContext {
@@ -29,16 +47,76 @@ pub trait HljsContext {
     fn add_hljs_language(&mut self, language: &HljsLang);
 
     /// Change the theme in current context for displaying code snippets. The same theme is used for
-    /// all snippets in the given context.
+    /// all snippets in the given context, except for those with their own
+    /// [`theme`](crate::Snippet::with_theme).
+    ///
+    /// With the [`accessible_only`](crate::config::Hljs#structfield.accessible_only) setting
+    /// enabled, themes that don't reach the WCAG AA contrast level are ignored, logging a warning,
+    /// and the theme of the context is kept.
     fn set_hljs_theme(&mut self, theme: &HljsTheme);
 
+    /// Change the theme in current context for displaying code snippets when the reader prefers a
+    /// dark color scheme, ignoring the
+    /// [`config::SETTINGS.hljs.dark_theme`](crate::config::Hljs#structfield.dark_theme)
+    /// configuration setting. The theme given by [`set_hljs_theme()`](Self::set_hljs_theme) is then
+    /// used for the light color scheme. Themes are ignored as in
+    /// [`set_hljs_theme()`](Self::set_hljs_theme).
+    fn set_hljs_dark_theme(&mut self, theme: &HljsTheme);
+
+    /// Override the color of a token in current context, on top of the theme and the
+    /// [`config::SETTINGS.hljs.token_colors`](crate::config::Hljs#structfield.token_colors)
+    /// configuration setting. Colors are CSS values like `"#e63946"` or `"rgb(230, 57, 70)"`,
+    /// invalid values are ignored.
+    fn set_hljs_token_color(&mut self, token: &HljsToken, color: &str);
+
+    /// Change the font family of the code in current context, ignoring the
+    /// [`config::SETTINGS.hljs.font_family`](crate::config::Hljs#structfield.font_family)
+    /// configuration setting. Invalid CSS values are ignored.
+    fn set_hljs_font_family(&mut self, family: &str);
+
+    /// Change the font size of the code blocks in current context, ignoring the
+    /// [`config::SETTINGS.hljs.font_size`](crate::config::Hljs#structfield.font_size)
+    /// configuration setting. Invalid CSS values are ignored.
+    fn set_hljs_font_size(&mut self, size: &str);
+
+    /// Change the line height of the code blocks in current context, ignoring the
+    /// [`config::SETTINGS.hljs.line_height`](crate::config::Hljs#structfield.line_height)
+    /// configuration setting. Invalid CSS values are ignored.
+    fn set_hljs_line_height(&mut self, height: &str);
+
+    /// Enable or disable the font ligatures of the code in current context, ignoring the
+    /// [`config::SETTINGS.hljs.ligatures`](crate::config::Hljs#structfield.ligatures)
+    /// configuration setting.
+    fn set_hljs_ligatures(&mut self, ligatures: bool);
+
     fn is_hljs_enabled(&self) -> bool;
 
     fn hljs_mode(&self) -> HljsMode;
 
     fn hljs_languages(&self) -> Option<HashSet<String>>;
 
+    /// Returns the languages added to the context, in the order they were added.
+    fn hljs_ordered_languages(&self) -> Option<Vec<HljsLang>>;
+
+    /// Returns the theme chosen by the reader if a [`ThemeSwitcher`](crate::ThemeSwitcher) is
+    /// rendered in the current context, or the theme of the current context otherwise.
     fn hljs_theme(&self) -> HljsTheme;
+
+    /// Returns the theme for the dark color scheme, or `None` if there is no dark theme or the
+    /// reader has chosen a theme with a [`ThemeSwitcher`](crate::ThemeSwitcher) rendered in the
+    /// current context.
+    fn hljs_dark_theme(&self) -> Option<HljsTheme>;
+
+    /// Returns the overridden token colors, from the configuration settings and the context.
+    fn hljs_token_colors(&self) -> Vec<(HljsToken, String)>;
+
+    fn hljs_font_family(&self) -> Option<String>;
+
+    fn hljs_font_size(&self) -> Option<String>;
+
+    fn hljs_line_height(&self) -> Option<String>;
+
+    fn hljs_ligatures(&self) -> Option<bool>;
 }
 
 impl HljsContext for Context {
@@ -56,6 +134,7 @@ impl HljsContext for Context {
 
     fn add_hljs_language(&mut self, language: &HljsLang) {
         let languages = match self.get_param::<String>(PARAM_HLJS_LANGS) {
+            Ok(previous) if previous.split(';').any(|l| l == language.to_string()) => return,
             Ok(previous) => concat_string!(previous, ";", language.to_string()),
             _ => language.to_string(),
         };
@@ -63,7 +142,49 @@ impl HljsContext for Context {
     }
 
     fn set_hljs_theme(&mut self, theme: &HljsTheme) {
-        self.set_param::<String>(PARAM_HLJS_THEME, &theme.to_string());
+        if is_allowed(theme) {
+            self.set_param::<String>(PARAM_HLJS_THEME, &theme.to_string());
+        }
+    }
+
+    fn set_hljs_dark_theme(&mut self, theme: &HljsTheme) {
+        if is_allowed(theme) {
+            self.set_param::<String>(PARAM_HLJS_DARK_THEME, &theme.to_string());
+        }
+    }
+
+    fn set_hljs_token_color(&mut self, token: &HljsToken, color: &str) {
+        if !HljsToken::is_valid_color(color) {
+            return;
+        }
+        let color = concat_string!(token.to_string(), ":", color.trim());
+        let colors = match self.get_param::<String>(PARAM_HLJS_TOKEN_COLORS) {
+            Ok(previous) => concat_string!(previous, ";", color),
+            _ => color,
+        };
+        self.set_param::<String>(PARAM_HLJS_TOKEN_COLORS, &colors);
+    }
+
+    fn set_hljs_font_family(&mut self, family: &str) {
+        if css::is_safe_value(family) {
+            self.set_param::<String>(PARAM_HLJS_FONT_FAMILY, &family.to_string());
+        }
+    }
+
+    fn set_hljs_font_size(&mut self, size: &str) {
+        if css::is_safe_value(size) {
+            self.set_param::<String>(PARAM_HLJS_FONT_SIZE, &size.to_string());
+        }
+    }
+
+    fn set_hljs_line_height(&mut self, height: &str) {
+        if css::is_safe_value(height) {
+            self.set_param::<String>(PARAM_HLJS_LINE_HEIGHT, &height.to_string());
+        }
+    }
+
+    fn set_hljs_ligatures(&mut self, ligatures: bool) {
+        self.set_param::<bool>(PARAM_HLJS_LIGATURES, &ligatures);
     }
 
     // HljsContext GETTERS.
@@ -78,15 +199,99 @@ impl HljsContext for Context {
     }
 
     fn hljs_languages(&self) -> Option<HashSet<String>> {
-        if let Ok(languages) = self.get_param::<String>(PARAM_HLJS_LANGS) {
-            let set: HashSet<String> = languages.split(';').map(|s| s.to_string()).collect();
-            return Some(set);
-        }
-        None
+        self.hljs_ordered_languages().map(|languages| {
+            languages
+                .iter()
+                .map(|language| language.to_string())
+                .collect()
+        })
+    }
+
+    fn hljs_ordered_languages(&self) -> Option<Vec<HljsLang>> {
+        self.get_param::<String>(PARAM_HLJS_LANGS)
+            .ok()
+            .map(|languages| {
+                languages
+                    .split(';')
+                    .filter_map(|language| HljsLang::from_str(language).ok())
+                    .collect()
+            })
     }
 
     fn hljs_theme(&self) -> HljsTheme {
-        self.get_param::<HljsTheme>(PARAM_HLJS_THEME)
+        switcher_theme(self)
+            .or(self.get_param::<HljsTheme>(PARAM_HLJS_THEME).ok())
             .unwrap_or(config::SETTINGS.hljs.theme)
     }
+
+    fn hljs_dark_theme(&self) -> Option<HljsTheme> {
+        // The theme chosen by the reader is used for both light and dark color schemes.
+        if switcher_theme(self).is_some() {
+            return None;
+        }
+        self.get_param::<HljsTheme>(PARAM_HLJS_DARK_THEME)
+            .ok()
+            .or(config::SETTINGS.hljs.dark_theme)
+    }
+
+    fn hljs_token_colors(&self) -> Vec<(HljsToken, String)> {
+        let mut colors: HashMap<HljsToken, String> = config::SETTINGS.hljs.token_colors.clone();
+        if let Ok(overrides) = self.get_param::<String>(PARAM_HLJS_TOKEN_COLORS) {
+            for (token, color) in overrides.split(';').filter_map(|c| c.split_once(':')) {
+                if let Ok(token) = HljsToken::from_str(token) {
+                    colors.insert(token, color.to_string());
+                }
+            }
+        }
+        HljsToken::all()
+            .into_iter()
+            .filter_map(|token| colors.remove(&token).map(|color| (token, color)))
+            .collect()
+    }
+
+    fn hljs_font_family(&self) -> Option<String> {
+        self.get_param::<String>(PARAM_HLJS_FONT_FAMILY)
+            .ok()
+            .or_else(|| config::SETTINGS.hljs.font_family.to_owned())
+    }
+
+    fn hljs_font_size(&self) -> Option<String> {
+        self.get_param::<String>(PARAM_HLJS_FONT_SIZE)
+            .ok()
+            .or_else(|| config::SETTINGS.hljs.font_size.to_owned())
+    }
+
+    fn hljs_line_height(&self) -> Option<String> {
+        self.get_param::<String>(PARAM_HLJS_LINE_HEIGHT)
+            .ok()
+            .or_else(|| config::SETTINGS.hljs.line_height.to_owned())
+    }
+
+    fn hljs_ligatures(&self) -> Option<bool> {
+        self.get_param::<bool>(PARAM_HLJS_LIGATURES)
+            .ok()
+            .or(config::SETTINGS.hljs.ligatures)
+    }
+}
+
+// Returns the theme chosen by the reader, if allowed and a ThemeSwitcher is rendered on the page.
+fn switcher_theme(cx: &Context) -> Option<HljsTheme> {
+    if !cx.get_param::<bool>(PARAM_HLJS_SWITCHER).unwrap_or(false) {
+        return None;
+    }
+    cx.request()
+        .cookie(COOKIE_HLJS_THEME)
+        .and_then(|cookie| HljsTheme::from_str(cookie.value()).ok())
+        .filter(|theme| theme.is_allowed())
+}
+
+// Returns `true` if the theme can be used in the context, logging a warning otherwise.
+fn is_allowed(theme: &HljsTheme) -> bool {
+    let allowed = theme.is_allowed();
+    if !allowed {
+        if let Err(error) = theme.check_contrast() {
+            trace::warn!("Theme ignored, {error}");
+        }
+    }
+    allowed
 }
@@ -0,0 +1,178 @@
+//! Audit the contrast of theme colors according to the WCAG guidelines.
+
+use crate::css;
+
+/// Conformance levels of the [WCAG](https://www.w3.org/TR/WCAG21/#contrast-minimum) contrast
+/// requirements for normal text.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum WcagLevel {
+    /// Contrast ratio of at least 4.5:1.
+    AA,
+    /// Contrast ratio of at least 7:1.
+    AAA,
+}
+
+impl WcagLevel {
+    /// Returns the minimum contrast ratio required by the level.
+    pub fn min_ratio(&self) -> f64 {
+        match self {
+            WcagLevel::AA => 4.5,
+            WcagLevel::AAA => 7.0,
+        }
+    }
+}
+
+/// Contrast of the color given to a `.hljs` or `.hljs-*` selector against its background.
+#[derive(Clone, Debug)]
+pub struct TokenContrast {
+    selector: String,
+    color: String,
+    background: String,
+    ratio: f64,
+}
+
+impl TokenContrast {
+    // TokenContrast GETTERS.
+
+    /// Returns the selector, like `.hljs-comment` or `.hljs-meta .hljs-keyword`.
+    pub fn selector(&self) -> &str {
+        &self.selector
+    }
+
+    /// Returns the text color as `#rrggbb`.
+    pub fn color(&self) -> &str {
+        &self.color
+    }
+
+    /// Returns the background color as `#rrggbb`.
+    pub fn background(&self) -> &str {
+        &self.background
+    }
+
+    /// Returns the contrast ratio, from 1 to 21.
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    pub fn passes(&self, level: WcagLevel) -> bool {
+        self.ratio >= level.min_ratio()
+    }
+}
+
+/// Contrast of every token color of a theme against the theme background.
+///
+/// ```rust
+/// use pagetop_hljs::{HljsTheme, WcagLevel};
+///
+/// let audit = HljsTheme::A11yDark.contrast_audit().unwrap();
+/// assert!(audit.passes(WcagLevel::AA));
+///
+/// let audit = HljsTheme::Github.contrast_audit().unwrap();
+/// for token in audit.failures(WcagLevel::AAA) {
+///     println!("{} {:.2}:1", token.selector(), token.ratio());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ContrastAudit {
+    tokens: Vec<TokenContrast>,
+}
+
+impl ContrastAudit {
+    // ContrastAudit GETTERS.
+
+    pub fn tokens(&self) -> &[TokenContrast] {
+        &self.tokens
+    }
+
+    /// Returns the lowest contrast ratio of the theme.
+    pub fn min_ratio(&self) -> f64 {
+        self.tokens
+            .iter()
+            .map(|token| token.ratio)
+            .fold(21.0, f64::min)
+    }
+
+    /// Returns the highest level passed by every token color, if any.
+    pub fn level(&self) -> Option<WcagLevel> {
+        [WcagLevel::AAA, WcagLevel::AA]
+            .into_iter()
+            .find(|&level| self.passes(level))
+    }
+
+    pub fn passes(&self, level: WcagLevel) -> bool {
+        self.tokens.iter().all(|token| token.passes(level))
+    }
+
+    /// Returns the token colors that don't reach the given level.
+    pub fn failures(&self, level: WcagLevel) -> Vec<&TokenContrast> {
+        self.tokens
+            .iter()
+            .filter(|token| !token.passes(level))
+            .collect()
+    }
+}
+
+// Audits the token colors of the theme stylesheet, which is not possible without a background.
+pub(crate) fn audit(theme_css: &str) -> Option<ContrastAudit> {
+    let rules = css::rules(theme_css);
+    let color_of = |declarations: &[(String, String)], properties: &[&str]| {
+        declarations
+            .iter()
+            .filter(|(property, _)| properties.contains(&property.as_str()))
+            .filter_map(|(_, value)| css::color(value))
+            .next_back()
+    };
+
+    let mut background = None;
+    for (selectors, declarations) in rules.iter() {
+        if selectors.iter().any(|selector| selector == ".hljs") {
+            background = color_of(declarations, &["background", "background-color"]).or(background);
+        }
+    }
+    let background = background?;
+
+    let mut tokens: Vec<TokenContrast> = Vec::new();
+    for (selectors, declarations) in rules.iter() {
+        let Some(color) = color_of(declarations, &["color"]) else {
+            continue;
+        };
+        let token_background = match color_of(declarations, &["background", "background-color"]) {
+            Some(token_background) => token_background,
+            // Colors over background images without a fallback color can't be audited.
+            None if has_background_image(declarations) => continue,
+            None => background.to_owned(),
+        };
+        let Some(ratio) = contrast_ratio(&color, &token_background) else {
+            continue;
+        };
+        for selector in selectors.iter() {
+            if !selector.contains(".hljs") || selector.contains("::") {
+                continue;
+            }
+            let token = TokenContrast {
+                selector: selector.to_owned(),
+                color: color.to_owned(),
+                background: token_background.to_owned(),
+                ratio,
+            };
+            // Later rules override the colors of the previous ones.
+            match tokens.iter_mut().find(|t| t.selector == token.selector) {
+                Some(previous) => *previous = token,
+                None => tokens.push(token),
+            }
+        }
+    }
+    Some(ContrastAudit { tokens })
+}
+
+fn has_background_image(declarations: &[(String, String)]) -> bool {
+    declarations
+        .iter()
+        .any(|(property, value)| property.starts_with("background") && value.contains("url("))
+}
+
+// Returns the contrast ratio between two colors given as `#rrggbb`.
+fn contrast_ratio(color: &str, background: &str) -> Option<f64> {
+    let (l1, l2) = (css::luminance(color)?, css::luminance(background)?);
+    Some((l1.max(l2) + 0.05) / (l1.min(l2) + 0.05))
+}
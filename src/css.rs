@@ -0,0 +1,331 @@
+// Processing of the theme stylesheets bundled with the package.
+
+include!(concat!(env!("OUT_DIR"), "/theme_css.rs"));
+
+// Returns the CSS of the given bundled theme.
+pub(crate) fn theme_css(theme: &str) -> Option<&'static str> {
+    THEME_CSS
+        .iter()
+        .find_map(|&(name, css)| if name == theme { Some(css) } else { None })
+}
+
+// Returns the CSS with every selector scoped under the given class, so that several themes can
+// coexist on the same page. Tokens in the scope are reset first, so the page theme doesn't style
+// them where the scoped theme doesn't. Relative URLs are adjusted to be served one level below
+// `/hljs/css/`.
+pub(crate) fn scoped_css(css: &str, scope: &str) -> String {
+    let css = strip_comments(css).replace("url(./", "url(../");
+    let mut scoped = String::with_capacity(css.len() * 2);
+    // Same specificity as the page theme rules, but lower than the scoped ones.
+    scoped.push_str(&format!(
+        ".{scope} :where(code [class*=\"hljs-\"]){{all:unset}}"
+    ));
+    scope_rules(&css, scope, &mut scoped);
+    scoped
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut stripped = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+// Scopes rules until the end of the CSS or the end of the enclosing block, returning the remaining
+// CSS after the block.
+fn scope_rules<'a>(css: &'a str, scope: &str, scoped: &mut String) -> &'a str {
+    let mut rest = css;
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix('}') {
+            return after;
+        }
+        let Some(open) = rest.find('{') else {
+            return "";
+        };
+        let prelude = rest[..open].trim();
+        let block = &rest[open + 1..];
+        if prelude.starts_with('@') {
+            // At-rules like @media enclose nested rules.
+            scoped.push_str(prelude);
+            scoped.push('{');
+            rest = scope_rules(block, scope, scoped);
+            scoped.push('}');
+        } else {
+            let selectors: Vec<String> = prelude
+                .split(',')
+                .map(|selector| format!(".{scope} {}", selector.trim()))
+                .collect();
+            scoped.push_str(&selectors.join(","));
+            scoped.push('{');
+            match block.find('}') {
+                Some(close) => {
+                    scoped.push_str(&block[..=close]);
+                    rest = &block[close + 1..];
+                }
+                None => {
+                    scoped.push_str(block);
+                    scoped.push('}');
+                    return "";
+                }
+            }
+        }
+    }
+}
+
+// Returns the value of a field in the header comment of the theme, like `GitHub` for `Theme:`.
+pub(crate) fn header_field(css: &str, field: &str) -> Option<String> {
+    let start = css.find("/*")?;
+    let end = css[start..].find("*/").map_or(css.len(), |end| start + end);
+    css[start..end].lines().find_map(|line| {
+        line.trim()
+            .strip_prefix(field)
+            .and_then(|value| value.strip_prefix(':'))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    })
+}
+
+// Returns the declarations, in order, of the top-level rules that include the given selector.
+pub(crate) fn declarations(css: &str, selector: &str) -> Vec<(String, String)> {
+    rules(css)
+        .into_iter()
+        .filter(|(selectors, _)| selectors.iter().any(|s| s == selector))
+        .flat_map(|(_, declarations)| declarations)
+        .collect()
+}
+
+// Selectors and declarations of a rule.
+pub(crate) type Rule = (Vec<String>, Vec<(String, String)>);
+
+// Returns the selectors and declarations of the top-level rules, ignoring at-rules like @media.
+pub(crate) fn rules(css: &str) -> Vec<Rule> {
+    let css = strip_comments(css);
+    let mut rules = Vec::new();
+    let mut rest = css.as_str();
+    while let Some(open) = rest.find('{') {
+        let prelude = rest[..open].trim();
+        let block = &rest[open + 1..];
+        let close = block_end(block);
+        if !prelude.starts_with('@') {
+            let selectors = prelude.split(',').map(|s| s.trim().to_string()).collect();
+            let declarations = split_declarations(&block[..close])
+                .into_iter()
+                .filter_map(|declaration| declaration.split_once(':'))
+                .map(|(property, value)| (property.trim().to_string(), value.trim().to_string()))
+                .collect();
+            rules.push((selectors, declarations));
+        }
+        rest = block.get(close + 1..).unwrap_or_default();
+    }
+    rules
+}
+
+// Returns `true` if the value can't break out of the CSS declaration where it is used.
+pub(crate) fn is_safe_value(value: &str) -> bool {
+    !value.trim().is_empty() && !value.contains([';', '{', '}', '<', '>', '\\'])
+}
+
+// Returns `true` if the value is an absolute path or an HTTP(S) URL that can be safely linked.
+pub(crate) fn is_safe_url(url: &str) -> bool {
+    (url.starts_with('/') || url.starts_with("https://") || url.starts_with("http://"))
+        && !url.starts_with("//")
+        && !url.contains(|c: char| c.is_whitespace() || "\"'<>\\".contains(c))
+}
+
+// Returns the file names of the images referenced by the theme, like `pojoaque.jpg`.
+pub(crate) fn assets(css: &str) -> Vec<String> {
+    css.split("url(./")
+        .skip(1)
+        .filter_map(|url| url.split(')').next())
+        .map(|url| url.trim_matches(['"', '\'']).to_string())
+        .collect()
+}
+
+// Returns the color as `#rrggbb` from a CSS value like `#fff`, `navy` or `url(a.png) #b7a68e`.
+pub(crate) fn color(value: &str) -> Option<String> {
+    let value = value.trim_end_matches("!important");
+    let mut tokens = Vec::new();
+    let mut depth = 0;
+    let mut token = String::new();
+    for c in value.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c.is_whitespace() && depth == 0 => {
+                tokens.push(std::mem::take(&mut token));
+                continue;
+            }
+            _ => {}
+        }
+        token.push(c);
+    }
+    tokens.push(token);
+    tokens.iter().rev().find_map(|token| parse_color(token))
+}
+
+// Returns the red, green and blue components of a color given as `#rrggbb`.
+pub(crate) fn rgb(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.strip_prefix('#').filter(|hex| hex.len() == 6)?;
+    let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((component(0)?, component(2)?, component(4)?))
+}
+
+// Returns the relative luminance of a color given as `#rrggbb`, as defined by WCAG 2.
+pub(crate) fn luminance(color: &str) -> Option<f64> {
+    let (r, g, b) = rgb(color)?;
+    let linear = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+}
+
+// Returns the position of the brace closing the given block, skipping nested blocks.
+fn block_end(block: &str) -> usize {
+    let mut depth = 0;
+    for (i, c) in block.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return i,
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    block.len()
+}
+
+// Splits the declarations of a block, ignoring semicolons inside parentheses like in data URLs.
+fn split_declarations(block: &str) -> Vec<&str> {
+    let mut declarations = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in block.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ';' if depth == 0 => {
+                declarations.push(&block[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&block[start..]);
+    declarations
+}
+
+fn parse_color(token: &str) -> Option<String> {
+    let token = token.trim().to_ascii_lowercase();
+    if let Some(hex) = token.strip_prefix('#') {
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        return match hex.len() {
+            3 | 4 => Some(hex.chars().take(3).fold(String::from("#"), |mut color, c| {
+                color.push(c);
+                color.push(c);
+                color
+            })),
+            6 | 8 => Some(format!("#{}", &hex[..6])),
+            _ => None,
+        };
+    }
+    if let Some(args) = token
+        .strip_prefix("rgba(")
+        .or_else(|| token.strip_prefix("rgb("))
+        .and_then(|args| args.strip_suffix(')'))
+    {
+        let components: Vec<u8> = args
+            .split([',', ' ', '/'])
+            .filter(|c| !c.is_empty())
+            .take(3)
+            .filter_map(|c| c.parse::<f64>().ok())
+            .map(|c| c.clamp(0.0, 255.0).round() as u8)
+            .collect();
+        return match components[..] {
+            [r, g, b] => Some(format!("#{r:02x}{g:02x}{b:02x}")),
+            _ => None,
+        };
+    }
+    let named = match token.as_str() {
+        "black" => "#000000",
+        "white" => "#ffffff",
+        "gray" | "grey" => "#808080",
+        "silver" => "#c0c0c0",
+        "maroon" => "#800000",
+        "red" => "#ff0000",
+        "purple" => "#800080",
+        "fuchsia" => "#ff00ff",
+        "green" => "#008000",
+        "lime" => "#00ff00",
+        "olive" => "#808000",
+        "yellow" => "#ffff00",
+        "navy" => "#000080",
+        "blue" => "#0000ff",
+        "teal" => "#008080",
+        "aqua" => "#00ffff",
+        "orange" => "#ffa500",
+        "gold" => "#ffd700",
+        _ => return None,
+    };
+    Some(named.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scoped(css: &str) -> String {
+        let mut scoped = String::new();
+        assert_eq!(scope_rules(css, "s", &mut scoped), "");
+        scoped
+    }
+
+    #[test]
+    fn scopes_selector_lists() {
+        assert_eq!(
+            scoped(".hljs-title,\n.hljs-section { color: red }"),
+            ".s .hljs-title,.s .hljs-section{ color: red }"
+        );
+    }
+
+    #[test]
+    fn scopes_rules_nested_in_media_rules() {
+        assert_eq!(
+            scoped("@media (min-width: 1px) { .hljs { color: red } .x, .y { } } .z { }"),
+            "@media (min-width: 1px){.s .hljs{ color: red }.s .x,.s .y{ }}.s .z{ }"
+        );
+    }
+
+    #[test]
+    fn closes_unterminated_blocks() {
+        assert_eq!(scoped(".a { color: red"), ".s .a{ color: red}");
+        assert_eq!(
+            scoped("@media print { .a { color: red }"),
+            "@media print{.s .a{ color: red }}"
+        );
+        assert_eq!(scoped(".a { } .b"), ".s .a{ }");
+    }
+
+    #[test]
+    fn resets_tokens_and_rewrites_relative_urls() {
+        let css = scoped_css("/* Theme */.hljs{background:url(./bg.png)}", "hljs-scope-x");
+        assert_eq!(
+            css,
+            ".hljs-scope-x :where(code [class*=\"hljs-\"]){all:unset}\
+            .hljs-scope-x .hljs{background:url(../bg.png)}"
+        );
+    }
+}
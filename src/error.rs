@@ -0,0 +1,300 @@
+use std::fmt;
+
+/// Errors returned when parsing the values used by the package or loading the configuration
+/// settings, carrying the rejected value and, if any, the most similar valid names as suggestions.
+///
+/// ```rust
+/// use pagetop_hljs::{HljsError, HljsLang, HljsTheme};
+///
+/// let error = "githib".parse::<HljsTheme>().unwrap_err();
+/// assert_eq!(error.value(), "githib");
+/// assert_eq!(error.suggestions(), ["github"]);
+/// assert_eq!(error.to_string(), "unknown theme \"githib\", did you mean \"github\"?");
+///
+/// let error = "rsx".parse::<HljsLang>().unwrap_err();
+/// assert!(matches!(error, HljsError::Language { .. }));
+/// ```
+///
+/// New variants may be added in future versions, so matching must include a wildcard arm.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum HljsError {
+    /// The value is neither the name nor an alias of any language.
+    Language {
+        /// The rejected value.
+        value: String,
+        /// The most similar language names, the closest first.
+        suggestions: Vec<String>,
+    },
+    /// The value is not the name of a bundled or registered theme.
+    Theme {
+        /// The rejected value.
+        value: String,
+        /// The most similar theme names, the closest first.
+        suggestions: Vec<String>,
+    },
+    /// The value can't be the name of a custom theme, because it is not given in *kebab-case* or
+    /// is the name of a bundled theme.
+    CustomTheme {
+        /// The rejected name.
+        value: String,
+    },
+    /// The value is the name of a theme that doesn't reach the WCAG AA contrast level required by
+    /// the [`accessible_only`](crate::config::Hljs#structfield.accessible_only) setting.
+    Contrast {
+        /// The rejected theme name.
+        value: String,
+        /// The most similar names of themes reaching the WCAG AA contrast level, the closest first.
+        suggestions: Vec<String>,
+    },
+    /// The value is the name of a custom theme whose contrast can't be checked, as required by the
+    /// [`accessible_only`](crate::config::Hljs#structfield.accessible_only) setting, because its
+    /// stylesheet is only given by URL.
+    Unaudited {
+        /// The rejected theme name.
+        value: String,
+    },
+    /// The value is not the name of a mode.
+    Mode {
+        /// The rejected value.
+        value: String,
+        /// The most similar mode names, the closest first.
+        suggestions: Vec<String>,
+    },
+    /// The value is not the name of a token.
+    Token {
+        /// The rejected value.
+        value: String,
+        /// The most similar token names, the closest first.
+        suggestions: Vec<String>,
+    },
+    /// The value is not a color that can be used for the given token.
+    TokenColor {
+        /// The name of the token.
+        token: String,
+        /// The rejected color.
+        value: String,
+    },
+    /// The value can't be used in the given configuration setting.
+    Setting {
+        /// The name of the setting, like `hljs.font_url`.
+        name: String,
+        /// The rejected value.
+        value: String,
+    },
+}
+
+impl HljsError {
+    // HljsError GETTERS.
+
+    /// Returns the rejected value.
+    pub fn value(&self) -> &str {
+        match self {
+            HljsError::Language { value, .. }
+            | HljsError::Theme { value, .. }
+            | HljsError::Mode { value, .. }
+            | HljsError::Token { value, .. }
+            | HljsError::TokenColor { value, .. }
+            | HljsError::CustomTheme { value }
+            | HljsError::Setting { value, .. }
+            | HljsError::Contrast { value, .. }
+            | HljsError::Unaudited { value } => value,
+        }
+    }
+
+    /// Returns the valid names most similar to the rejected value, the closest first.
+    pub fn suggestions(&self) -> &[String] {
+        match self {
+            HljsError::Language { suggestions, .. }
+            | HljsError::Theme { suggestions, .. }
+            | HljsError::Mode { suggestions, .. }
+            | HljsError::Token { suggestions, .. }
+            | HljsError::Contrast { suggestions, .. } => suggestions,
+            HljsError::TokenColor { .. }
+            | HljsError::CustomTheme { .. }
+            | HljsError::Setting { .. }
+            | HljsError::Unaudited { .. } => &[],
+        }
+    }
+}
+
+impl fmt::Display for HljsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HljsError::Language { value, .. } => write!(f, "unknown language \"{value}\"")?,
+            HljsError::Theme { value, .. } => write!(f, "unknown theme \"{value}\"")?,
+            HljsError::Mode { value, .. } => write!(f, "unknown mode \"{value}\"")?,
+            HljsError::Token { value, .. } => write!(f, "unknown token \"{value}\"")?,
+            HljsError::TokenColor { token, value } => {
+                write!(f, "invalid color \"{value}\" for token \"{token}\"")?
+            }
+            HljsError::CustomTheme { value } => write!(
+                f,
+                "invalid custom theme \"{value}\", use a kebab-case name other than the bundled \
+                themes"
+            )?,
+            HljsError::Setting { name, value } => {
+                write!(f, "invalid value \"{value}\" in the {name} setting")?
+            }
+            HljsError::Contrast { value, .. } => write!(
+                f,
+                "theme \"{value}\" doesn't reach the WCAG AA contrast level required by \
+                hljs.accessible_only"
+            )?,
+            HljsError::Unaudited { value } => write!(
+                f,
+                "the contrast of theme \"{value}\" required by hljs.accessible_only can't be \
+                checked, give its stylesheet with `css` instead of `url`"
+            )?,
+        }
+        let suggestions: Vec<String> = self
+            .suggestions()
+            .iter()
+            .map(|suggestion| format!("\"{suggestion}\""))
+            .collect();
+        match suggestions.split_last() {
+            Some((last, [])) => write!(f, ", did you mean {last}?"),
+            Some((last, others)) => write!(f, ", did you mean {} or {last}?", others.join(", ")),
+            None => match self {
+                HljsError::Theme { .. } => write!(
+                    f,
+                    ", the available themes are listed by HljsTheme::all() and shown in the \
+                    /hljs/gallery page when hljs.gallery is enabled"
+                ),
+                _ => Ok(()),
+            },
+        }
+    }
+}
+
+impl std::error::Error for HljsError {}
+
+/// Errors returned when creating a [`Base16Scheme`](crate::Base16Scheme), naming the color that
+/// failed by its base16 key, from `base00` to `base0F`.
+///
+/// ```rust
+/// use pagetop_hljs::{Base16Error, Base16Scheme};
+///
+/// let error = Base16Scheme::from_yaml("scheme: Empty").unwrap_err();
+/// assert_eq!(error, Base16Error::MissingColor { key: "base00".to_string() });
+/// assert_eq!(error.to_string(), "missing color \"base00\" in the base16 scheme");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Base16Error {
+    /// The color with the given key is not in the scheme.
+    MissingColor {
+        /// The key of the color, like `base0A`.
+        key: String,
+    },
+    /// The value of the color with the given key is not given with six hexadecimal digits.
+    InvalidColor {
+        /// The key of the color, like `base0A`.
+        key: String,
+        /// The rejected color.
+        value: String,
+    },
+}
+
+impl fmt::Display for Base16Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Base16Error::MissingColor { key } => {
+                write!(f, "missing color \"{key}\" in the base16 scheme")
+            }
+            Base16Error::InvalidColor { key, value } => write!(
+                f,
+                "invalid color \"{value}\" for \"{key}\" in the base16 scheme, expected six \
+                hexadecimal digits like \"2b303b\""
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Base16Error {}
+
+// Returns up to three names similar to the value, by edit distance or because one starts with the
+// other, ignoring case and the separators between words.
+pub(crate) fn suggestions<'a>(
+    value: &str,
+    names: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let normalize = |s: &str| s.trim().to_lowercase().replace([' ', '_'], "-");
+    let prefix = |a: &str, b: &str| b.len() >= 3 && a.starts_with(b);
+    let value = normalize(value);
+    let max_distance = (value.chars().count() / 3).max(1);
+
+    let mut similar: Vec<(usize, &str)> = names
+        .into_iter()
+        .filter_map(|name| {
+            let normalized = normalize(name);
+            let distance = edit_distance(&value, &normalized);
+            if distance <= max_distance
+                || prefix(&normalized, &value)
+                || prefix(&value, &normalized)
+            {
+                Some((distance, name))
+            } else {
+                None
+            }
+        })
+        .collect();
+    similar.sort();
+    similar.dedup();
+    similar
+        .into_iter()
+        .take(3)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("github", "github"), 0);
+        assert_eq!(edit_distance("githib", "github"), 1);
+        assert_eq!(edit_distance("rust", "rsut"), 2);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "core"), 4);
+        assert_eq!(edit_distance("ñandú", "nandu"), 2);
+    }
+
+    #[test]
+    fn suggestions_are_the_closest_names_first() {
+        let names = ["github", "github-dark", "gml", "monokai", "vs", "vs2015"];
+        assert_eq!(suggestions("githib", names), ["github"]);
+        assert_eq!(suggestions("GitHub Dark", names), ["github-dark", "github"]);
+        assert_eq!(suggestions("vs20", names), ["vs2015"]);
+        assert_eq!(suggestions("zenburn", names), Vec::<String>::new());
+    }
+
+    #[test]
+    fn suggestions_are_at_most_three() {
+        let names = [
+            "atom-one-dark",
+            "atom-one-light",
+            "atom-one-dark-reasonable",
+            "agate",
+        ];
+        assert_eq!(suggestions("atom", names).len(), 3);
+    }
+}
@@ -0,0 +1,217 @@
+// Gallery of themes, mounted when `hljs.gallery` is enabled in the configuration settings.
+
+use pagetop::prelude::*;
+
+use crate::{HljsContext, HljsLang, HljsTheme, Snippet};
+use crate::{LOCALES_HLJS, PKG_VERSION};
+
+use serde::Deserialize;
+
+use std::str::FromStr;
+
+// Sample snippets displayed in the gallery, the first one by default.
+const SAMPLES: &[(HljsLang, &str)] = &[
+    (
+        HljsLang::Rust,
+        r#"
+/// Returns the greeting for the given name.
+#[inline]
+pub fn greet(name: &str) -> String {
+    let count = name.chars().count(); // Characters, not bytes.
+    match count {
+        0 => String::from("Hello, world!"),
+        _ => format!("Hello, {name} ({count} letters)!"),
+    }
+}
+        "#,
+    ),
+    (
+        HljsLang::Python,
+        r#"
+import math
+
+@dataclass
+class Circle:
+    """A circle with a given radius."""
+    radius: float = 1.0
+
+    def area(self) -> float:
+        # Uses the math module.
+        return math.pi * self.radius ** 2
+
+print(f"Area: {Circle(2).area():.2f}", None, True)
+        "#,
+    ),
+    (
+        HljsLang::JavaScript,
+        r#"
+// Fetches the user and logs its name.
+async function showUser(id = 1) {
+    const response = await fetch(`/api/users/${id}`);
+    if (!response.ok) {
+        throw new Error('Not found: ' + response.status);
+    }
+    const { name } = await response.json();
+    console.log(name, null, true, 3.14);
+}
+        "#,
+    ),
+    (
+        HljsLang::HTML,
+        r#"
+<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <title>Sample page</title>
+    <link rel="stylesheet" href="/theme.css">
+  </head>
+  <body class="home">
+    <!-- Main content -->
+    <h1 id="title">Hello &amp; welcome!</h1>
+  </body>
+</html>
+        "#,
+    ),
+    (
+        HljsLang::CSS,
+        r#"
+@media (min-width: 768px) {
+  /* Two columns on wide screens. */
+  .layout > .column:first-child {
+    width: calc(50% - 1rem);
+    color: #336699 !important;
+    font-family: "Fira Code", monospace;
+  }
+}
+        "#,
+    ),
+    (
+        HljsLang::Bash,
+        r#"
+#!/bin/bash
+# Backs up every configuration file.
+for file in "$HOME"/.config/*.toml; do
+    if [ -f "$file" ]; then
+        cp "$file" "${file}.bak" && echo "Saved $file"
+    fi
+done
+exit 0
+        "#,
+    ),
+    (
+        HljsLang::SQL,
+        r#"
+-- Top customers by total amount.
+SELECT c.name, SUM(o.amount) AS total
+FROM customers c
+JOIN orders o ON o.customer_id = c.id
+WHERE o.created_at >= '2024-01-01' AND o.status <> 'cancelled'
+GROUP BY c.name
+ORDER BY total DESC
+LIMIT 10;
+        "#,
+    ),
+];
+
+#[derive(Deserialize)]
+pub(crate) struct GalleryQuery {
+    lang: Option<String>,
+}
+
+// Returns the sample in the language given by the `lang` query parameter, the first one if there
+// is no parameter, or `None` if there is no sample in the given language.
+fn find_sample(query: &GalleryQuery) -> Option<&'static (HljsLang, &'static str)> {
+    match query.lang.as_deref() {
+        Some(lang) => {
+            let lang = HljsLang::from_str(lang).ok()?;
+            SAMPLES.iter().find(|(language, _)| *language == lang)
+        }
+        None => SAMPLES.first(),
+    }
+}
+
+// Displays a sample snippet, in the language given by the `lang` query parameter, in every theme.
+// Each theme is displayed in its own frame to link the stylesheet given by `HljsTheme::to_url`.
+pub(crate) async fn gallery(
+    request: HttpRequest,
+    query: service::web::Query<GalleryQuery>,
+) -> ResultPage<Markup, ErrorPage> {
+    let Some((language, _)) = find_sample(&query) else {
+        return Err(ErrorPage::NotFound(request));
+    };
+
+    let mut page = Page::new(request).with_title(L10n::t("hljs_gallery", &LOCALES_HLJS));
+    let show = L10n::t("hljs_gallery_show", &LOCALES_HLJS)
+        .using(page.context().langid())
+        .unwrap_or_default();
+    page = page.with_component(Html::with(html! {
+        form class="hljs-gallery-form" method="get" {
+            select name="lang" data-hljs-gallery-lang {
+                @for (lang, _) in SAMPLES {
+                    option value=(lang.to_string()) selected[lang == language] {
+                        (lang.name())
+                    }
+                }
+            }
+            noscript { button type="submit" { (show) } }
+        }
+    }));
+    // Themes not allowed by `hljs.accessible_only` would be ignored in the frames.
+    for theme in HljsTheme::all().into_iter().filter(HljsTheme::is_allowed) {
+        let url = concat_string!(
+            "/hljs/gallery/",
+            theme.to_string(),
+            "?lang=",
+            language.to_string()
+        );
+        page = page.with_component(Html::with(html! {
+            h2 class="hljs-gallery-theme" id=(theme.to_string()) {
+                (theme.display_name()) " "
+                a href=(HljsTheme::to_url(theme.to_string())) { small { "CSS" } }
+            }
+            iframe
+                class="hljs-gallery-sample"
+                src=(url)
+                title=(theme.display_name())
+                loading="lazy"
+                width="100%"
+                height="360"
+            {}
+        }));
+    }
+    page.context().set_assets(AssetsOp::AddJavaScript(
+        JavaScript::at("/hljs/snippet/gallery.js")
+            .with_version(PKG_VERSION)
+            .with_mode(ModeJS::Defer),
+    ));
+    page.render()
+}
+
+// Displays the sample snippet in the given theme, linking the stylesheet given by
+// `HljsTheme::to_url` like any other page using the theme.
+pub(crate) async fn gallery_sample(
+    request: HttpRequest,
+    theme: service::web::Path<String>,
+    query: service::web::Query<GalleryQuery>,
+) -> ResultPage<Markup, ErrorPage> {
+    let (Ok(theme), Some((language, sample))) = (HljsTheme::from_str(&theme), find_sample(&query))
+    else {
+        return Err(ErrorPage::NotFound(request));
+    };
+    if !theme.is_allowed() {
+        return Err(ErrorPage::NotFound(request));
+    }
+
+    let mut page = Page::new(request)
+        .with_title(L10n::n(theme.display_name()))
+        .with_component(
+            Snippet::with(*language, *sample)
+                .with_line_numbers(true)
+                .with_highlight_lines("2"),
+        );
+    let cx = page.context();
+    cx.set_hljs_theme(&theme);
+    // The same theme for the dark color scheme, so the stylesheet is linked as is.
+    cx.set_hljs_dark_theme(&theme);
+    page.render()
+}
@@ -0,0 +1,1000 @@
+//! Server-side syntax highlighting for the ***server*** mode.
+//!
+//! Code is highlighted with the grammars of the highlight.js *common* library, compiled by the
+//! library itself into `highlight/grammars.rs`, and with the same algorithm of the library, so the
+//! markup is the same rendered by *highlight.js* in the browser and the stylesheets of all the
+//! [`HljsTheme`](crate::HljsTheme)s apply to it. This includes the languages embedded in others,
+//! like CSS and JavaScript in HTML, and the relevance used to pick the best of several. Code in
+//! languages not preloaded by the *common* library is rendered as escaped plain text.
+//!
+//! The regular expressions of the grammars are matched with the semantics of JavaScript over the
+//! UTF-16 code units of the code (see `highlight/regex.rs`).
+
+use pagetop::prelude::*;
+
+use crate::HljsLang;
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, OnceLock};
+
+#[rustfmt::skip]
+mod grammars;
+mod regex;
+
+use grammars::LANGUAGES;
+use regex::{Captures, Flags, Regex};
+
+/// Highlights the code, or escapes it when the language has no server-side grammar.
+pub(crate) fn highlight(language: &HljsLang, code: &str) -> Markup {
+    let code: Vec<u16> = code.encode_utf16().collect();
+    let markup = match language_index(&language.to_string()) {
+        Some(language) => {
+            let result = Session::default().run(language, None, &code, true, None);
+            match result.failed {
+                false => result.emitter.render(),
+                true => escape(&code),
+            }
+        }
+        None => escape(&code),
+    };
+    // Lone surrogates left by splitting astral characters are replaced, as when sent by Node.js.
+    PreEscaped(String::from_utf16_lossy(&markup))
+}
+
+// Language compiled by highlight.js, with its modes indexed by position, the language first.
+struct Language {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    case_insensitive: bool,
+    unicode: bool,
+    disable_autodetect: bool,
+    superset_of: Option<&'static str>,
+    class_name_aliases: &'static [(&'static str, &'static str)],
+    modes: &'static [Mode],
+}
+
+// Mode of a grammar, with the same meaning as in highlight.js.
+struct Mode {
+    scope: Option<&'static str>,
+    begin_scope: Option<Scopes>,
+    end_scope: Option<Scopes>,
+    begin: &'static str,
+    end: Option<&'static str>,
+    // Expression ending the mode, or any of the parents it ends with.
+    terminator_end: &'static str,
+    illegal: Option<&'static str>,
+    // Keywords sorted by name, with their scope and relevance.
+    keywords: Option<&'static [(&'static str, &'static str, u8)]>,
+    keyword_pattern: &'static str,
+    contains: &'static [usize],
+    // Mode started when this one ends.
+    starts: Option<usize>,
+    relevance: f64,
+    sub_language: Option<SubLanguage>,
+    exclude_begin: bool,
+    exclude_end: bool,
+    return_begin: bool,
+    return_end: bool,
+    ends_with_parent: bool,
+    ends_parent: bool,
+    skip: bool,
+    before_begin: Option<Callback>,
+    on_begin: Option<Callback>,
+    on_end: Option<Callback>,
+}
+
+const MODE: Mode = Mode {
+    scope: None,
+    begin_scope: None,
+    end_scope: None,
+    begin: "",
+    end: None,
+    terminator_end: "",
+    illegal: None,
+    keywords: None,
+    keyword_pattern: r"\w+",
+    contains: &[],
+    starts: None,
+    relevance: 1.0,
+    sub_language: None,
+    exclude_begin: false,
+    exclude_end: false,
+    return_begin: false,
+    return_end: false,
+    ends_with_parent: false,
+    ends_parent: false,
+    skip: false,
+    before_begin: None,
+    on_begin: None,
+    on_end: None,
+};
+
+// Scopes of the text matched by `begin` or `end`, as a whole or by groups.
+enum Scopes {
+    Wrap(&'static str),
+    Multi(&'static [(usize, Option<&'static str>)]),
+}
+
+// Language of the text of a mode, or the languages to detect the one with the highest relevance.
+enum SubLanguage {
+    One(&'static str),
+    Any(&'static [&'static str]),
+}
+
+// Callbacks of the modes, deciding whether to ignore a match.
+enum Callback {
+    // `hljs.SHEBANG`, only at the start of the code.
+    IgnoreUnlessFirst,
+    // `beginKeywords`, not after a dot.
+    IgnoreAfterDot,
+    // `hljs.END_SAME_AS_BEGIN`, saving the first group of the begin...
+    SaveBeginMatch,
+    // ...to end only with the same text.
+    IgnoreUnlessSameAsBegin,
+    // Opening tags of JSX, and not type parameters or comparisons.
+    IgnoreUnlessJsxTag,
+}
+
+// Expressions of a language, compiled with its flags.
+struct Compiled {
+    modes: Vec<CompiledMode>,
+}
+
+struct CompiledMode {
+    begin: Option<Regex>,
+    end: Option<Regex>,
+    terminator_end: Option<Regex>,
+    illegal: Option<Regex>,
+    keyword_pattern: Option<Regex>,
+}
+
+fn compiled(language: usize) -> Option<&'static Compiled> {
+    static COMPILED: LazyLock<Vec<OnceLock<Option<Compiled>>>> =
+        LazyLock::new(|| LANGUAGES.iter().map(|_| OnceLock::new()).collect());
+
+    COMPILED[language]
+        .get_or_init(|| {
+            compile(&LANGUAGES[language])
+                .map_err(|error| trace::warn!("Grammar ignored, {error}"))
+                .ok()
+        })
+        .as_ref()
+}
+
+fn compile(language: &Language) -> Result<Compiled, String> {
+    let flags = Flags {
+        ignore_case: language.case_insensitive,
+        unicode: language.unicode,
+    };
+    let regex = |source: Option<&str>| {
+        source
+            .filter(|source| !source.is_empty())
+            .map(|source| Regex::new(source, flags))
+            .transpose()
+    };
+    let modes = language
+        .modes
+        .iter()
+        .map(|mode| {
+            Ok(CompiledMode {
+                begin: regex(Some(mode.begin))?,
+                end: mode.end.map(|end| Regex::new(end, flags)).transpose()?,
+                terminator_end: regex(Some(mode.terminator_end))?,
+                illegal: regex(mode.illegal)?,
+                keyword_pattern: regex(mode.keywords.map(|_| mode.keyword_pattern))?,
+            })
+        })
+        .collect::<Result<_, String>>()?;
+    Ok(Compiled { modes })
+}
+
+// Finds a language by its name or alias, ignoring case.
+fn language_index(name: &str) -> Option<usize> {
+    let name = name.to_lowercase();
+    LANGUAGES
+        .iter()
+        .position(|language| language.name == name)
+        // Aliases registered later replace the former ones.
+        .or_else(|| {
+            LANGUAGES
+                .iter()
+                .rposition(|language| language.aliases.contains(&name.as_str()))
+        })
+}
+
+// Markup of a run, with the nodes of the `TokenTree` of highlight.js rendered as they are added.
+#[derive(Default)]
+struct Emitter {
+    out: Vec<u16>,
+    // Open nodes, and whether each one is a span.
+    open: Vec<bool>,
+    // Holds a node highlight.js fails to render, making the run fail.
+    broken: bool,
+}
+
+impl Emitter {
+    // Opens a span with the class names of the scope, like "hljs-title function_" for the
+    // "title.function" scope.
+    fn open(&mut self, scope: &str) {
+        self.open.push(!scope.is_empty());
+        if scope.is_empty() {
+            return;
+        }
+        self.push("<span class=\"hljs-");
+        for (i, name) in scope.split('.').enumerate() {
+            if i > 0 {
+                self.push(" ");
+            }
+            self.push(name);
+            self.push(&"_".repeat(i));
+        }
+        self.push("\">");
+    }
+
+    fn close(&mut self) {
+        if self.open.pop() == Some(true) {
+            self.push("</span>");
+        }
+    }
+
+    fn keyword(&mut self, text: &[u16], scope: &str) {
+        if !text.is_empty() {
+            self.open(scope);
+            self.text(text);
+            self.close();
+        }
+    }
+
+    fn text(&mut self, text: &[u16]) {
+        escape_into(&mut self.out, text);
+    }
+
+    fn sublanguage(&mut self, emitter: Emitter, language: Option<&str>) {
+        if let Some(language) = language {
+            self.push("<span class=\"language-");
+            self.push(language);
+            self.push("\">");
+        }
+        self.broken |= emitter.broken;
+        self.out.extend(emitter.render());
+        if language.is_some() {
+            self.push("</span>");
+        }
+    }
+
+    // Returns the markup, closing the nodes left open.
+    fn render(mut self) -> Vec<u16> {
+        while !self.open.is_empty() {
+            self.close();
+        }
+        self.out
+    }
+
+    fn push(&mut self, s: &str) {
+        self.out.extend(s.encode_utf16());
+    }
+}
+
+fn escape(text: &[u16]) -> Vec<u16> {
+    let mut out = Vec::with_capacity(text.len());
+    escape_into(&mut out, text);
+    out
+}
+
+fn escape_into(out: &mut Vec<u16>, text: &[u16]) {
+    for &unit in text {
+        let escaped = match char::from_u32(unit.into()) {
+            Some('&') => "&amp;",
+            Some('<') => "&lt;",
+            Some('>') => "&gt;",
+            Some('"') => "&quot;",
+            Some('\'') => "&#x27;",
+            _ => {
+                out.push(unit);
+                continue;
+            }
+        };
+        out.extend(escaped.encode_utf16());
+    }
+}
+
+// Result of highlighting code in a language.
+struct Highlighted {
+    // Language as requested, none for the plain text of the automatic detection.
+    language: Option<&'static str>,
+    relevance: f64,
+    emitter: Emitter,
+    // Illegal text found, or an error raised by highlight.js, so the code is rendered escaped.
+    failed: bool,
+    // Open modes at the end, to go on with the next text of an embedded language.
+    top: Option<Vec<usize>>,
+}
+
+// State shared by the runs of a language and of the languages embedded in it.
+#[derive(Default)]
+struct Session {
+    // First group of the text matched by the modes ending with the same text, by language and
+    // mode.
+    begin_matches: HashMap<(usize, usize), Option<Vec<u16>>>,
+}
+
+// Errors stopping a run.
+enum Error {
+    Illegal,
+    Other,
+}
+
+enum Rule {
+    Begin(usize),
+    End,
+    Illegal,
+}
+
+struct Lexeme {
+    rule: Rule,
+    index: usize,
+    end: usize,
+    captures: Captures,
+}
+
+// Iterations over the code after which a run fails as an infinite loop.
+const MAX_ITERATIONS: usize = 100_000;
+
+// Highlighting of code in a language, as the `_highlight()` function of highlight.js.
+struct Run<'a> {
+    session: &'a mut Session,
+    language: usize,
+    grammar: &'static Language,
+    compiled: &'static Compiled,
+    code: &'a [u16],
+    ignore_illegals: bool,
+    // Open modes, the innermost last.
+    stack: Vec<usize>,
+    // Text not processed yet.
+    buffer: Vec<u16>,
+    relevance: f64,
+    keyword_hits: HashMap<String, usize>,
+    emitter: Emitter,
+    // Whether the last match was a begin, and its position, to skip an empty end right after.
+    last_match: Option<(bool, usize)>,
+    continuations: HashMap<&'static str, Vec<usize>>,
+    iterations: usize,
+    // First rule to try at the same position after ignoring a match.
+    regex_index: usize,
+    resume: bool,
+}
+
+impl Session {
+    fn run(
+        &mut self,
+        language: usize,
+        name: Option<&'static str>,
+        code: &[u16],
+        ignore_illegals: bool,
+        top: Option<Vec<usize>>,
+    ) -> Highlighted {
+        let grammar = &LANGUAGES[language];
+        let mut highlighted = Highlighted {
+            language: Some(name.unwrap_or(grammar.name)),
+            relevance: 0.0,
+            emitter: Emitter::default(),
+            failed: true,
+            top: None,
+        };
+        let Some(compiled) = compiled(language) else {
+            return highlighted;
+        };
+        let mut run = Run {
+            session: self,
+            language,
+            grammar,
+            compiled,
+            code,
+            ignore_illegals,
+            stack: top.unwrap_or_else(|| vec![0]),
+            buffer: Vec::new(),
+            relevance: 0.0,
+            keyword_hits: HashMap::new(),
+            emitter: Emitter::default(),
+            last_match: None,
+            continuations: HashMap::new(),
+            iterations: 0,
+            regex_index: 0,
+            resume: false,
+        };
+        for i in 1..run.stack.len() {
+            if let Some(scope) = grammar.modes[run.stack[i]].scope {
+                run.emitter.open(scope);
+            }
+        }
+        let result = run.highlight();
+        highlighted.emitter = run.emitter;
+        match result {
+            Ok(()) if !highlighted.emitter.broken => {
+                highlighted.relevance = run.relevance;
+                highlighted.failed = false;
+                highlighted.top = Some(run.stack);
+            }
+            Err(Error::Illegal) => {}
+            _ => highlighted.top = Some(run.stack),
+        }
+        highlighted
+    }
+
+    // Highlights the code with the language of highest relevance, as `highlightAuto()`.
+    fn auto(&mut self, code: &[u16], subset: &'static [&'static str]) -> Highlighted {
+        let names: Vec<&'static str> = match subset.is_empty() {
+            true => LANGUAGES.iter().map(|language| language.name).collect(),
+            false => subset.to_vec(),
+        };
+        let mut plaintext = Emitter::default();
+        plaintext.text(code);
+        let mut results = vec![Highlighted {
+            language: None,
+            relevance: 0.0,
+            emitter: plaintext,
+            failed: false,
+            top: None,
+        }];
+        for name in names {
+            if let Some(language) = language_index(name) {
+                if !LANGUAGES[language].disable_autodetect {
+                    results.push(self.run(language, Some(name), code, false, None));
+                }
+            }
+        }
+        sort(&mut results, |a, b| {
+            if a.relevance != b.relevance {
+                return if b.relevance > a.relevance { 1 } else { -1 };
+            }
+            if let (Some(a), Some(b)) = (a.language, b.language) {
+                let superset_of = |language| {
+                    language_index(language).and_then(|language| LANGUAGES[language].superset_of)
+                };
+                if superset_of(a) == Some(b) {
+                    return 1;
+                }
+                if superset_of(b) == Some(a) {
+                    return -1;
+                }
+            }
+            0
+        });
+        results.swap_remove(0)
+    }
+}
+
+impl Run<'_> {
+    fn highlight(&mut self) -> Result<(), Error> {
+        let code = self.code;
+        let mut index = 0;
+        loop {
+            self.iterations += 1;
+            if self.resume {
+                self.resume = false;
+            } else {
+                self.regex_index = 0;
+            }
+            let Some(lexeme) = self.exec(index) else {
+                break;
+            };
+            let before = substring(code, index, lexeme.index);
+            index = lexeme.index + self.process_lexeme(before, Some(&lexeme))?;
+        }
+        self.process_lexeme(substring(code, index, code.len()), None)?;
+        while !self.emitter.open.is_empty() {
+            self.emitter.close();
+        }
+        Ok(())
+    }
+
+    fn top(&self) -> usize {
+        self.stack[self.stack.len() - 1]
+    }
+
+    fn mode(&self) -> &'static Mode {
+        &self.grammar.modes[self.top()]
+    }
+
+    // Finds the next match of the modes contained in the innermost mode, its end or its illegal
+    // text, trying the rules after an ignored match first at the same position.
+    fn exec(&mut self, from: usize) -> Option<Lexeme> {
+        let modes = &self.compiled.modes;
+        let mut rules: Vec<(Rule, &Regex)> = self
+            .mode()
+            .contains
+            .iter()
+            .filter_map(|&child| Some((Rule::Begin(child), modes[child].begin.as_ref()?)))
+            .collect();
+        let begins = rules.len();
+        if let Some(end) = &modes[self.top()].terminator_end {
+            rules.push((Rule::End, end));
+        }
+        if let Some(illegal) = &modes[self.top()].illegal {
+            rules.push((Rule::Illegal, illegal));
+        }
+        let regexes: Vec<&Regex> = rules.iter().map(|(_, regex)| *regex).collect();
+        let first = self.regex_index.min(regexes.len());
+        let mut found = regex::find_any(&regexes[first..], self.code, from)
+            .map(|(i, captures)| (first + i, first, captures));
+        if self.regex_index != 0 && !found.as_ref().is_some_and(|(.., c)| starts_at(c, from)) {
+            found = regex::find_any(&regexes, self.code, from + 1).map(|(i, c)| (i, 0, c));
+        }
+        let (i, first, captures) = found?;
+        self.regex_index += i - first + 1;
+        if self.regex_index == begins {
+            self.regex_index = 0;
+        }
+        let (index, end) = captures[0]?;
+        Some(Lexeme {
+            rule: rules.swap_remove(i).0,
+            index,
+            end,
+            captures,
+        })
+    }
+
+    // Processes the text before a match and the match, returning how far to advance.
+    fn process_lexeme(&mut self, before: &[u16], lexeme: Option<&Lexeme>) -> Result<usize, Error> {
+        self.buffer.extend_from_slice(before);
+        let Some(lexeme) = lexeme else {
+            self.flush();
+            return Ok(0);
+        };
+        let text = &self.code[lexeme.index..lexeme.end];
+        // An empty end right after an empty begin would loop, consume one code unit.
+        if self.last_match == Some((true, lexeme.index))
+            && matches!(lexeme.rule, Rule::End)
+            && text.is_empty()
+        {
+            let next = self.code.get(lexeme.index..lexeme.index + 1);
+            self.buffer.extend_from_slice(next.unwrap_or_default());
+            return Ok(1);
+        }
+        self.last_match = Some((matches!(lexeme.rule, Rule::Begin(_)), lexeme.index));
+        match lexeme.rule {
+            Rule::Begin(mode) => return Ok(self.do_begin(mode, lexeme)),
+            Rule::End => {
+                if let Some(advance) = self.do_end(lexeme)? {
+                    return Ok(advance);
+                }
+            }
+            Rule::Illegal if !self.ignore_illegals => return Err(Error::Illegal),
+            Rule::Illegal if text.is_empty() => return Ok(1),
+            Rule::Illegal => {}
+        }
+        if self.iterations > MAX_ITERATIONS && self.iterations > 3 * lexeme.index {
+            return Err(Error::Other);
+        }
+        self.buffer.extend_from_slice(text);
+        Ok(text.len())
+    }
+
+    fn do_begin(&mut self, child: usize, lexeme: &Lexeme) -> usize {
+        let mode = &self.grammar.modes[child];
+        let text = &self.code[lexeme.index..lexeme.end];
+        for callback in [&mode.before_begin, &mode.on_begin].into_iter().flatten() {
+            if self.ignores(callback, child, lexeme) {
+                // Ignored matches consume one code unit, or resume with the next rules.
+                if self.regex_index != 0 {
+                    self.resume = true;
+                    return 0;
+                }
+                match text.first() {
+                    Some(&unit) => self.buffer.push(unit),
+                    None => self.buffer.extend("undefined".encode_utf16()),
+                }
+                return 1;
+            }
+        }
+        if mode.skip {
+            self.buffer.extend_from_slice(text);
+        } else {
+            if mode.exclude_begin {
+                self.buffer.extend_from_slice(text);
+            }
+            self.flush();
+            if !mode.return_begin && !mode.exclude_begin {
+                self.buffer = text.to_vec();
+            }
+        }
+        self.start_mode(child, lexeme);
+        if mode.return_begin {
+            0
+        } else {
+            text.len()
+        }
+    }
+
+    fn do_end(&mut self, lexeme: &Lexeme) -> Result<Option<usize>, Error> {
+        let text = &self.code[lexeme.index..lexeme.end];
+        let Some(depth) = self.end_of_mode(self.stack.len() - 1, lexeme) else {
+            return Ok(None);
+        };
+        let mode = self.mode();
+        match &mode.end_scope {
+            Some(Scopes::Wrap(scope)) => {
+                self.flush();
+                self.emitter.keyword(text, scope);
+            }
+            Some(Scopes::Multi(groups)) => {
+                self.flush();
+                self.emit_groups(groups, &lexeme.captures);
+            }
+            None if mode.skip => self.buffer.extend_from_slice(text),
+            None => {
+                if !mode.return_end && !mode.exclude_end {
+                    self.buffer.extend_from_slice(text);
+                }
+                self.flush();
+                if mode.exclude_end {
+                    self.buffer = text.to_vec();
+                }
+            }
+        }
+        let ended = self.stack[depth];
+        while self.stack.len() > depth {
+            let closed = &self.grammar.modes[self.stack.pop().unwrap_or_default()];
+            if closed.scope.is_some() {
+                self.emitter.close();
+            }
+            if !closed.skip && closed.sub_language.is_none() {
+                self.relevance += closed.relevance;
+            }
+        }
+        // Ending the language itself leaves no mode to go on with.
+        if self.stack.is_empty() {
+            return Err(Error::Other);
+        }
+        if let Some(starts) = self.grammar.modes[ended].starts {
+            self.start_mode(starts, lexeme);
+        }
+        Ok(Some(if mode.return_end { 0 } else { text.len() }))
+    }
+
+    // Returns the depth of the mode ended by the match, or of the parent ended with it.
+    fn end_of_mode(&mut self, depth: usize, lexeme: &Lexeme) -> Option<usize> {
+        let id = self.stack[depth];
+        let mode = &self.grammar.modes[id];
+        // The end is matched at the start of the code from the match.
+        let rest = &self.code[lexeme.index..];
+        let mut ended = self.compiled.modes[id]
+            .end
+            .as_ref()
+            .is_some_and(|end| end.match_at(rest, 0).is_some());
+        if ended {
+            if let Some(callback) = &mode.on_end {
+                ended = !self.ignores(callback, id, lexeme);
+            }
+        }
+        if ended {
+            let mut depth = depth;
+            while depth > 0 && self.grammar.modes[self.stack[depth]].ends_parent {
+                depth -= 1;
+            }
+            return Some(depth);
+        }
+        if mode.ends_with_parent && depth > 0 {
+            return self.end_of_mode(depth - 1, lexeme);
+        }
+        None
+    }
+
+    fn start_mode(&mut self, id: usize, lexeme: &Lexeme) {
+        let mode = &self.grammar.modes[id];
+        if let Some(scope) = mode.scope {
+            self.emitter.open(self.alias(scope));
+        }
+        match &mode.begin_scope {
+            Some(Scopes::Wrap(scope)) => {
+                let buffer = std::mem::take(&mut self.buffer);
+                self.emitter.keyword(&buffer, self.alias(scope));
+            }
+            Some(Scopes::Multi(groups)) => {
+                self.emit_groups(groups, &lexeme.captures);
+                self.buffer.clear();
+            }
+            None => {}
+        }
+        self.stack.push(id);
+    }
+
+    // Emits the groups of a match with their scopes, or with the keywords of the innermost mode.
+    fn emit_groups(&mut self, groups: &[(usize, Option<&'static str>)], captures: &Captures) {
+        for &(group, scope) in groups {
+            let code = self.code;
+            let text = captures.get(group).copied().flatten();
+            let text = text.map(|(start, end)| &code[start..end]);
+            match (scope.map(|scope| self.alias(scope)), text) {
+                (Some(scope), Some(text)) => self.emitter.keyword(text, scope),
+                // highlight.js adds the groups not matched as `undefined` and fails to render them,
+                // or highlights the keywords of "undefined".
+                (Some(_), None) => self.emitter.broken = true,
+                (None, None) if self.mode().keywords.is_none() => self.emitter.broken = true,
+                (None, text) => {
+                    self.buffer = match text {
+                        Some(text) => text.to_vec(),
+                        None => "undefined".encode_utf16().collect(),
+                    };
+                    self.process_keywords();
+                    self.buffer.clear();
+                }
+            }
+        }
+    }
+
+    // Processes the text not processed yet, with the keywords or the language of the innermost
+    // mode.
+    fn flush(&mut self) {
+        match &self.mode().sub_language {
+            Some(sub_language) if !self.buffer.is_empty() => {
+                let buffer = std::mem::take(&mut self.buffer);
+                let result = match sub_language {
+                    SubLanguage::One(name) => {
+                        let Some(language) = LANGUAGES.iter().position(|l| l.name == *name) else {
+                            self.emitter.text(&buffer);
+                            return;
+                        };
+                        let top = self.continuations.remove(name);
+                        let result = self.session.run(language, Some(name), &buffer, true, top);
+                        if let Some(top) = &result.top {
+                            self.continuations.insert(name, top.clone());
+                        }
+                        result
+                    }
+                    SubLanguage::Any(names) => self.session.auto(&buffer, names),
+                };
+                if self.mode().relevance > 0.0 {
+                    self.relevance += result.relevance;
+                }
+                self.emitter.sublanguage(result.emitter, result.language);
+            }
+            Some(_) => {}
+            None => self.process_keywords(),
+        }
+        self.buffer.clear();
+    }
+
+    fn process_keywords(&mut self) {
+        let buffer = std::mem::take(&mut self.buffer);
+        let pattern = self.compiled.modes[self.top()].keyword_pattern.as_ref();
+        let (Some(keywords), Some(pattern)) = (self.mode().keywords, pattern) else {
+            self.emitter.text(&buffer);
+            self.buffer = buffer;
+            return;
+        };
+        let mut text = Vec::new();
+        let mut last = 0;
+        while let Some((start, end)) = pattern.find_at(&buffer, last).and_then(|c| c[0]) {
+            text.extend_from_slice(substring(&buffer, last, start));
+            let word = &buffer[start..end];
+            let mut key = String::from_utf16_lossy(word);
+            if self.grammar.case_insensitive {
+                key = key.to_lowercase();
+            }
+            match keywords.binary_search_by(|&(keyword, ..)| keyword.cmp(&key)) {
+                Ok(i) => {
+                    let (_, scope, relevance) = keywords[i];
+                    self.emitter.text(&text);
+                    text.clear();
+                    // Only the first hits of each keyword are relevant.
+                    let hits = self.keyword_hits.entry(key).or_default();
+                    *hits += 1;
+                    if *hits <= 7 {
+                        self.relevance += f64::from(relevance);
+                    }
+                    if scope.starts_with('_') {
+                        text.extend_from_slice(word);
+                    } else {
+                        self.emitter.keyword(word, self.alias(scope));
+                    }
+                }
+                Err(_) => text.extend_from_slice(word),
+            }
+            // An empty match would loop, go on from the next code unit.
+            last = if end > start { end } else { end + 1 };
+        }
+        text.extend_from_slice(substring(&buffer, last, buffer.len()));
+        self.emitter.text(&text);
+        self.buffer = buffer;
+    }
+
+    // Whether the callback of the mode ignores the match.
+    fn ignores(&mut self, callback: &Callback, mode: usize, lexeme: &Lexeme) -> bool {
+        let code = self.code;
+        let group = |i: usize| {
+            let group = lexeme.captures.get(i).copied().flatten();
+            group.map(|(start, end)| code[start..end].to_vec())
+        };
+        match callback {
+            Callback::IgnoreUnlessFirst => lexeme.index != 0,
+            Callback::IgnoreAfterDot => lexeme.index > 0 && code[lexeme.index - 1] == b'.'.into(),
+            Callback::SaveBeginMatch => {
+                let key = (self.language, mode);
+                self.session.begin_matches.insert(key, group(1));
+                false
+            }
+            Callback::IgnoreUnlessSameAsBegin => {
+                let key = (self.language, mode);
+                self.session.begin_matches.get(&key).cloned().flatten() != group(1)
+            }
+            Callback::IgnoreUnlessJsxTag => {
+                let (start, end) = (lexeme.index, lexeme.end);
+                let next = code.get(end).copied();
+                if next == Some(b'<'.into()) || next == Some(b','.into()) {
+                    return true;
+                }
+                // A tag closed at once must be closed later.
+                if next == Some(b'>'.into()) {
+                    let mut closing: Vec<u16> = "</".encode_utf16().collect();
+                    closing.extend_from_slice(&code[start + 1..end]);
+                    if !code[end..]
+                        .windows(closing.len())
+                        .any(|text| text == closing)
+                    {
+                        return true;
+                    }
+                }
+                // Neither an attribute `\s*=` nor a type parameter `\s+extends\s+`.
+                let rest = &code[end..];
+                let spaces = rest.iter().take_while(|&&unit| is_space(unit)).count();
+                let after = &rest[spaces..];
+                let extends: Vec<u16> = "extends".encode_utf16().collect();
+                after.first() == Some(&b'='.into())
+                    || (spaces > 0
+                        && after.starts_with(&extends)
+                        && after.get(extends.len()).is_some_and(|&unit| is_space(unit)))
+            }
+        }
+    }
+
+    fn alias(&self, scope: &'static str) -> &'static str {
+        self.grammar
+            .class_name_aliases
+            .iter()
+            .find_map(|&(name, alias)| (name == scope).then_some(alias))
+            .unwrap_or(scope)
+    }
+}
+
+fn starts_at(captures: &Captures, at: usize) -> bool {
+    captures[0].is_some_and(|(start, _)| start == at)
+}
+
+fn is_space(unit: u16) -> bool {
+    regex::is_space(unit.into())
+}
+
+// `String.prototype.substring()`, swapping the positions out of order.
+fn substring(text: &[u16], start: usize, end: usize) -> &[u16] {
+    let (start, end) = (start.min(text.len()), end.min(text.len()));
+    &text[start.min(end)..start.max(end)]
+}
+
+// Sorts like `Array.prototype.sort()` of V8 for short arrays, reversing the first run if it is
+// descending and then inserting the others with a binary search, so the result is the same even
+// for comparisons that are not consistent.
+fn sort<T>(items: &mut [T], compare: impl Fn(&T, &T) -> i32) {
+    let len = items.len();
+    if len < 2 {
+        return;
+    }
+    let descending = compare(&items[1], &items[0]) < 0;
+    let mut run = 2;
+    while run < len {
+        let order = compare(&items[run], &items[run - 1]);
+        if (descending && order >= 0) || (!descending && order < 0) {
+            break;
+        }
+        run += 1;
+    }
+    if descending {
+        items[..run].reverse();
+    }
+    for start in run..len {
+        let (mut left, mut right) = (0, start);
+        while left < right {
+            let mid = left + (right - left) / 2;
+            if compare(&items[start], &items[mid]) < 0 {
+                right = mid;
+            } else {
+                left = mid + 1;
+            }
+        }
+        items[left..=start].rotate_right(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use std::path::Path;
+
+    // The expected markup in `tests/highlight` is rendered by highlight.js for each input, and can
+    // be regenerated with `node tests/highlight/generate.js`.
+    #[test]
+    fn renders_the_same_markup_as_highlight_js() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/highlight");
+        for dir in fs::read_dir(fixtures).unwrap() {
+            let dir = dir.unwrap().path();
+            if !dir.is_dir() {
+                continue;
+            }
+            let language: HljsLang = dir.file_name().unwrap().to_str().unwrap().parse().unwrap();
+            for input in fs::read_dir(&dir).unwrap() {
+                let input = input.unwrap().path();
+                if input.extension().and_then(|extension| extension.to_str()) != Some("txt") {
+                    continue;
+                }
+                let code = fs::read_to_string(&input).unwrap();
+                let expected = fs::read_to_string(input.with_extension("html")).unwrap();
+                let markup = highlight(&language, &code).into_string();
+                assert_eq!(markup, expected, "{}", input.display());
+            }
+        }
+    }
+
+    #[test]
+    fn every_common_language_has_a_grammar() {
+        for (i, language) in LANGUAGES.iter().enumerate() {
+            if let Err(error) = compile(language) {
+                panic!("{}: {error}", language.name);
+            }
+            assert!(compiled(i).is_some());
+        }
+        for language in [
+            "bash",
+            "c",
+            "cpp",
+            "csharp",
+            "css",
+            "diff",
+            "go",
+            "graphql",
+            "html",
+            "ini",
+            "java",
+            "javascript",
+            "json",
+            "kotlin",
+            "less",
+            "lua",
+            "makefile",
+            "markdown",
+            "objectivec",
+            "perl",
+            "php",
+            "php-template",
+            "plaintext",
+            "python",
+            "python-repl",
+            "r",
+            "ruby",
+            "rust",
+            "scss",
+            "shell",
+            "sql",
+            "swift",
+            "toml",
+            "typescript",
+            "vbnet",
+            "wasm",
+            "xml",
+            "yaml",
+        ] {
+            let language: HljsLang = language.parse().unwrap();
+            assert!(
+                language_index(&language.to_string()).is_some(),
+                "{language:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn escapes_languages_without_grammar() {
+        let markup = highlight(&HljsLang::Lisp, "(print \"<a & b>\")").into_string();
+        assert_eq!(markup, "(print &quot;&lt;a &amp; b&gt;&quot;)");
+    }
+}
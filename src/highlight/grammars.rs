@@ -0,0 +1,2205 @@
+// Grammars of the highlight.js common languages, compiled by highlight.js 11.7.0. Generated by
+// `node build/grammars.js`, don't edit.
+
+use super::{Callback, Language, Mode, Scopes, SubLanguage, MODE};
+
+pub(super) static LANGUAGES: &[Language] = &[
+    Language {
+        name: r"bash",
+        aliases: &[r"sh"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: BASH,
+    },
+    Language {
+        name: r"c",
+        aliases: &[r"h"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: true,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: C,
+    },
+    Language {
+        name: r"cpp",
+        aliases: &[r"cc", r"c++", r"h++", r"hpp", r"hh", r"hxx", r"cxx"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[(r"function.dispatch", r"built_in")],
+        modes: CPP,
+    },
+    Language {
+        name: r"csharp",
+        aliases: &[r"cs", r"c#"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: CSHARP,
+    },
+    Language {
+        name: r"css",
+        aliases: &[],
+        case_insensitive: true,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[(r"keyframePosition", r"selector-tag")],
+        modes: CSS,
+    },
+    Language {
+        name: r"diff",
+        aliases: &[r"patch"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: DIFF,
+    },
+    Language {
+        name: r"go",
+        aliases: &[r"golang"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: GO,
+    },
+    Language {
+        name: r"graphql",
+        aliases: &[r"gql"],
+        case_insensitive: true,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: GRAPHQL,
+    },
+    Language {
+        name: r"ini",
+        aliases: &[r"toml"],
+        case_insensitive: true,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: INI,
+    },
+    Language {
+        name: r"java",
+        aliases: &[r"jsp"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: JAVA,
+    },
+    Language {
+        name: r"javascript",
+        aliases: &[r"js", r"jsx", r"mjs", r"cjs"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: JAVASCRIPT,
+    },
+    Language {
+        name: r"json",
+        aliases: &[],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: JSON,
+    },
+    Language {
+        name: r"kotlin",
+        aliases: &[r"kt", r"kts"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: KOTLIN,
+    },
+    Language {
+        name: r"less",
+        aliases: &[],
+        case_insensitive: true,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: LESS,
+    },
+    Language {
+        name: r"lua",
+        aliases: &[],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: LUA,
+    },
+    Language {
+        name: r"makefile",
+        aliases: &[r"mk", r"mak", r"make"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: MAKEFILE,
+    },
+    Language {
+        name: r"xml",
+        aliases: &[r"html", r"xhtml", r"rss", r"atom", r"xjb", r"xsd", r"xsl", r"plist", r"wsf", r"svg"],
+        case_insensitive: true,
+        unicode: true,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: XML,
+    },
+    Language {
+        name: r"markdown",
+        aliases: &[r"md", r"mkdown", r"mkd"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: MARKDOWN,
+    },
+    Language {
+        name: r"objectivec",
+        aliases: &[r"mm", r"objc", r"obj-c", r"obj-c++", r"objective-c++"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: OBJECTIVEC,
+    },
+    Language {
+        name: r"perl",
+        aliases: &[r"pl", r"pm"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: PERL,
+    },
+    Language {
+        name: r"php",
+        aliases: &[],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: PHP,
+    },
+    Language {
+        name: r"php-template",
+        aliases: &[],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: PHP_TEMPLATE,
+    },
+    Language {
+        name: r"plaintext",
+        aliases: &[r"text", r"txt"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: true,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: PLAINTEXT,
+    },
+    Language {
+        name: r"python",
+        aliases: &[r"py", r"gyp", r"ipython"],
+        case_insensitive: false,
+        unicode: true,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: PYTHON,
+    },
+    Language {
+        name: r"python-repl",
+        aliases: &[r"pycon"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: PYTHON_REPL,
+    },
+    Language {
+        name: r"r",
+        aliases: &[],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: R,
+    },
+    Language {
+        name: r"ruby",
+        aliases: &[r"rb", r"gemspec", r"podspec", r"thor", r"irb"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: RUBY,
+    },
+    Language {
+        name: r"rust",
+        aliases: &[r"rs"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: RUST,
+    },
+    Language {
+        name: r"scss",
+        aliases: &[],
+        case_insensitive: true,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: SCSS,
+    },
+    Language {
+        name: r"shell",
+        aliases: &[r"console", r"shellsession"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: SHELL,
+    },
+    Language {
+        name: r"sql",
+        aliases: &[],
+        case_insensitive: true,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: SQL,
+    },
+    Language {
+        name: r"swift",
+        aliases: &[],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: SWIFT,
+    },
+    Language {
+        name: r"typescript",
+        aliases: &[r"ts", r"tsx"],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: TYPESCRIPT,
+    },
+    Language {
+        name: r"vbnet",
+        aliases: &[r"vb"],
+        case_insensitive: true,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[(r"label", r"symbol")],
+        modes: VBNET,
+    },
+    Language {
+        name: r"wasm",
+        aliases: &[],
+        case_insensitive: false,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: WASM,
+    },
+    Language {
+        name: r"yaml",
+        aliases: &[r"yml"],
+        case_insensitive: true,
+        unicode: false,
+        disable_autodetect: false,
+        superset_of: None,
+        class_name_aliases: &[],
+        modes: YAML,
+    },
+];
+
+// Unicode properties used by the regular expressions.
+pub(super) static PROPERTIES: &[(&str, &[(u32, u32)])] = &[
+    (
+        r"L",
+        &[(0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE), (0x370, 0x374), (0x376, 0x377), (0x37A, 0x37D), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x48A, 0x52F), (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x620, 0x64A), (0x66E, 0x66F), (0x671, 0x6D3), (0x6D5, 0x6D5), (0x6E5, 0x6E6), (0x6EE, 0x6EF), (0x6FA, 0x6FC), (0x6FF, 0x6FF), (0x710, 0x710), (0x712, 0x72F), (0x74D, 0x7A5), (0x7B1, 0x7B1), (0x7CA, 0x7EA), (0x7F4, 0x7F5), (0x7FA, 0x7FA), (0x800, 0x815), (0x81A, 0x81A), (0x824, 0x824), (0x828, 0x828), (0x840, 0x858), (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x8A0, 0x8C9), (0x904, 0x939), (0x93D, 0x93D), (0x950, 0x950), (0x958, 0x961), (0x971, 0x980), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8), (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BD, 0x9BD), (0x9CE, 0x9CE), (0x9DC, 0x9DD), (0x9DF, 0x9E1), (0x9F0, 0x9F1), (0x9FC, 0x9FC), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33), (0xA35, 0xA36), (0xA38, 0xA39), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA72, 0xA74), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABD, 0xABD), (0xAD0, 0xAD0), (0xAE0, 0xAE1), (0xAF9, 0xAF9), (0xB05, 0xB0C), (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3D, 0xB3D), (0xB5C, 0xB5D), (0xB5F, 0xB61), (0xB71, 0xB71), (0xB83, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90), (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA), (0xBAE, 0xBB9), (0xBD0, 0xBD0), (0xC05, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3D, 0xC3D), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC61), (0xC80, 0xC80), (0xC85, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBD, 0xCBD), (0xCDC, 0xCDE), (0xCE0, 0xCE1), (0xCF1, 0xCF2), (0xD04, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD3A), (0xD3D, 0xD3D), (0xD4E, 0xD4E), (0xD54, 0xD56), (0xD5F, 0xD61), (0xD7A, 0xD7F), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xE01, 0xE30), (0xE32, 0xE33), (0xE40, 0xE46), (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEB0), (0xEB2, 0xEB3), (0xEBD, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF40, 0xF47), (0xF49, 0xF6C), (0xF88, 0xF8C), (0x1000, 0x102A), (0x103F, 0x103F), (0x1050, 0x1055), (0x105A, 0x105D), (0x1061, 0x1061), (0x1065, 0x1066), (0x106E, 0x1070), (0x1075, 0x1081), (0x108E, 0x108E), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C), (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16F1, 0x16F8), (0x1700, 0x1711), (0x171F, 0x1731), (0x1740, 0x1751), (0x1760, 0x176C), (0x176E, 0x1770), (0x1780, 0x17B3), (0x17D7, 0x17D7), (0x17DC, 0x17DC), (0x1820, 0x1878), (0x1880, 0x1884), (0x1887, 0x18A8), (0x18AA, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1950, 0x196D), (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x1A00, 0x1A16), (0x1A20, 0x1A54), (0x1AA7, 0x1AA7), (0x1B05, 0x1B33), (0x1B45, 0x1B4C), (0x1B83, 0x1BA0), (0x1BAE, 0x1BAF), (0x1BBA, 0x1BE5), (0x1C00, 0x1C23), (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC), (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115), (0x2119, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x212D), (0x212F, 0x2139), (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E), (0x2183, 0x2184), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96), (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x2E2F, 0x2E2F), (0x3005, 0x3006), (0x3031, 0x3035), (0x303B, 0x303C), (0x3041, 0x3096), (0x309D, 0x309F), (0x30A1, 0x30FA), (0x30FC, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD), (0xA500, 0xA60C), (0xA610, 0xA61F), (0xA62A, 0xA62B), (0xA640, 0xA66E), (0xA67F, 0xA69D), (0xA6A0, 0xA6E5), (0xA717, 0xA71F), (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA801), (0xA803, 0xA805), (0xA807, 0xA80A), (0xA80C, 0xA822), (0xA840, 0xA873), (0xA882, 0xA8B3), (0xA8F2, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA8FE), (0xA90A, 0xA925), (0xA930, 0xA946), (0xA960, 0xA97C), (0xA984, 0xA9B2), (0xA9CF, 0xA9CF), (0xA9E0, 0xA9E4), (0xA9E6, 0xA9EF), (0xA9FA, 0xA9FE), (0xAA00, 0xAA28), (0xAA40, 0xAA42), (0xAA44, 0xAA4B), (0xAA60, 0xAA76), (0xAA7A, 0xAA7A), (0xAA7E, 0xAAAF), (0xAAB1, 0xAAB1), (0xAAB5, 0xAAB6), (0xAAB9, 0xAABD), (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEA), (0xAAF2, 0xAAF4), (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABE2), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDFB), (0xFE70, 0xFE74), (0xFE76, 0xFEFC), (0xFF21, 0xFF3A), (0xFF41, 0xFF5A), (0xFF66, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x10300, 0x1031F), (0x1032D, 0x10340), (0x10342, 0x10349), (0x10350, 0x10375), (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF), (0x10400, 0x1049D), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959), (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A00), (0x10A10, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE4), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D23), (0x10D4A, 0x10D65), (0x10D6F, 0x10D85), (0x10E80, 0x10EA9), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10F00, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F45), (0x10F70, 0x10F81), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11003, 0x11037), (0x11071, 0x11072), (0x11075, 0x11075), (0x11083, 0x110AF), (0x110D0, 0x110E8), (0x11103, 0x11126), (0x11144, 0x11144), (0x11147, 0x11147), (0x11150, 0x11172), (0x11176, 0x11176), (0x11183, 0x111B2), (0x111C1, 0x111C4), (0x111DA, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x1122B), (0x1123F, 0x11240), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112DE), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133D, 0x1133D), (0x11350, 0x11350), (0x1135D, 0x11361), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5), (0x113B7, 0x113B7), (0x113D1, 0x113D1), (0x113D3, 0x113D3), (0x11400, 0x11434), (0x11447, 0x1144A), (0x1145F, 0x11461), (0x11480, 0x114AF), (0x114C4, 0x114C5), (0x114C7, 0x114C7), (0x11580, 0x115AE), (0x115D8, 0x115DB), (0x11600, 0x1162F), (0x11644, 0x11644), (0x11680, 0x116AA), (0x116B8, 0x116B8), (0x11700, 0x1171A), (0x11740, 0x11746), (0x11800, 0x1182B), (0x118A0, 0x118DF), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x1192F), (0x1193F, 0x1193F), (0x11941, 0x11941), (0x119A0, 0x119A7), (0x119AA, 0x119D0), (0x119E1, 0x119E1), (0x119E3, 0x119E3), (0x11A00, 0x11A00), (0x11A0B, 0x11A32), (0x11A3A, 0x11A3A), (0x11A50, 0x11A50), (0x11A5C, 0x11A89), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11BC0, 0x11BE0), (0x11C00, 0x11C08), (0x11C0A, 0x11C2E), (0x11C40, 0x11C40), (0x11C72, 0x11C8F), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D30), (0x11D46, 0x11D46), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D89), (0x11D98, 0x11D98), (0x11DB0, 0x11DDB), (0x11EE0, 0x11EF2), (0x11F02, 0x11F02), (0x11F04, 0x11F10), (0x11F12, 0x11F33), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342F), (0x13441, 0x13446), (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x1611D), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A70, 0x16ABE), (0x16AD0, 0x16AED), (0x16B00, 0x16B2F), (0x16B40, 0x16B43), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16D40, 0x16D6C), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x16F00, 0x16F4A), (0x16F50, 0x16F50), (0x16F93, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x16FF2, 0x16FF3), (0x17000, 0x18CD5), (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D), (0x1E100, 0x1E12C), (0x1E137, 0x1E13D), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AD), (0x1E2C0, 0x1E2EB), (0x1E4D0, 0x1E4EB), (0x1E5D0, 0x1E5ED), (0x1E5F0, 0x1E5F0), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6E2), (0x1E6E4, 0x1E6E5), (0x1E6E7, 0x1E6ED), (0x1E6F0, 0x1E6F4), (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E900, 0x1E943), (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479)],
+    ),
+    (
+        r"XID_Continue",
+        &[(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xB7, 0xB7), (0xBA, 0xBA), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE), (0x300, 0x374), (0x376, 0x377), (0x37B, 0x37D), (0x37F, 0x37F), (0x386, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x483, 0x487), (0x48A, 0x52F), (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x591, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x610, 0x61A), (0x620, 0x669), (0x66E, 0x6D3), (0x6D5, 0x6DC), (0x6DF, 0x6E8), (0x6EA, 0x6FC), (0x6FF, 0x6FF), (0x710, 0x74A), (0x74D, 0x7B1), (0x7C0, 0x7F5), (0x7FA, 0x7FA), (0x7FD, 0x7FD), (0x800, 0x82D), (0x840, 0x85B), (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x897, 0x8E1), (0x8E3, 0x963), (0x966, 0x96F), (0x971, 0x983), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8), (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BC, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CE), (0x9D7, 0x9D7), (0x9DC, 0x9DD), (0x9DF, 0x9E3), (0x9E6, 0x9F1), (0x9FC, 0x9FC), (0x9FE, 0x9FE), (0xA01, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33), (0xA35, 0xA36), (0xA38, 0xA39), (0xA3C, 0xA3C), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D), (0xA51, 0xA51), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA66, 0xA75), (0xA81, 0xA83), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABC, 0xAC5), (0xAC7, 0xAC9), (0xACB, 0xACD), (0xAD0, 0xAD0), (0xAE0, 0xAE3), (0xAE6, 0xAEF), (0xAF9, 0xAFF), (0xB01, 0xB03), (0xB05, 0xB0C), (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3C, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57), (0xB5C, 0xB5D), (0xB5F, 0xB63), (0xB66, 0xB6F), (0xB71, 0xB71), (0xB82, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90), (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA), (0xBAE, 0xBB9), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD), (0xBD0, 0xBD0), (0xBD7, 0xBD7), (0xBE6, 0xBEF), (0xC00, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3C, 0xC44), (0xC46, 0xC48), (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC63), (0xC66, 0xC6F), (0xC80, 0xC83), (0xC85, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBC, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCDC, 0xCDE), (0xCE0, 0xCE3), (0xCE6, 0xCEF), (0xCF1, 0xCF3), (0xD00, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD44), (0xD46, 0xD48), (0xD4A, 0xD4E), (0xD54, 0xD57), (0xD5F, 0xD63), (0xD66, 0xD6F), (0xD7A, 0xD7F), (0xD81, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xDCA, 0xDCA), (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDE6, 0xDEF), (0xDF2, 0xDF3), (0xE01, 0xE3A), (0xE40, 0xE4E), (0xE50, 0xE59), (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEC8, 0xECE), (0xED0, 0xED9), (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF18, 0xF19), (0xF20, 0xF29), (0xF35, 0xF35), (0xF37, 0xF37), (0xF39, 0xF39), (0xF3E, 0xF47), (0xF49, 0xF6C), (0xF71, 0xF84), (0xF86, 0xF97), (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x1000, 0x1049), (0x1050, 0x109D), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A), (0x135D, 0x135F), (0x1369, 0x1371), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C), (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1715), (0x171F, 0x1734), (0x1740, 0x1753), (0x1760, 0x176C), (0x176E, 0x1770), (0x1772, 0x1773), (0x1780, 0x17D3), (0x17D7, 0x17D7), (0x17DC, 0x17DD), (0x17E0, 0x17E9), (0x180B, 0x180D), (0x180F, 0x1819), (0x1820, 0x1878), (0x1880, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1920, 0x192B), (0x1930, 0x193B), (0x1946, 0x196D), (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x19D0, 0x19DA), (0x1A00, 0x1A1B), (0x1A20, 0x1A5E), (0x1A60, 0x1A7C), (0x1A7F, 0x1A89), (0x1A90, 0x1A99), (0x1AA7, 0x1AA7), (0x1AB0, 0x1ABD), (0x1ABF, 0x1ADD), (0x1AE0, 0x1AEB), (0x1B00, 0x1B4C), (0x1B50, 0x1B59), (0x1B6B, 0x1B73), (0x1B80, 0x1BF3), (0x1C00, 0x1C37), (0x1C40, 0x1C49), (0x1C4D, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CFA), (0x1D00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x200C, 0x200D), (0x203F, 0x2040), (0x2054, 0x2054), (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x20D0, 0x20DC), (0x20E1, 0x20E1), (0x20E5, 0x20F0), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115), (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139), (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D7F, 0x2D96), (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x2DE0, 0x2DFF), (0x3005, 0x3007), (0x3021, 0x302F), (0x3031, 0x3035), (0x3038, 0x303C), (0x3041, 0x3096), (0x3099, 0x309A), (0x309D, 0x309F), (0x30A1, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD), (0xA500, 0xA60C), (0xA610, 0xA62B), (0xA640, 0xA66F), (0xA674, 0xA67D), (0xA67F, 0xA6F1), (0xA717, 0xA71F), (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA827), (0xA82C, 0xA82C), (0xA840, 0xA873), (0xA880, 0xA8C5), (0xA8D0, 0xA8D9), (0xA8E0, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA92D), (0xA930, 0xA953), (0xA960, 0xA97C), (0xA980, 0xA9C0), (0xA9CF, 0xA9D9), (0xA9E0, 0xA9FE), (0xAA00, 0xAA36), (0xAA40, 0xAA4D), (0xAA50, 0xAA59), (0xAA60, 0xAA76), (0xAA7A, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEF), (0xAAF2, 0xAAF6), (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABEA), (0xABEC, 0xABED), (0xABF0, 0xABF9), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D), (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDF9), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0xFE33, 0xFE34), (0xFE4D, 0xFE4F), (0xFE71, 0xFE71), (0xFE73, 0xFE73), (0xFE77, 0xFE77), (0xFE79, 0xFE79), (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC), (0xFF10, 0xFF19), (0xFF21, 0xFF3A), (0xFF3F, 0xFF3F), (0xFF41, 0xFF5A), (0xFF65, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x101FD, 0x101FD), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x102E0, 0x102E0), (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x1037A), (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104A0, 0x104A9), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959), (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE6), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D27), (0x10D30, 0x10D39), (0x10D40, 0x10D65), (0x10D69, 0x10D6D), (0x10D6F, 0x10D85), (0x10E80, 0x10EA9), (0x10EAB, 0x10EAC), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10EFA, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F50), (0x10F70, 0x10F85), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11000, 0x11046), (0x11066, 0x11075), (0x1107F, 0x110BA), (0x110C2, 0x110C2), (0x110D0, 0x110E8), (0x110F0, 0x110F9), (0x11100, 0x11134), (0x11136, 0x1113F), (0x11144, 0x11147), (0x11150, 0x11173), (0x11176, 0x11176), (0x11180, 0x111C4), (0x111C9, 0x111CC), (0x111CE, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x11237), (0x1123E, 0x11241), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112EA), (0x112F0, 0x112F9), (0x11300, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133B, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11350, 0x11350), (0x11357, 0x11357), (0x1135D, 0x11363), (0x11366, 0x1136C), (0x11370, 0x11374), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5), (0x113B7, 0x113C0), (0x113C2, 0x113C2), (0x113C5, 0x113C5), (0x113C7, 0x113CA), (0x113CC, 0x113D3), (0x113E1, 0x113E2), (0x11400, 0x1144A), (0x11450, 0x11459), (0x1145E, 0x11461), (0x11480, 0x114C5), (0x114C7, 0x114C7), (0x114D0, 0x114D9), (0x11580, 0x115B5), (0x115B8, 0x115C0), (0x115D8, 0x115DD), (0x11600, 0x11640), (0x11644, 0x11644), (0x11650, 0x11659), (0x11680, 0x116B8), (0x116C0, 0x116C9), (0x116D0, 0x116E3), (0x11700, 0x1171A), (0x1171D, 0x1172B), (0x11730, 0x11739), (0x11740, 0x11746), (0x11800, 0x1183A), (0x118A0, 0x118E9), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935), (0x11937, 0x11938), (0x1193B, 0x11943), (0x11950, 0x11959), (0x119A0, 0x119A7), (0x119AA, 0x119D7), (0x119DA, 0x119E1), (0x119E3, 0x119E4), (0x11A00, 0x11A3E), (0x11A47, 0x11A47), (0x11A50, 0x11A99), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11B60, 0x11B67), (0x11BC0, 0x11BE0), (0x11BF0, 0x11BF9), (0x11C00, 0x11C08), (0x11C0A, 0x11C36), (0x11C38, 0x11C40), (0x11C50, 0x11C59), (0x11C72, 0x11C8F), (0x11C92, 0x11CA7), (0x11CA9, 0x11CB6), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D47), (0x11D50, 0x11D59), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E), (0x11D90, 0x11D91), (0x11D93, 0x11D98), (0x11DA0, 0x11DA9), (0x11DB0, 0x11DDB), (0x11DE0, 0x11DE9), (0x11EE0, 0x11EF6), (0x11F00, 0x11F10), (0x11F12, 0x11F3A), (0x11F3E, 0x11F42), (0x11F50, 0x11F5A), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342F), (0x13440, 0x13455), (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x16139), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A60, 0x16A69), (0x16A70, 0x16ABE), (0x16AC0, 0x16AC9), (0x16AD0, 0x16AED), (0x16AF0, 0x16AF4), (0x16B00, 0x16B36), (0x16B40, 0x16B43), (0x16B50, 0x16B59), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16D40, 0x16D6C), (0x16D70, 0x16D79), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x16F00, 0x16F4A), (0x16F4F, 0x16F87), (0x16F8F, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE4), (0x16FF0, 0x16FF6), (0x17000, 0x18CD5), (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1BC9D, 0x1BC9E), (0x1CCF0, 0x1CCF9), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D165, 0x1D169), (0x1D16D, 0x1D172), (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1D7CE, 0x1D7FF), (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E030, 0x1E06D), (0x1E08F, 0x1E08F), (0x1E100, 0x1E12C), (0x1E130, 0x1E13D), (0x1E140, 0x1E149), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AE), (0x1E2C0, 0x1E2F9), (0x1E4D0, 0x1E4F9), (0x1E5D0, 0x1E5FA), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6F5), (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E8D0, 0x1E8D6), (0x1E900, 0x1E94B), (0x1E950, 0x1E959), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x1FBF0, 0x1FBF9), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479), (0xE0100, 0xE01EF)],
+    ),
+    (
+        r"XID_Start",
+        &[(0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE), (0x370, 0x374), (0x376, 0x377), (0x37B, 0x37D), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x48A, 0x52F), (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x620, 0x64A), (0x66E, 0x66F), (0x671, 0x6D3), (0x6D5, 0x6D5), (0x6E5, 0x6E6), (0x6EE, 0x6EF), (0x6FA, 0x6FC), (0x6FF, 0x6FF), (0x710, 0x710), (0x712, 0x72F), (0x74D, 0x7A5), (0x7B1, 0x7B1), (0x7CA, 0x7EA), (0x7F4, 0x7F5), (0x7FA, 0x7FA), (0x800, 0x815), (0x81A, 0x81A), (0x824, 0x824), (0x828, 0x828), (0x840, 0x858), (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x8A0, 0x8C9), (0x904, 0x939), (0x93D, 0x93D), (0x950, 0x950), (0x958, 0x961), (0x971, 0x980), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8), (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BD, 0x9BD), (0x9CE, 0x9CE), (0x9DC, 0x9DD), (0x9DF, 0x9E1), (0x9F0, 0x9F1), (0x9FC, 0x9FC), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33), (0xA35, 0xA36), (0xA38, 0xA39), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA72, 0xA74), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABD, 0xABD), (0xAD0, 0xAD0), (0xAE0, 0xAE1), (0xAF9, 0xAF9), (0xB05, 0xB0C), (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3D, 0xB3D), (0xB5C, 0xB5D), (0xB5F, 0xB61), (0xB71, 0xB71), (0xB83, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90), (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA), (0xBAE, 0xBB9), (0xBD0, 0xBD0), (0xC05, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3D, 0xC3D), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC61), (0xC80, 0xC80), (0xC85, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBD, 0xCBD), (0xCDC, 0xCDE), (0xCE0, 0xCE1), (0xCF1, 0xCF2), (0xD04, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD3A), (0xD3D, 0xD3D), (0xD4E, 0xD4E), (0xD54, 0xD56), (0xD5F, 0xD61), (0xD7A, 0xD7F), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xE01, 0xE30), (0xE32, 0xE32), (0xE40, 0xE46), (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEB0), (0xEB2, 0xEB2), (0xEBD, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF40, 0xF47), (0xF49, 0xF6C), (0xF88, 0xF8C), (0x1000, 0x102A), (0x103F, 0x103F), (0x1050, 0x1055), (0x105A, 0x105D), (0x1061, 0x1061), (0x1065, 0x1066), (0x106E, 0x1070), (0x1075, 0x1081), (0x108E, 0x108E), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C), (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1711), (0x171F, 0x1731), (0x1740, 0x1751), (0x1760, 0x176C), (0x176E, 0x1770), (0x1780, 0x17B3), (0x17D7, 0x17D7), (0x17DC, 0x17DC), (0x1820, 0x1878), (0x1880, 0x18A8), (0x18AA, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1950, 0x196D), (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x1A00, 0x1A16), (0x1A20, 0x1A54), (0x1AA7, 0x1AA7), (0x1B05, 0x1B33), (0x1B45, 0x1B4C), (0x1B83, 0x1BA0), (0x1BAE, 0x1BAF), (0x1BBA, 0x1BE5), (0x1C00, 0x1C23), (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC), (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115), (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139), (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96), (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x3005, 0x3007), (0x3021, 0x3029), (0x3031, 0x3035), (0x3038, 0x303C), (0x3041, 0x3096), (0x309D, 0x309F), (0x30A1, 0x30FA), (0x30FC, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD), (0xA500, 0xA60C), (0xA610, 0xA61F), (0xA62A, 0xA62B), (0xA640, 0xA66E), (0xA67F, 0xA69D), (0xA6A0, 0xA6EF), (0xA717, 0xA71F), (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA801), (0xA803, 0xA805), (0xA807, 0xA80A), (0xA80C, 0xA822), (0xA840, 0xA873), (0xA882, 0xA8B3), (0xA8F2, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA8FE), (0xA90A, 0xA925), (0xA930, 0xA946), (0xA960, 0xA97C), (0xA984, 0xA9B2), (0xA9CF, 0xA9CF), (0xA9E0, 0xA9E4), (0xA9E6, 0xA9EF), (0xA9FA, 0xA9FE), (0xAA00, 0xAA28), (0xAA40, 0xAA42), (0xAA44, 0xAA4B), (0xAA60, 0xAA76), (0xAA7A, 0xAA7A), (0xAA7E, 0xAAAF), (0xAAB1, 0xAAB1), (0xAAB5, 0xAAB6), (0xAAB9, 0xAABD), (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEA), (0xAAF2, 0xAAF4), (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABE2), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D), (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDF9), (0xFE71, 0xFE71), (0xFE73, 0xFE73), (0xFE77, 0xFE77), (0xFE79, 0xFE79), (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC), (0xFF21, 0xFF3A), (0xFF41, 0xFF5A), (0xFF66, 0xFF9D), (0xFFA0, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x10375), (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959), (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A00), (0x10A10, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE4), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D23), (0x10D4A, 0x10D65), (0x10D6F, 0x10D85), (0x10E80, 0x10EA9), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10F00, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F45), (0x10F70, 0x10F81), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11003, 0x11037), (0x11071, 0x11072), (0x11075, 0x11075), (0x11083, 0x110AF), (0x110D0, 0x110E8), (0x11103, 0x11126), (0x11144, 0x11144), (0x11147, 0x11147), (0x11150, 0x11172), (0x11176, 0x11176), (0x11183, 0x111B2), (0x111C1, 0x111C4), (0x111DA, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x1122B), (0x1123F, 0x11240), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112DE), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133D, 0x1133D), (0x11350, 0x11350), (0x1135D, 0x11361), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5), (0x113B7, 0x113B7), (0x113D1, 0x113D1), (0x113D3, 0x113D3), (0x11400, 0x11434), (0x11447, 0x1144A), (0x1145F, 0x11461), (0x11480, 0x114AF), (0x114C4, 0x114C5), (0x114C7, 0x114C7), (0x11580, 0x115AE), (0x115D8, 0x115DB), (0x11600, 0x1162F), (0x11644, 0x11644), (0x11680, 0x116AA), (0x116B8, 0x116B8), (0x11700, 0x1171A), (0x11740, 0x11746), (0x11800, 0x1182B), (0x118A0, 0x118DF), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x1192F), (0x1193F, 0x1193F), (0x11941, 0x11941), (0x119A0, 0x119A7), (0x119AA, 0x119D0), (0x119E1, 0x119E1), (0x119E3, 0x119E3), (0x11A00, 0x11A00), (0x11A0B, 0x11A32), (0x11A3A, 0x11A3A), (0x11A50, 0x11A50), (0x11A5C, 0x11A89), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11BC0, 0x11BE0), (0x11C00, 0x11C08), (0x11C0A, 0x11C2E), (0x11C40, 0x11C40), (0x11C72, 0x11C8F), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D30), (0x11D46, 0x11D46), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D89), (0x11D98, 0x11D98), (0x11DB0, 0x11DDB), (0x11EE0, 0x11EF2), (0x11F02, 0x11F02), (0x11F04, 0x11F10), (0x11F12, 0x11F33), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342F), (0x13441, 0x13446), (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x1611D), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A70, 0x16ABE), (0x16AD0, 0x16AED), (0x16B00, 0x16B2F), (0x16B40, 0x16B43), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16D40, 0x16D6C), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x16F00, 0x16F4A), (0x16F50, 0x16F50), (0x16F93, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x16FF2, 0x16FF6), (0x17000, 0x18CD5), (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D), (0x1E100, 0x1E12C), (0x1E137, 0x1E13D), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AD), (0x1E2C0, 0x1E2EB), (0x1E4D0, 0x1E4EB), (0x1E5D0, 0x1E5ED), (0x1E5F0, 0x1E5F0), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6E2), (0x1E6E4, 0x1E6E5), (0x1E6E7, 0x1E6ED), (0x1E6F0, 0x1E6F4), (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E900, 0x1E943), (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479)],
+    ),
+];
+
+static BASH: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_0), keyword_pattern: r"\b[a-z][a-z0-9._-]+\b", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta"), begin: r"^#![ ]*\/.*\b(fish|bash|zsh|sh|csh|ksh|tcsh|dash|scsh)\b.*", end: Some(r"$"), terminator_end: r"$", relevance: 10.0, on_begin: Some(Callback::IgnoreUnlessFirst), ..MODE },
+    /* 2 */ Mode { scope: Some(r"meta"), begin: r"^#![ ]*\/", end: Some(r"$"), terminator_end: r"$", relevance: 0.0, on_begin: Some(Callback::IgnoreUnlessFirst), ..MODE },
+    /* 3 */ Mode { scope: Some(r"function"), begin: r"\w[\w\d_]*\s*\(\s*\)\s*\{", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[13], relevance: 0.0, return_begin: true, ..MODE },
+    /* 4 */ Mode { begin: r"\$?\(\(", end: Some(r"\)\)"), terminator_end: r"\)\)", contains: &[14, 15, 11, 12], ..MODE },
+    /* 5 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[16, 17], ..MODE },
+    /* 6 */ Mode { begin: r"<<-?\s*(?=\w+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(18), ..MODE },
+    /* 7 */ Mode { begin: r"(\/[a-z._-]+)+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 8 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[19, 11, 12, 20], ..MODE },
+    /* 9 */ Mode { scope: Some(r""), begin: r#"\\""#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", ..MODE },
+    /* 11 */ Mode { scope: Some(r"variable"), begin: r"\$[\w\d#@][\w\d_]*(?![\w\d])(?![$])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"variable"), begin: r"\$\{", end: Some(r"\}"), terminator_end: r"\}", contains: &[12, 21], ..MODE },
+    /* 13 */ Mode { scope: Some(r"title"), begin: r"\w[\w\d_]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 14 */ Mode { scope: Some(r"number"), begin: r"\d+#[0-9a-f]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 15 */ Mode { scope: Some(r"number"), begin: r"\b\d+(\.\d+)?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 17 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 18 */ Mode { begin: r"\B|\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[22], ..MODE },
+    /* 19 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"subst"), begin: r"\$\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[23, 8], ..MODE },
+    /* 21 */ Mode { begin: r":-", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[11, 12], ..MODE },
+    /* 22 */ Mode { scope: Some(r"string"), begin: r"(\w+)", end: Some(r"(\w+)"), terminator_end: r"(\w+)", on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 23 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static C: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"</"), keywords: Some(KEYWORDS_1), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 5, 16, 17], ..MODE },
+    /* 1 */ Mode { begin: r"=", end: Some(r";"), terminator_end: r";", keywords: Some(KEYWORDS_1), contains: &[5, 6, 7, 8, 18, 10, 11, 12, 13, 14, 15, 19], relevance: 0.0, ..MODE },
+    /* 2 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_1), contains: &[5, 6, 7, 8, 20, 10, 11, 12, 13, 14, 15, 19], relevance: 0.0, ..MODE },
+    /* 3 */ Mode { begin: r"\b(new|throw|return|else)(?!\.)(?=\b|\s)", end: Some(r";"), terminator_end: r";", keywords: Some(KEYWORDS_1), contains: &[5, 6, 7, 8, 21, 10, 11, 12, 13, 14, 15, 19], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 4 */ Mode { begin: r"((decltype\(auto\)|(?:[a-zA-Z_]\w*::)?[a-zA-Z_]\w*(?:<[^<>]+>)?)[\*&\s]+)+(?:[a-zA-Z_]\w*::)?[a-zA-Z]\w*\s*\(", end: Some(r"[{;=]"), terminator_end: r"[{;=]", illegal: Some(r"[^\w\s\*&:<>.]"), keywords: Some(KEYWORDS_1), contains: &[22, 23, 24, 25, 6, 7, 8, 26, 5], exclude_end: true, return_begin: true, ..MODE },
+    /* 5 */ Mode { scope: Some(r"meta"), begin: r"#\s*[a-z]+\b", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_2), contains: &[27, 28, 29, 30, 31, 8, 32], ..MODE },
+    /* 6 */ Mode { scope: Some(r"type"), begin: r"\b[a-z\d_]*_t\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { scope: Some(r"type"), begin: r"\batomic_[a-z]{3,6}\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 8 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[33, 34, 35], ..MODE },
+    /* 9 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[36, 37], ..MODE },
+    /* 10 */ Mode { scope: Some(r"number"), begin: r"\b(0b[01']+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { scope: Some(r"number"), begin: r"(-?)\b([\d']+(\.[\d']*)?|\.[\d']+)((ll|LL|l|L)(u|U)?|(u|U)(ll|LL|l|L)?|f|F|b|B)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 12 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9']+|(\b[\d']+(\.[\d']*)?|\.[\d']+)([eE][-+]?[\d']+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"string"), begin: r#"(u8?|U|L)?""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[38], ..MODE },
+    /* 14 */ Mode { scope: Some(r"string"), begin: r"(u8?|U|L)?'(\\(x[0-9A-Fa-f]{2}|u[0-9A-Fa-f]{4,8}|[0-7]{3}|\S)|.)", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"."), ..MODE },
+    /* 15 */ Mode { scope: Some(r"string"), begin: r#"(?:u8?|U|L)?R"([^()\\ ]{0,16})\("#, end: Some(r#"\)([^()\\ ]{0,16})""#), terminator_end: r#"\)([^()\\ ]{0,16})""#, on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 16 */ Mode { begin: r"[a-zA-Z]\w*::", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_1), ..MODE },
+    /* 17 */ Mode { scope: Some(r"class"), begin: r"\b(enum|class|struct|union)(?!\.)(?=\b|\s)", end: Some(r"[{;:<>=]"), terminator_end: r"[{;:<>=]", keywords: Some(KEYWORDS_3), contains: &[39, 40], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 18 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[41, 42], ..MODE },
+    /* 19 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_1), contains: &[5, 6, 7, 8, 43, 10, 11, 12, 13, 14, 15, 19], relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[44, 45], ..MODE },
+    /* 21 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[46, 47], ..MODE },
+    /* 22 */ Mode { begin: r"decltype\(auto\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_1), relevance: 0.0, ..MODE },
+    /* 23 */ Mode { begin: r"(?:[a-zA-Z_]\w*::)?[a-zA-Z]\w*\s*\(", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[48], relevance: 0.0, return_begin: true, ..MODE },
+    /* 24 */ Mode { begin: r",", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 25 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_1), contains: &[8, 49, 13, 14, 15, 10, 11, 12, 6, 7, 50], relevance: 0.0, ..MODE },
+    /* 26 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[51, 52], ..MODE },
+    /* 27 */ Mode { begin: r"\\\n", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 28 */ Mode { scope: Some(r"string"), begin: r#"(u8?|U|L)?""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[53], ..MODE },
+    /* 29 */ Mode { scope: Some(r"string"), begin: r"(u8?|U|L)?'(\\(x[0-9A-Fa-f]{2}|u[0-9A-Fa-f]{4,8}|[0-7]{3}|\S)|.)", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"."), ..MODE },
+    /* 30 */ Mode { scope: Some(r"string"), begin: r#"(?:u8?|U|L)?R"([^()\\ ]{0,16})\("#, end: Some(r#"\)([^()\\ ]{0,16})""#), terminator_end: r#"\)([^()\\ ]{0,16})""#, on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 31 */ Mode { scope: Some(r"string"), begin: r"<.*?>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 32 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[54, 55], ..MODE },
+    /* 33 */ Mode { begin: r"\\\n", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 34 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 35 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 36 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 37 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 38 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 39 */ Mode { begin: r"\b(final|class|struct)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_4), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 40 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 41 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 42 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 43 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[56, 57], ..MODE },
+    /* 44 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 45 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 46 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 47 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 48 */ Mode { scope: Some(r"title.function"), begin: r"(?:[a-zA-Z_]\w*::)?[a-zA-Z]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 49 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[58, 59], ..MODE },
+    /* 50 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_1), contains: &[50, 8, 60, 13, 14, 15, 10, 11, 12, 6, 7], relevance: 0.0, ..MODE },
+    /* 51 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 52 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 53 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 54 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 55 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 56 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 57 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 58 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 59 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 60 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[61, 62], ..MODE },
+    /* 61 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 62 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static CPP: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"</"), keywords: Some(KEYWORDS_5), contains: &[1, 2, 3, 4, 5, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 6, 16, 17, 18], ..MODE },
+    /* 1 */ Mode { begin: r"=", end: Some(r";"), terminator_end: r";", keywords: Some(KEYWORDS_5), contains: &[5, 6, 7, 8, 19, 10, 11, 12, 13, 14, 15, 20], relevance: 0.0, ..MODE },
+    /* 2 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_5), contains: &[5, 6, 7, 8, 21, 10, 11, 12, 13, 14, 15, 20], relevance: 0.0, ..MODE },
+    /* 3 */ Mode { begin: r"\b(new|throw|return|else)(?!\.)(?=\b|\s)", end: Some(r";"), terminator_end: r";", keywords: Some(KEYWORDS_5), contains: &[5, 6, 7, 8, 22, 10, 11, 12, 13, 14, 15, 20], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 4 */ Mode { scope: Some(r"function"), begin: r"((?!struct)(decltype\(auto\)|(?:[a-zA-Z_]\w*::)?[a-zA-Z_]\w*(?:<[^<>]+>)?)[\*&\s]+)+(?:[a-zA-Z_]\w*::)?[a-zA-Z]\w*\s*\(", end: Some(r"[{;=]"), terminator_end: r"[{;=]", illegal: Some(r"[^\w\s\*&:<>.]"), keywords: Some(KEYWORDS_5), contains: &[23, 24, 25, 26, 27, 28, 7, 8, 29, 6], exclude_end: true, return_begin: true, ..MODE },
+    /* 5 */ Mode { scope: Some(r"function.dispatch"), begin: r"\b(?!decltype)(?!if)(?!for)(?!switch)(?!while)[a-zA-Z]\w*(?=(<[^<>]+>|)\s*\()", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_6), relevance: 0.0, ..MODE },
+    /* 6 */ Mode { scope: Some(r"meta"), begin: r"#\s*[a-z]+\b", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_2), contains: &[30, 31, 32, 33, 34, 8, 35], ..MODE },
+    /* 7 */ Mode { scope: Some(r"type"), begin: r"\b[a-z\d_]*_t\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 8 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[36, 37, 38], ..MODE },
+    /* 9 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[39, 40], ..MODE },
+    /* 10 */ Mode { scope: Some(r"number"), begin: r"\b(0b[01']+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { scope: Some(r"number"), begin: r"(-?)\b([\d']+(\.[\d']*)?|\.[\d']+)((ll|LL|l|L)(u|U)?|(u|U)(ll|LL|l|L)?|f|F|b|B)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 12 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9']+|(\b[\d']+(\.[\d']*)?|\.[\d']+)([eE][-+]?[\d']+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"string"), begin: r#"(u8?|U|L)?""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[41], ..MODE },
+    /* 14 */ Mode { scope: Some(r"string"), begin: r"(u8?|U|L)?'(\\(x[0-9A-Fa-f]{2}|u[0-9A-Fa-f]{4,8}|[0-7]{3}|\S)|.)", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"."), ..MODE },
+    /* 15 */ Mode { scope: Some(r"string"), begin: r#"(?:u8?|U|L)?R"([^()\\ ]{0,16})\("#, end: Some(r#"\)([^()\\ ]{0,16})""#), terminator_end: r#"\)([^()\\ ]{0,16})""#, on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 16 */ Mode { begin: r"\b(deque|list|queue|priority_queue|pair|stack|vector|map|set|bitset|multiset|multimap|unordered_map|unordered_set|unordered_multiset|unordered_multimap|array|tuple|optional|variant|function)\s*<(?!<)", end: Some(r">"), terminator_end: r">", keywords: Some(KEYWORDS_5), contains: &[16, 7], ..MODE },
+    /* 17 */ Mode { begin: r"[a-zA-Z]\w*::", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_5), ..MODE },
+    /* 18 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class"))])), begin: r"(\b(?:enum(?:\s+(?:class|struct))?|class|struct|union))(\s+)(\w+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 19 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[42, 43], ..MODE },
+    /* 20 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_5), contains: &[5, 6, 7, 8, 44, 10, 11, 12, 13, 14, 15, 20], relevance: 0.0, ..MODE },
+    /* 21 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[45, 46], ..MODE },
+    /* 22 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[47, 48], ..MODE },
+    /* 23 */ Mode { begin: r"decltype\(auto\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_5), relevance: 0.0, ..MODE },
+    /* 24 */ Mode { begin: r"(?:[a-zA-Z_]\w*::)?[a-zA-Z]\w*\s*\(", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[49], relevance: 0.0, return_begin: true, ..MODE },
+    /* 25 */ Mode { begin: r"::", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 26 */ Mode { begin: r":", terminator_end: r"[{;=]", contains: &[13, 14, 15, 10, 11, 12], ends_with_parent: true, ..MODE },
+    /* 27 */ Mode { begin: r",", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 28 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_5), contains: &[8, 50, 13, 14, 15, 10, 11, 12, 7, 51], relevance: 0.0, ..MODE },
+    /* 29 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[52, 53], ..MODE },
+    /* 30 */ Mode { begin: r"\\\n", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 31 */ Mode { scope: Some(r"string"), begin: r#"(u8?|U|L)?""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[54], ..MODE },
+    /* 32 */ Mode { scope: Some(r"string"), begin: r"(u8?|U|L)?'(\\(x[0-9A-Fa-f]{2}|u[0-9A-Fa-f]{4,8}|[0-7]{3}|\S)|.)", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"."), ..MODE },
+    /* 33 */ Mode { scope: Some(r"string"), begin: r#"(?:u8?|U|L)?R"([^()\\ ]{0,16})\("#, end: Some(r#"\)([^()\\ ]{0,16})""#), terminator_end: r#"\)([^()\\ ]{0,16})""#, on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 34 */ Mode { scope: Some(r"string"), begin: r"<.*?>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 35 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[55, 56], ..MODE },
+    /* 36 */ Mode { begin: r"\\\n", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 37 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 38 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 39 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 40 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 41 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 42 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 43 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 44 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[57, 58], ..MODE },
+    /* 45 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 46 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 47 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 48 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 49 */ Mode { scope: Some(r"title"), begin: r"(?:[a-zA-Z_]\w*::)?[a-zA-Z]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 50 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[59, 60], ..MODE },
+    /* 51 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_5), contains: &[51, 8, 61, 13, 14, 15, 10, 11, 12, 7], relevance: 0.0, ..MODE },
+    /* 52 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 53 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 54 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 55 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 56 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 57 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 58 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 59 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 60 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 61 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[62, 63], ..MODE },
+    /* 62 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 63 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static CSHARP: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"::"), keywords: Some(KEYWORDS_7), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"///", end: Some(r"$"), terminator_end: r"$", contains: &[20, 21, 22, 23, 24], return_begin: true, ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[25, 26], ..MODE },
+    /* 3 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[27, 28], ..MODE },
+    /* 4 */ Mode { scope: Some(r"meta"), begin: r"#", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_8), ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r#"\$@""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[29, 30, 31, 32], ..MODE },
+    /* 6 */ Mode { scope: Some(r"string"), begin: r#"\$""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[33, 34, 35, 36], ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r#"@""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[37], ..MODE },
+    /* 8 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[38], ..MODE },
+    /* 9 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[39], ..MODE },
+    /* 10 */ Mode { scope: Some(r"number"), begin: r"\b(0b[01']+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { scope: Some(r"number"), begin: r"(-?)\b([\d']+(\.[\d']*)?|\.[\d']+)(u|U|l|L|ul|UL|f|F|b|B)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 12 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9']+|(\b[\d']+(\.[\d']*)?|\.[\d']+)([eE][-+]?[\d']+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 13 */ Mode { begin: r"\b(class|interface)(?!\.)(?=\b|\s)", end: Some(r"[{;=]"), terminator_end: r"[{;=]", illegal: Some(r"[^\s:,]"), keywords: Some(KEYWORDS_9), contains: &[40, 41, 42, 43, 44], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 14 */ Mode { begin: r"\b(namespace)(?!\.)(?=\b|\s)", end: Some(r"[{;=]"), terminator_end: r"[{;=]", illegal: Some(r"[^\s:]"), keywords: Some(KEYWORDS_10), contains: &[41, 45, 46], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 15 */ Mode { begin: r"\b(record)(?!\.)(?=\b|\s)", end: Some(r"[{;=]"), terminator_end: r"[{;=]", illegal: Some(r"[^\s:]"), keywords: Some(KEYWORDS_11), contains: &[41, 42, 47, 48], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 16 */ Mode { scope: Some(r"meta"), begin: r"^\s*\[(?=[\w])", end: Some(r"\]"), terminator_end: r"\]", contains: &[49], exclude_begin: true, exclude_end: true, ..MODE },
+    /* 17 */ Mode { begin: r"\b(new|return|throw|await|else)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_12), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 18 */ Mode { scope: Some(r"function"), begin: r"([a-zA-Z]\w*(<[a-zA-Z]\w*(\s*,\s*[a-zA-Z]\w*)*>)?(\[\])?\s+)+[a-zA-Z]\w*\s*(<[^=]+>\s*)?\(", end: Some(r"\s*[{;=]"), terminator_end: r"\s*[{;=]", keywords: Some(KEYWORDS_7), contains: &[50, 51, 52, 53, 54, 55], exclude_end: true, return_begin: true, ..MODE },
+    /* 19 */ Mode { begin: r"@[a-zA-Z]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"doctag"), begin: r"///", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 21 */ Mode { scope: Some(r"doctag"), begin: r"<!--|-->", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 22 */ Mode { scope: Some(r"doctag"), begin: r"</?", end: Some(r">"), terminator_end: r">", ..MODE },
+    /* 23 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 24 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 25 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 26 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 27 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 28 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 29 */ Mode { begin: r"\{\{", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 30 */ Mode { begin: r"\}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 31 */ Mode { begin: r#""""#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 32 */ Mode { scope: Some(r"subst"), begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", keywords: Some(KEYWORDS_7), contains: &[56, 57, 58, 59, 60, 10, 11, 12, 61], ..MODE },
+    /* 33 */ Mode { begin: r"\{\{", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 34 */ Mode { begin: r"\}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 35 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 36 */ Mode { scope: Some(r"subst"), begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", illegal: Some(r"\n"), keywords: Some(KEYWORDS_7), contains: &[62, 57, 63, 64, 65, 10, 11, 12, 66], ..MODE },
+    /* 37 */ Mode { begin: r#""""#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 38 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 39 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 40 */ Mode { begin: r"\b(where|class)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_13), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 41 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z](\.?\w)*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 42 */ Mode { begin: r"<", end: Some(r">"), terminator_end: r">", contains: &[67, 41], ..MODE },
+    /* 43 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[68, 69], ..MODE },
+    /* 44 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[70, 71], ..MODE },
+    /* 45 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[72, 73], ..MODE },
+    /* 46 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[74, 75], ..MODE },
+    /* 47 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[76, 77], ..MODE },
+    /* 48 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[78, 79], ..MODE },
+    /* 49 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, ..MODE },
+    /* 50 */ Mode { begin: r"\b(public|private|protected|static|internal|protected|abstract|async|extern|override|unsafe|virtual|new|sealed|partial)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_14), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 51 */ Mode { begin: r"[a-zA-Z]\w*\s*(<[^=]+>\s*)?\(", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[80, 42], relevance: 0.0, return_begin: true, ..MODE },
+    /* 52 */ Mode { begin: r"\(\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 53 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_7), contains: &[5, 6, 7, 8, 9, 10, 11, 12, 81], relevance: 0.0, exclude_begin: true, exclude_end: true, ..MODE },
+    /* 54 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[82, 83], ..MODE },
+    /* 55 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[84, 85], ..MODE },
+    /* 56 */ Mode { scope: Some(r"string"), begin: r#"\$@""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[29, 30, 31, 32], ..MODE },
+    /* 57 */ Mode { scope: Some(r"string"), begin: r#"\$""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[33, 34, 86, 36], ..MODE },
+    /* 58 */ Mode { scope: Some(r"string"), begin: r#"@""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[37], ..MODE },
+    /* 59 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[87], ..MODE },
+    /* 60 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[88], ..MODE },
+    /* 61 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[89, 90], ..MODE },
+    /* 62 */ Mode { scope: Some(r"string"), begin: r#"\$@""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[91, 92, 93, 36], ..MODE },
+    /* 63 */ Mode { scope: Some(r"string"), begin: r#"@""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[37], ..MODE },
+    /* 64 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[94], ..MODE },
+    /* 65 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[95], ..MODE },
+    /* 66 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", illegal: Some(r"\n"), contains: &[96, 97], ..MODE },
+    /* 67 */ Mode { begin: r"\b(in|out)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_15), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 68 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 69 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 70 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 71 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 72 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 73 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 74 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 75 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 76 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 77 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 78 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 79 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 80 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 81 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[98, 99], ..MODE },
+    /* 82 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 83 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 84 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 85 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 86 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 87 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 88 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 89 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 90 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 91 */ Mode { begin: r"\{\{", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 92 */ Mode { begin: r"\}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 93 */ Mode { begin: r#""""#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 94 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 95 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 96 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 97 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 98 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 99 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static CSS: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"[=|'\$]"), keywords: Some(KEYWORDS_16), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[14, 15], ..MODE },
+    /* 2 */ Mode { begin: r"-(webkit|moz|ms|o)-(?=[a-z])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 3 */ Mode { scope: Some(r"number"), begin: r"\b\d+(\.\d+)?(%|em|ex|ch|rem|vw|vh|vmin|vmax|cm|mm|in|pt|pc|px|deg|grad|rad|turn|s|ms|Hz|kHz|dpi|dpcm|dppx)?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 4 */ Mode { scope: Some(r"selector-id"), begin: r"#[A-Za-z0-9_-]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 5 */ Mode { scope: Some(r"selector-class"), begin: r"\.[a-zA-Z-][a-zA-Z0-9_-]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 6 */ Mode { scope: Some(r"selector-attr"), begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", illegal: Some(r"$"), contains: &[16, 17], ..MODE },
+    /* 7 */ Mode { scope: Some(r"selector-pseudo"), begin: r":(active|any-link|blank|checked|current|default|defined|dir|disabled|drop|empty|enabled|first|first-child|first-of-type|fullscreen|future|focus|focus-visible|focus-within|has|host|host-context|hover|indeterminate|in-range|invalid|is|lang|last-child|last-of-type|left|link|local-link|not|nth-child|nth-col|nth-last-child|nth-last-col|nth-last-of-type|nth-of-type|only-child|only-of-type|optional|out-of-range|past|placeholder-shown|read-only|read-write|required|right|root|scope|target|target-within|user-invalid|valid|visited|where)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 8 */ Mode { scope: Some(r"selector-pseudo"), begin: r":(:)?(after|backdrop|before|cue|cue-region|first-letter|first-line|grammar-error|marker|part|placeholder|selection|slotted|spelling-error)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 9 */ Mode { scope: Some(r"attr"), begin: r"--[A-Za-z][A-Za-z0-9_-]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { scope: Some(r"attribute"), begin: r"\b(z-index|writing-mode|word-wrap|word-spacing|word-break|will-change|width|widows|white-space|voice-volume|voice-stress|voice-rate|voice-range|voice-pitch|voice-family|voice-duration|voice-balance|visibility|vertical-align|unicode-bidi|transition-timing-function|transition-property|transition-duration|transition-delay|transition|transform-style|transform-origin|transform-box|transform|top|text-underline-position|text-transform|text-shadow|text-rendering|text-overflow|text-orientation|text-justify|text-indent|text-emphasis-style|text-emphasis-position|text-emphasis-color|text-emphasis|text-decoration-style|text-decoration-line|text-decoration-color|text-decoration|text-combine-upright|text-align-last|text-align-all|text-align|table-layout|tab-size|src|speak-as|speak|shape-outside|shape-margin|shape-image-threshold|scrollbar-width|scrollbar-gutter|scrollbar-color|scroll-snap-type|scroll-snap-stop|scroll-snap-align|scroll-padding-top|scroll-padding-right|scroll-padding-left|scroll-padding-inline-start|scroll-padding-inline-end|scroll-padding-inline|scroll-padding-bottom|scroll-padding-block-start|scroll-padding-block-end|scroll-padding-block|scroll-padding|scroll-margin-top|scroll-margin-right|scroll-margin-left|scroll-margin-inline-start|scroll-margin-inline-end|scroll-margin-inline|scroll-margin-bottom|scroll-margin-block-start|scroll-margin-block-end|scroll-margin-block|scroll-margin|row-gap|right|rest-before|rest-after|rest|resize|quotes|position|pointer-events|perspective-origin|perspective|pause-before|pause-after|pause|page-break-inside|page-break-before|page-break-after|padding-top|padding-right|padding-left|padding-inline-start|padding-inline-end|padding-inline|padding-bottom|padding-block-start|padding-block-end|padding-block|padding|overflow-y|overflow-x|overflow-wrap|overflow|outline-width|outline-style|outline-offset|outline-color|outline|orphans|order|opacity|object-position|object-fit|normal|none|nav-up|nav-right|nav-left|nav-index|nav-down|mix-blend-mode|min-width|min-inline-size|min-height|min-block-size|max-width|max-inline-size|max-height|max-block-size|mask-type|mask-size|mask-repeat|mask-position|mask-origin|mask-mode|mask-image|mask-composite|mask-clip|mask-border-width|mask-border-source|mask-border-slice|mask-border-repeat|mask-border-outset|mask-border-mode|mask-border|mask|marks|margin-top|margin-right|margin-left|margin-inline-start|margin-inline-end|margin-inline|margin-bottom|margin-block-start|margin-block-end|margin-block|margin|list-style-type|list-style-position|list-style-image|list-style|line-height|line-break|letter-spacing|left|justify-content|isolation|inline-size|ime-mode|image-resolution|image-rendering|image-orientation|icon|hyphens|height|hanging-punctuation|grid-template-rows|grid-template-columns|grid-template-areas|grid-template|grid-row-start|grid-row-end|grid-row|grid-gap|grid-column-start|grid-column-end|grid-column|grid-auto-rows|grid-auto-flow|grid-auto-columns|grid-area|grid|glyph-orientation-vertical|gap|font-weight|font-variation-settings|font-variant-position|font-variant-numeric|font-variant-ligatures|font-variant-east-asian|font-variant-caps|font-variant|font-synthesis|font-style|font-stretch|font-smoothing|font-size-adjust|font-size|font-language-override|font-kerning|font-feature-settings|font-family|font-display|font|flow|float|flex-wrap|flex-shrink|flex-grow|flex-flow|flex-direction|flex-basis|flex|filter|empty-cells|display|direction|cursor|cue-before|cue-after|cue|counter-reset|counter-increment|content-visibility|content|contain|columns|column-width|column-span|column-rule-width|column-rule-style|column-rule-color|column-rule|column-gap|column-fill|column-count|color|clip-rule|clip-path|clip|clear|caret-color|caption-side|break-inside|break-before|break-after|box-sizing|box-shadow|box-decoration-break|bottom|border-width|border-top-width|border-top-style|border-top-right-radius|border-top-left-radius|border-top-color|border-top|border-style|border-spacing|border-right-width|border-right-style|border-right-color|border-right|border-radius|border-left-width|border-left-style|border-left-color|border-left|border-inline-width|border-inline-style|border-inline-start-width|border-inline-start-style|border-inline-start-color|border-inline-start|border-inline-end-width|border-inline-end-style|border-inline-end-color|border-inline-end|border-inline-color|border-inline|border-image-width|border-image-source|border-image-slice|border-image-repeat|border-image-outset|border-image|border-color|border-collapse|border-bottom-width|border-bottom-style|border-bottom-right-radius|border-bottom-left-radius|border-bottom-color|border-bottom|border-block-width|border-block-style|border-block-start-width|border-block-start-style|border-block-start-color|border-block-start|border-block-end-width|border-block-end-style|border-block-end-color|border-block-end|border-block-color|border-block|border|block-size|background-size|background-repeat|background-position|background-origin|background-image|background-color|background-clip|background-blend-mode|background-attachment|background|backface-visibility|animation-timing-function|animation-play-state|animation-name|animation-iteration-count|animation-fill-mode|animation-duration|animation-direction|animation-delay|animation|all|align-self|align-items|align-content)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { begin: r":", end: Some(r"[;}{]"), terminator_end: r"[;}{]", contains: &[18, 19, 20, 3, 21, 22, 23, 24], ..MODE },
+    /* 12 */ Mode { begin: r"(?=@)", end: Some(r"[{;]"), terminator_end: r"[{;]", illegal: Some(r":"), contains: &[25, 26], relevance: 0.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"selector-tag"), begin: r"\b(a|abbr|address|article|aside|audio|b|blockquote|body|button|canvas|caption|cite|code|dd|del|details|dfn|div|dl|dt|em|fieldset|figcaption|figure|footer|form|h1|h2|h3|h4|h5|h6|header|hgroup|html|i|iframe|img|input|ins|kbd|label|legend|li|main|mark|menu|nav|object|ol|p|q|quote|samp|section|span|strong|summary|sup|table|tbody|td|textarea|tfoot|th|thead|time|tr|ul|var|video)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 14 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 15 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 16 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[27], ..MODE },
+    /* 17 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[28], ..MODE },
+    /* 18 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[29, 30], ..MODE },
+    /* 19 */ Mode { scope: Some(r"number"), begin: r"#(([0-9a-fA-F]{3,4})|(([0-9a-fA-F]{2}){3,4}))\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 20 */ Mode { scope: Some(r"meta"), begin: r"!important", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 21 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[31], ..MODE },
+    /* 22 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[32], ..MODE },
+    /* 23 */ Mode { begin: r"(url|data-uri)\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_17), contains: &[33, 34, 35], relevance: 0.0, ..MODE },
+    /* 24 */ Mode { scope: Some(r"built_in"), begin: r"[\w-]+(?=\()", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 25 */ Mode { scope: Some(r"keyword"), begin: r"@-?\w[\w]*(-\w+)*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 26 */ Mode { begin: r"\s", terminator_end: r"[{;]", keywords: Some(KEYWORDS_18), keyword_pattern: r"[a-z-]+", contains: &[36, 37, 38, 3], relevance: 0.0, exclude_end: true, ends_with_parent: true, ..MODE },
+    /* 27 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 28 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 29 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 30 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 31 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 33 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[39], ..MODE },
+    /* 34 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[40], ..MODE },
+    /* 35 */ Mode { scope: Some(r"string"), begin: r"[^)]", terminator_end: r"\)", exclude_end: true, ends_with_parent: true, ..MODE },
+    /* 36 */ Mode { scope: Some(r"attribute"), begin: r"[a-z-]+(?=:)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 37 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[41], ..MODE },
+    /* 38 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[42], ..MODE },
+    /* 39 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 40 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 41 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 42 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static DIFF: &[Mode] = &[
+    /* 0 */ Mode { contains: &[1, 2, 3, 4, 5, 6], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta"), begin: r"(?:^@@ +-\d+,\d+ +\+\d+,\d+ +@@|^\*\*\* +\d+,\d+ +\*\*\*\*$|^--- +\d+,\d+ +----$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 10.0, ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"(?:Index: |^index|={3,}|^-{3}|^\*{3} |^\+{3}|^diff --git)", end: Some(r"$"), terminator_end: r"$", ..MODE },
+    /* 3 */ Mode { scope: Some(r"comment"), begin: r"^\*{15}$", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 4 */ Mode { scope: Some(r"addition"), begin: r"^\+", end: Some(r"$"), terminator_end: r"$", ..MODE },
+    /* 5 */ Mode { scope: Some(r"deletion"), begin: r"^-", end: Some(r"$"), terminator_end: r"$", ..MODE },
+    /* 6 */ Mode { scope: Some(r"addition"), begin: r"^!", end: Some(r"$"), terminator_end: r"$", ..MODE },
+];
+
+static GO: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"</"), keywords: Some(KEYWORDS_19), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[10, 11], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[12, 13], ..MODE },
+    /* 3 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[14], ..MODE },
+    /* 4 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[15], ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r"`", end: Some(r"`"), terminator_end: r"`", ..MODE },
+    /* 6 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)[i]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 8 */ Mode { begin: r":=", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 9 */ Mode { scope: Some(r"function"), begin: r"\b(func)(?!\.)(?=\b|\s)", end: Some(r"\s*(\{|$)"), terminator_end: r"\s*(\{|$)", keywords: Some(KEYWORDS_20), contains: &[16, 17], relevance: 0.0, exclude_end: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 10 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 11 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 13 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 14 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 15 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", illegal: Some(r#"["']"#), keywords: Some(KEYWORDS_19), ends_parent: true, ..MODE },
+];
+
+static GRAPHQL: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"(?:[;<']|BEGIN)"), keywords: Some(KEYWORDS_21), contains: &[1, 2, 3, 4, 5, 6, 7, 8], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[9, 10], ..MODE },
+    /* 2 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[11], ..MODE },
+    /* 3 */ Mode { scope: Some(r"number"), begin: r"\b\d+(\.\d+)?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 4 */ Mode { scope: Some(r"punctuation"), begin: r"[.]{3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 5 */ Mode { scope: Some(r"punctuation"), begin: r"[\!\(\)\:\=\[\]\{\|\}]{1}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 6 */ Mode { scope: Some(r"variable"), begin: r"\$", end: Some(r"\W"), terminator_end: r"\W", relevance: 0.0, exclude_end: true, ..MODE },
+    /* 7 */ Mode { scope: Some(r"meta"), begin: r"@\w+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", exclude_end: true, ..MODE },
+    /* 8 */ Mode { scope: Some(r"symbol"), begin: r"[_A-Za-z][_0-9A-Za-z]*(?=\s*:)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 10 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static INI: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"\S"), contains: &[1, 2, 3, 4], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r";", end: Some(r"$"), terminator_end: r"$", contains: &[5, 6], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[5, 6], ..MODE },
+    /* 3 */ Mode { scope: Some(r"section"), begin: r"\[+", end: Some(r"\]+"), terminator_end: r"\]+", ..MODE },
+    /* 4 */ Mode { scope: Some(r"attr"), begin: r#"(?:[A-Za-z0-9_-]+|"(\\"|[^"])*"|'[^']*')(\s*\.\s*(?:[A-Za-z0-9_-]+|"(\\"|[^"])*"|'[^']*'))*(?=\s*=\s*[^#\s])"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(7), ..MODE },
+    /* 5 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 6 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { begin: r"\B|\b", end: Some(r"$"), terminator_end: r"$", contains: &[1, 2, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17], ..MODE },
+    /* 8 */ Mode { begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", contains: &[1, 2, 9, 10, 11, 12, 13, 14, 15, 16, 17, 8], relevance: 0.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"literal"), begin: r"\bon|off|true|false|yes|no\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { scope: Some(r"variable"), begin: r#"\$[\w\d"][\w\d_]*"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { scope: Some(r"variable"), begin: r"\$\{(.*?)\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"string"), begin: r"'''", end: Some(r"'''"), terminator_end: r"'''", contains: &[18], relevance: 10.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"string"), begin: r#"""""#, end: Some(r#"""""#), terminator_end: r#"""""#, contains: &[19], relevance: 10.0, ..MODE },
+    /* 14 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[20], ..MODE },
+    /* 15 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[21], ..MODE },
+    /* 16 */ Mode { scope: Some(r"number"), begin: r"([+-]+)?[\d]+_[\d_]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"number"), begin: r"\b\d+(\.\d+)?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 20 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 21 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static JAVA: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"<\/|#"), keywords: Some(KEYWORDS_22), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"/\*\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[24, 25, 26, 27], relevance: 0.0, ..MODE },
+    /* 2 */ Mode { begin: r"import java\.[a-z]+\.", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_23), relevance: 2.0, ..MODE },
+    /* 3 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[28, 29], ..MODE },
+    /* 4 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[30, 31], ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r#"""""#, end: Some(r#"""""#), terminator_end: r#"""""#, contains: &[32], ..MODE },
+    /* 6 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[33], ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[34], ..MODE },
+    /* 8 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class"))])), begin: r"(\b(?:class|interface|enum|extends|implements|new))(\s+)([À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 9 */ Mode { scope: Some(r"keyword"), begin: r"non-sealed", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"type")), (2, None), (3, Some(r"variable")), (4, None), (5, Some(r"operator"))])), begin: r"((?!else)[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*)(\s+)([À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*)(\s+)(=(?!=))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class"))])), begin: r"(record)(\s+)([À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[35, 36, 37], ..MODE },
+    /* 12 */ Mode { begin: r"\b(new|throw|return|else)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_24), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 13 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, Some(r"title.function")), (3, None)])), begin: r"((?:[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:<[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:<[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:<[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:\s*,\s*[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*)*>)?(?:\s*,\s*[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:<[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:\s*,\s*[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*)*>)?)*>)?(?:\s*,\s*[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:<[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:<[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:\s*,\s*[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*)*>)?(?:\s*,\s*[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:<[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*(?:\s*,\s*[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*)*>)?)*>)?)*>)?\s+))([a-zA-Z_]\w*)(\s*(?=\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_22), contains: &[38, 39, 40], ..MODE },
+    /* 14 */ Mode { scope: Some(r"number"), begin: r"(\b([0-9](_*[0-9])*)((\.([0-9](_*[0-9])*))|\.)?|(\.([0-9](_*[0-9])*)))[eE][+-]?([0-9](_*[0-9])*)[fFdD]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 15 */ Mode { scope: Some(r"number"), begin: r"\b([0-9](_*[0-9])*)((\.([0-9](_*[0-9])*))[fFdD]?\b|\.([fFdD]\b)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { scope: Some(r"number"), begin: r"(\.([0-9](_*[0-9])*))[fFdD]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"number"), begin: r"\b([0-9](_*[0-9])*)[fFdD]\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { scope: Some(r"number"), begin: r"\b0[xX](([0-9a-fA-F](_*[0-9a-fA-F])*)\.?|([0-9a-fA-F](_*[0-9a-fA-F])*)?\.([0-9a-fA-F](_*[0-9a-fA-F])*))[pP][+-]?([0-9](_*[0-9])*)[fFdD]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { scope: Some(r"number"), begin: r"\b(0|[1-9](_*[0-9])*)[lL]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"number"), begin: r"\b0[xX]([0-9a-fA-F](_*[0-9a-fA-F])*)[lL]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 21 */ Mode { scope: Some(r"number"), begin: r"\b0(_*[0-7])*[lL]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 22 */ Mode { scope: Some(r"number"), begin: r"\b0[bB][01](_*[01])*[lL]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 23 */ Mode { scope: Some(r"meta"), begin: r"@[À-ʸa-zA-Z_$][À-ʸa-zA-Z_$0-9]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[41], ..MODE },
+    /* 24 */ Mode { begin: r"\w+@", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 25 */ Mode { scope: Some(r"doctag"), begin: r"@[A-Za-z]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 26 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 27 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 28 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 29 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 30 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 31 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 32 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 33 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 34 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 35 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_22), contains: &[42], relevance: 0.0, ends_parent: true, ..MODE },
+    /* 36 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[43, 44], ..MODE },
+    /* 37 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[45, 46], ..MODE },
+    /* 38 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_22), contains: &[23, 47, 48, 14, 15, 16, 17, 18, 19, 20, 21, 22, 49], relevance: 0.0, ..MODE },
+    /* 39 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[50, 51], ..MODE },
+    /* 40 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[52, 53], ..MODE },
+    /* 41 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[41], ..MODE },
+    /* 42 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[54, 55], ..MODE },
+    /* 43 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 44 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 45 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 46 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 47 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[56], ..MODE },
+    /* 48 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[57], ..MODE },
+    /* 49 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[58, 59], ..MODE },
+    /* 50 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 51 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 52 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 53 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 54 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 55 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 56 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 57 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 58 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 59 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static JAVASCRIPT: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"#(?![$_A-z])"), keywords: Some(KEYWORDS_25), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta"), begin: r"^#![ ]*\/.*\bnode\b.*", end: Some(r"$"), terminator_end: r"$", relevance: 5.0, on_begin: Some(Callback::IgnoreUnlessFirst), ..MODE },
+    /* 2 */ Mode { scope: Some(r"meta"), begin: r#"^\s*['"]use (strict|asm)['"]"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 10.0, ..MODE },
+    /* 3 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[37], ..MODE },
+    /* 4 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[38], ..MODE },
+    /* 5 */ Mode { begin: r"html`", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(39), ..MODE },
+    /* 6 */ Mode { begin: r"css`", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(40), ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r"`", end: Some(r"`"), terminator_end: r"`", contains: &[41, 42], ..MODE },
+    /* 8 */ Mode { scope: Some(r"comment"), begin: r"\/\*\*(?!\/)", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[43, 44, 45], relevance: 0.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[46, 47], ..MODE },
+    /* 10 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[48, 49], ..MODE },
+    /* 11 */ Mode { begin: r"\$\d+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"number"), begin: r"(\b(0|[1-9](_?[0-9])*|0[0-7]*[89][0-9]*)((\.([0-9](_?[0-9])*))|\.)?|(\.([0-9](_?[0-9])*)))[eE][+-]?([0-9](_?[0-9])*)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"number"), begin: r"\b(0|[1-9](_?[0-9])*|0[0-7]*[89][0-9]*)\b((\.([0-9](_?[0-9])*))\b|\.)?|(\.([0-9](_?[0-9])*))\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 14 */ Mode { scope: Some(r"number"), begin: r"\b(0|[1-9](_?[0-9])*)n\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 15 */ Mode { scope: Some(r"number"), begin: r"\b0[xX][0-9a-fA-F](_?[0-9a-fA-F])*n?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { scope: Some(r"number"), begin: r"\b0[bB][0-1](_?[0-1])*n?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"number"), begin: r"\b0[oO][0-7](_?[0-7])*n?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { scope: Some(r"number"), begin: r"\b0[0-7]+n?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { scope: Some(r"title.class"), begin: r"(?:\bJSON|\b[A-Z][a-z]+([A-Z][a-z]*|\d)*|\b[A-Z]{2,}([A-Z][a-z]+|\d)+([A-Z][a-z]*)*|\b[A-Z]{2,}[a-z]+([A-Z][a-z]+|\d)*([A-Z][a-z]*)*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_26), relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"attr"), begin: r"[A-Za-z$_][0-9A-Za-z$_]*(?=:)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 21 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function")), (4, None), (5, None), (6, None), (8, None)])), begin: r"(const|var|let)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)(\s*)(=\s*)((async\s*)?)((?=(\([^()]*(\([^()]*(\([^()]*\)[^()]*)*\)[^()]*)*\)|[a-zA-Z_]\w*)\s*=>))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_27), contains: &[50], ..MODE },
+    /* 22 */ Mode { begin: r"(!|!=|!==|%|%=|&|&&|&=|\*|\*=|\+|\+=|,|-|-=|/=|/|:|;|<<|<<=|<=|<|===|==|=|>>>=|>>=|>=|>>>|>>|>|\?|\[|\{|\(|\^|\^=|\||\|=|\|\||~|\b(case|return|throw)\b)\s*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_28), contains: &[8, 9, 10, 51, 52, 53, 54, 55, 56, 57], relevance: 0.0, ..MODE },
+    /* 23 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function")), (4, None)])), begin: r"(function)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)((?=\s*\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", illegal: Some(r"%"), contains: &[50], ..MODE },
+    /* 24 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None)])), begin: r"(function)(\s*(?=\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", illegal: Some(r"%"), contains: &[50], ..MODE },
+    /* 25 */ Mode { begin: r"\b(while|if|switch|catch|for)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_29), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 26 */ Mode { begin: r"\b(?!function)[a-zA-Z_]\w*\([^()]*(\([^()]*(\([^()]*\)[^()]*)*\)[^()]*)*\)\s*\{", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[50, 58], return_begin: true, ..MODE },
+    /* 27 */ Mode { begin: r"\.\.\.", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 28 */ Mode { scope: Some(r"property"), begin: r"\.(?=[A-Za-z$_][0-9A-Za-z$_]*(?![0-9A-Za-z$_(]))", end: Some(r"[A-Za-z$_][0-9A-Za-z$_]*"), terminator_end: r"[A-Za-z$_][0-9A-Za-z$_]*", keywords: Some(KEYWORDS_30), relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 29 */ Mode { begin: r"\$[A-Za-z$_][0-9A-Za-z$_]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 30 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"title.function"))])), begin: r"(\bconstructor(?=\s*\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[50], ..MODE },
+    /* 31 */ Mode { scope: Some(r"title.function"), begin: r"\b(?!setInterval|setTimeout|clearInterval|clearTimeout|require|exports|eval|isFinite|isNaN|parseFloat|parseInt|decodeURI|decodeURIComponent|encodeURI|encodeURIComponent|escape|unescape|super|import)[A-Za-z$_][0-9A-Za-z$_]*(?=\()", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { scope: Some(r"variable.constant"), begin: r"\b[A-Z][A-Z_0-9]+\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 33 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class")), (4, None), (5, Some(r"keyword")), (6, None), (7, Some(r"title.class.inherited"))])), begin: r"(class)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)(\s+)(extends)(\s+)([A-Za-z$_][0-9A-Za-z$_]*(\.[A-Za-z$_][0-9A-Za-z$_]*)*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 34 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class"))])), begin: r"(class)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 35 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function")), (4, None)])), begin: r"(get|set)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)((?=\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[59, 50], ..MODE },
+    /* 36 */ Mode { begin: r"\$[(.]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 37 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 38 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 39 */ Mode { begin: r"\B|\b", end: Some(r"`"), terminator_end: r"`", contains: &[60, 42], sub_language: Some(SubLanguage::One(r"xml")), ..MODE },
+    /* 40 */ Mode { begin: r"\B|\b", end: Some(r"`"), terminator_end: r"`", contains: &[61, 42], sub_language: Some(SubLanguage::One(r"css")), ..MODE },
+    /* 41 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 42 */ Mode { scope: Some(r"subst"), begin: r"\$\{", end: Some(r"\}"), terminator_end: r"\}", keywords: Some(KEYWORDS_25), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[62, 63, 5, 6, 7, 64, 12, 13, 14, 15, 16, 17, 18, 65], ..MODE },
+    /* 43 */ Mode { begin: r"(?=@[A-Za-z]+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[66, 67, 68, 69], relevance: 0.0, ..MODE },
+    /* 44 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 45 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 46 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 47 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 48 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 49 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 50 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_25), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[8, 9, 10, 70, 71, 5, 6, 7, 64, 12, 13, 14, 15, 16, 17, 18, 65, 72], exclude_begin: true, exclude_end: true, ..MODE },
+    /* 51 */ Mode { begin: r"(?=\/[^/\n]*\/)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[73], ..MODE },
+    /* 52 */ Mode { scope: Some(r"function"), begin: r"(\([^()]*(\([^()]*(\([^()]*\)[^()]*)*\)[^()]*)*\)|[a-zA-Z_]\w*)\s*=>", end: Some(r"\s*=>"), terminator_end: r"\s*=>", contains: &[74, 75, 76], return_begin: true, ..MODE },
+    /* 53 */ Mode { begin: r",", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 54 */ Mode { begin: r"\s+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 55 */ Mode { begin: r"<>", end: Some(r"<\/>"), terminator_end: r"</>", contains: &[77], sub_language: Some(SubLanguage::One(r"xml")), ..MODE },
+    /* 56 */ Mode { begin: r"<[A-Za-z0-9\\._:-]+\s*\/>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[77], sub_language: Some(SubLanguage::One(r"xml")), ..MODE },
+    /* 57 */ Mode { begin: r"<[A-Za-z0-9\\._:-]+", end: Some(r"\/[A-Za-z0-9\\._:-]+>|\/>"), terminator_end: r"\/[A-Za-z0-9\\._:-]+>|\/>", contains: &[77], sub_language: Some(SubLanguage::One(r"xml")), on_begin: Some(Callback::IgnoreUnlessJsxTag), ..MODE },
+    /* 58 */ Mode { scope: Some(r"title.function"), begin: r"[A-Za-z$_][0-9A-Za-z$_]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 59 */ Mode { begin: r"\(\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 60 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 61 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 62 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[78], ..MODE },
+    /* 63 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[79], ..MODE },
+    /* 64 */ Mode { begin: r"\$\d+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 65 */ Mode { begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", keywords: Some(KEYWORDS_25), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[65, 80, 81, 5, 6, 7, 64, 12, 13, 14, 15, 16, 17, 18], ..MODE },
+    /* 66 */ Mode { scope: Some(r"doctag"), begin: r"@[A-Za-z]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 67 */ Mode { scope: Some(r"type"), begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", relevance: 0.0, exclude_begin: true, exclude_end: true, ..MODE },
+    /* 68 */ Mode { scope: Some(r"variable"), begin: r"[A-Za-z$_][0-9A-Za-z$_]*(?=\s*(-)|$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ends_parent: true, ..MODE },
+    /* 69 */ Mode { begin: r"(?=[^\n])\s", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 70 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[82], ..MODE },
+    /* 71 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[83], ..MODE },
+    /* 72 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_25), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[72, 8, 9, 10, 84, 85, 5, 6, 7, 64, 12, 13, 14, 15, 16, 17, 18, 65], ..MODE },
+    /* 73 */ Mode { scope: Some(r"regexp"), begin: r"\/", end: Some(r"\/[gimuy]*"), terminator_end: r"\/[gimuy]*", illegal: Some(r"\n"), contains: &[86, 87], ..MODE },
+    /* 74 */ Mode { scope: Some(r"params"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 75 */ Mode { begin: r"\(\s*\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", skip: true, ..MODE },
+    /* 76 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_25), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[8, 9, 10, 88, 89, 5, 6, 7, 64, 12, 13, 14, 15, 16, 17, 18, 65, 72], exclude_begin: true, exclude_end: true, ..MODE },
+    /* 77 */ Mode { begin: r"<[A-Za-z0-9\\._:-]+", end: Some(r"\/[A-Za-z0-9\\._:-]+>|\/>"), terminator_end: r"\/[A-Za-z0-9\\._:-]+>|\/>", contains: &[77], skip: true, ..MODE },
+    /* 78 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 79 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 80 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[90], ..MODE },
+    /* 81 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[91], ..MODE },
+    /* 82 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 83 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 84 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[92], ..MODE },
+    /* 85 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[93], ..MODE },
+    /* 86 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 87 */ Mode { begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", contains: &[94], relevance: 0.0, ..MODE },
+    /* 88 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[95], ..MODE },
+    /* 89 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[96], ..MODE },
+    /* 90 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 91 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 92 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 93 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 94 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 95 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 96 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static JSON: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"\S"), keywords: Some(KEYWORDS_31), contains: &[1, 2, 3, 4, 5, 6, 7], ..MODE },
+    /* 1 */ Mode { scope: Some(r"attr"), begin: r#""(\\.|[^\\"\r\n])*"(?=\s*:)"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 1.01, ..MODE },
+    /* 2 */ Mode { scope: Some(r"punctuation"), begin: r"[{}[\],:]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 3 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[8], ..MODE },
+    /* 4 */ Mode { scope: Some(r"literal"), begin: r"\b(true|false|null)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_32), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 5 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 6 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[9, 10], ..MODE },
+    /* 7 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[11, 12], ..MODE },
+    /* 8 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 10 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 12 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static KOTLIN: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_33), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"/\*\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[23, 24, 25], relevance: 0.0, ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[26, 27], ..MODE },
+    /* 3 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[28, 29, 30], ..MODE },
+    /* 4 */ Mode { scope: Some(r"keyword"), begin: r"\b(break|continue|return|this)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(31), ..MODE },
+    /* 5 */ Mode { scope: Some(r"symbol"), begin: r"[a-zA-Z_]\w*@", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 6 */ Mode { scope: Some(r"meta"), begin: r"@(?:file|property|field|get|set|receiver|param|setparam|delegate)\s*:(?:\s*[a-zA-Z_]\w*)?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { scope: Some(r"meta"), begin: r"@[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[32], ..MODE },
+    /* 8 */ Mode { scope: Some(r"function"), begin: r"\b(fun)(?!\.)(?=\b|\s)", end: Some(r"[(]|$"), terminator_end: r"[(]|$", keywords: Some(KEYWORDS_33), contains: &[33, 34, 35, 3], relevance: 5.0, exclude_end: true, return_begin: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 9 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, None), (3, Some(r"title.class"))])), begin: r"(class|interface|trait)(\s+)([a-zA-Z_]\w*)", end: Some(r"[:\{(]|$"), terminator_end: r"[:\{(]|$", illegal: Some(r"extends implements"), keywords: Some(KEYWORDS_34), contains: &[36, 37, 38, 39, 6, 7], exclude_end: true, ..MODE },
+    /* 10 */ Mode { scope: Some(r"string"), begin: r#"""""#, end: Some(r#""""(?=[^"])"#), terminator_end: r#""""(?=[^"])"#, contains: &[40, 41], ..MODE },
+    /* 11 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[42], ..MODE },
+    /* 12 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[43, 40, 41], ..MODE },
+    /* 13 */ Mode { scope: Some(r"meta"), begin: r"^#!/usr/bin/env", end: Some(r"$"), terminator_end: r"$", illegal: Some("\u{a}"), ..MODE },
+    /* 14 */ Mode { scope: Some(r"number"), begin: r"(\b([0-9](_*[0-9])*)((\.([0-9](_*[0-9])*))|\.)?|(\.([0-9](_*[0-9])*)))[eE][+-]?([0-9](_*[0-9])*)[fFdD]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 15 */ Mode { scope: Some(r"number"), begin: r"\b([0-9](_*[0-9])*)((\.([0-9](_*[0-9])*))[fFdD]?\b|\.([fFdD]\b)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { scope: Some(r"number"), begin: r"(\.([0-9](_*[0-9])*))[fFdD]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"number"), begin: r"\b([0-9](_*[0-9])*)[fFdD]\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { scope: Some(r"number"), begin: r"\b0[xX](([0-9a-fA-F](_*[0-9a-fA-F])*)\.?|([0-9a-fA-F](_*[0-9a-fA-F])*)?\.([0-9a-fA-F](_*[0-9a-fA-F])*))[pP][+-]?([0-9](_*[0-9])*)[fFdD]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { scope: Some(r"number"), begin: r"\b(0|[1-9](_*[0-9])*)[lL]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"number"), begin: r"\b0[xX]([0-9a-fA-F](_*[0-9a-fA-F])*)[lL]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 21 */ Mode { scope: Some(r"number"), begin: r"\b0(_*[0-7])*[lL]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 22 */ Mode { scope: Some(r"number"), begin: r"\b0[bB][01](_*[01])*[lL]?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 23 */ Mode { scope: Some(r"doctag"), begin: r"@[A-Za-z]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 24 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 25 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 26 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 27 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 28 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[44, 45], ..MODE },
+    /* 29 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 30 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 31 */ Mode { begin: r"\B|\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[46], ..MODE },
+    /* 32 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[47, 48, 49, 32], ..MODE },
+    /* 33 */ Mode { begin: r"[a-zA-Z_]\w*\s*\(", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[50], relevance: 0.0, return_begin: true, ..MODE },
+    /* 34 */ Mode { scope: Some(r"type"), begin: r"<", end: Some(r">"), terminator_end: r">", keywords: Some(KEYWORDS_35), relevance: 0.0, ..MODE },
+    /* 35 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_33), contains: &[51, 52, 3, 6, 7, 10, 11, 12, 53], relevance: 0.0, ends_parent: true, ..MODE },
+    /* 36 */ Mode { begin: r"\b(public|protected|internal|private|constructor)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_36), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 37 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 38 */ Mode { scope: Some(r"type"), begin: r"<", end: Some(r">"), terminator_end: r">", relevance: 0.0, exclude_begin: true, exclude_end: true, ..MODE },
+    /* 39 */ Mode { scope: Some(r"type"), begin: r"[,:]\s*", end: Some(r"[<\(,){\s]|$"), terminator_end: r"[<\(,){\s]|$", exclude_begin: true, return_end: true, ..MODE },
+    /* 40 */ Mode { scope: Some(r"variable"), begin: r"\$[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 41 */ Mode { scope: Some(r"subst"), begin: r"\$\{", end: Some(r"\}"), terminator_end: r"\}", contains: &[54, 10, 11, 12], ..MODE },
+    /* 42 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 43 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 44 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 45 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 46 */ Mode { scope: Some(r"symbol"), begin: r"@\w+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 47 */ Mode { scope: Some(r"string"), begin: r#"""""#, end: Some(r#""""(?=[^"])"#), terminator_end: r#""""(?=[^"])"#, contains: &[40, 41], ..MODE },
+    /* 48 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[55], ..MODE },
+    /* 49 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[56, 40, 41], ..MODE },
+    /* 50 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 51 */ Mode { begin: r":", end: Some(r"[=,\/]"), terminator_end: r"[=,\/]|\)", contains: &[57, 58, 59, 3], relevance: 0.0, ends_with_parent: true, ..MODE },
+    /* 52 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[60, 61], ..MODE },
+    /* 53 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 54 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 55 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 56 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 57 */ Mode { scope: Some(r"type"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 58 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[57, 58], ..MODE },
+    /* 59 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[62, 63], ..MODE },
+    /* 60 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 61 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 62 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 63 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static LESS: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r#"[=>'/<($"]"#), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[12, 13], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[14, 15], ..MODE },
+    /* 3 */ Mode { scope: Some(r"keyword"), begin: r"@(import|media|charset|font-face|(-[a-z]+-)?keyframes|supports|document|namespace|page|viewport|host)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(16), ..MODE },
+    /* 4 */ Mode { scope: Some(r"variable"), begin: r"@[\w-]+\s*:", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(17), relevance: 15.0, ..MODE },
+    /* 5 */ Mode { scope: Some(r"variable"), begin: r"@[\w-]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(17), ..MODE },
+    /* 6 */ Mode { begin: r"[\w-]+:(:)?(active|any-link|blank|checked|current|default|defined|dir|disabled|drop|empty|enabled|first|first-child|first-of-type|fullscreen|future|focus|focus-visible|focus-within|has|host|host-context|hover|indeterminate|in-range|invalid|is|lang|last-child|last-of-type|left|link|local-link|not|nth-child|nth-col|nth-last-child|nth-last-col|nth-last-of-type|nth-of-type|only-child|only-of-type|optional|out-of-range|past|placeholder-shown|read-only|read-write|required|right|root|scope|target|target-within|user-invalid|valid|visited|where|after|backdrop|before|cue|cue-region|first-letter|first-line|grammar-error|marker|part|placeholder|selection|slotted|spelling-error)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[8, 9], return_begin: true, ..MODE },
+    /* 7 */ Mode { begin: r"([\w-]+|@\{[\w-]+\})\s*:", end: Some(r"[;}]"), terminator_end: r"[;}]", contains: &[18, 19, 20], relevance: 0.0, return_begin: true, ..MODE },
+    /* 8 */ Mode { begin: r"[\.#:&\[>]", end: Some(r"[;{}]"), terminator_end: r"[;{}]", illegal: Some(r#"[<='$"]"#), contains: &[21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 11], relevance: 0.0, return_begin: true, return_end: true, ..MODE },
+    /* 9 */ Mode { begin: r"([\w-]+|@\{[\w-]+\})", end: Some(r"\{"), terminator_end: r"\{", illegal: Some(r#"[<='$"]"#), contains: &[37, 38, 39, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 11], relevance: 0.0, return_begin: true, return_end: true, ..MODE },
+    /* 10 */ Mode { begin: r"\b(when)(?!\.)(?=\b|\s)", keywords: Some(KEYWORDS_37), contains: &[40, 41, 42, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11], relevance: 0.0, ends_with_parent: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 11 */ Mode { scope: Some(r"built_in"), begin: r"[\w-]+(?=\()", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 13 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 14 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 15 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 16 */ Mode { begin: r"\B|\b", end: Some(r"[;{}]"), terminator_end: r"[;{}]", keywords: Some(KEYWORDS_18), keyword_pattern: r"[a-z-]+", contains: &[54, 55, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11], relevance: 0.0, return_end: true, ..MODE },
+    /* 17 */ Mode { begin: r"\B|\b", end: Some(r"[;}]"), terminator_end: r"[;}]", contains: &[56, 57, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11, 58], return_end: true, ..MODE },
+    /* 18 */ Mode { begin: r"-(webkit|moz|ms|o)-", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 19 */ Mode { scope: Some(r"attr"), begin: r"--[A-Za-z][A-Za-z0-9_-]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 20 */ Mode { scope: Some(r"attribute"), begin: r"\b(z-index|writing-mode|word-wrap|word-spacing|word-break|will-change|width|widows|white-space|voice-volume|voice-stress|voice-rate|voice-range|voice-pitch|voice-family|voice-duration|voice-balance|visibility|vertical-align|unicode-bidi|transition-timing-function|transition-property|transition-duration|transition-delay|transition|transform-style|transform-origin|transform-box|transform|top|text-underline-position|text-transform|text-shadow|text-rendering|text-overflow|text-orientation|text-justify|text-indent|text-emphasis-style|text-emphasis-position|text-emphasis-color|text-emphasis|text-decoration-style|text-decoration-line|text-decoration-color|text-decoration|text-combine-upright|text-align-last|text-align-all|text-align|table-layout|tab-size|src|speak-as|speak|shape-outside|shape-margin|shape-image-threshold|scrollbar-width|scrollbar-gutter|scrollbar-color|scroll-snap-type|scroll-snap-stop|scroll-snap-align|scroll-padding-top|scroll-padding-right|scroll-padding-left|scroll-padding-inline-start|scroll-padding-inline-end|scroll-padding-inline|scroll-padding-bottom|scroll-padding-block-start|scroll-padding-block-end|scroll-padding-block|scroll-padding|scroll-margin-top|scroll-margin-right|scroll-margin-left|scroll-margin-inline-start|scroll-margin-inline-end|scroll-margin-inline|scroll-margin-bottom|scroll-margin-block-start|scroll-margin-block-end|scroll-margin-block|scroll-margin|row-gap|right|rest-before|rest-after|rest|resize|quotes|position|pointer-events|perspective-origin|perspective|pause-before|pause-after|pause|page-break-inside|page-break-before|page-break-after|padding-top|padding-right|padding-left|padding-inline-start|padding-inline-end|padding-inline|padding-bottom|padding-block-start|padding-block-end|padding-block|padding|overflow-y|overflow-x|overflow-wrap|overflow|outline-width|outline-style|outline-offset|outline-color|outline|orphans|order|opacity|object-position|object-fit|normal|none|nav-up|nav-right|nav-left|nav-index|nav-down|mix-blend-mode|min-width|min-inline-size|min-height|min-block-size|max-width|max-inline-size|max-height|max-block-size|mask-type|mask-size|mask-repeat|mask-position|mask-origin|mask-mode|mask-image|mask-composite|mask-clip|mask-border-width|mask-border-source|mask-border-slice|mask-border-repeat|mask-border-outset|mask-border-mode|mask-border|mask|marks|margin-top|margin-right|margin-left|margin-inline-start|margin-inline-end|margin-inline|margin-bottom|margin-block-start|margin-block-end|margin-block|margin|list-style-type|list-style-position|list-style-image|list-style|line-height|line-break|letter-spacing|left|justify-content|isolation|inline-size|ime-mode|image-resolution|image-rendering|image-orientation|icon|hyphens|height|hanging-punctuation|grid-template-rows|grid-template-columns|grid-template-areas|grid-template|grid-row-start|grid-row-end|grid-row|grid-gap|grid-column-start|grid-column-end|grid-column|grid-auto-rows|grid-auto-flow|grid-auto-columns|grid-area|grid|glyph-orientation-vertical|gap|font-weight|font-variation-settings|font-variant-position|font-variant-numeric|font-variant-ligatures|font-variant-east-asian|font-variant-caps|font-variant|font-synthesis|font-style|font-stretch|font-smoothing|font-size-adjust|font-size|font-language-override|font-kerning|font-feature-settings|font-family|font-display|font|flow|float|flex-wrap|flex-shrink|flex-grow|flex-flow|flex-direction|flex-basis|flex|filter|empty-cells|display|direction|cursor|cue-before|cue-after|cue|counter-reset|counter-increment|content-visibility|content|contain|columns|column-width|column-span|column-rule-width|column-rule-style|column-rule-color|column-rule|column-gap|column-fill|column-count|color|clip-rule|clip-path|clip|clear|caret-color|caption-side|break-inside|break-before|break-after|box-sizing|box-shadow|box-decoration-break|bottom|border-width|border-top-width|border-top-style|border-top-right-radius|border-top-left-radius|border-top-color|border-top|border-style|border-spacing|border-right-width|border-right-style|border-right-color|border-right|border-radius|border-left-width|border-left-style|border-left-color|border-left|border-inline-width|border-inline-style|border-inline-start-width|border-inline-start-style|border-inline-start-color|border-inline-start|border-inline-end-width|border-inline-end-style|border-inline-end-color|border-inline-end|border-inline-color|border-inline|border-image-width|border-image-source|border-image-slice|border-image-repeat|border-image-outset|border-image|border-color|border-collapse|border-bottom-width|border-bottom-style|border-bottom-right-radius|border-bottom-left-radius|border-bottom-color|border-bottom|border-block-width|border-block-style|border-block-start-width|border-block-start-style|border-block-start-color|border-block-start|border-block-end-width|border-block-end-style|border-block-end-color|border-block-end|border-block-color|border-block|border|block-size|background-size|background-repeat|background-position|background-origin|background-image|background-color|background-clip|background-blend-mode|background-attachment|background|backface-visibility|animation-timing-function|animation-play-state|animation-name|animation-iteration-count|animation-fill-mode|animation-duration|animation-direction|animation-delay|animation|all|align-self|align-items|align-content)\b", end: Some(r"(?=:)"), terminator_end: r"(?=:)", starts: Some(59), ..MODE },
+    /* 21 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[60, 61], ..MODE },
+    /* 22 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[62, 63], ..MODE },
+    /* 23 */ Mode { begin: r"\b(when)(?!\.)(?=\b|\s)", terminator_end: r"[;{}]", keywords: Some(KEYWORDS_37), contains: &[40, 64, 65, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11], relevance: 0.0, ends_with_parent: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 24 */ Mode { scope: Some(r"keyword"), begin: r"all\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 25 */ Mode { scope: Some(r"variable"), begin: r"@\{[\w-]+\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 26 */ Mode { scope: Some(r"selector-tag"), begin: r"\b(a|abbr|address|article|aside|audio|b|blockquote|body|button|canvas|caption|cite|code|dd|del|details|dfn|div|dl|dt|em|fieldset|figcaption|figure|footer|form|h1|h2|h3|h4|h5|h6|header|hgroup|html|i|iframe|img|input|ins|kbd|label|legend|li|main|mark|menu|nav|object|ol|p|q|quote|samp|section|span|strong|summary|sup|table|tbody|td|textarea|tfoot|th|thead|time|tr|ul|var|video)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 27 */ Mode { scope: Some(r"number"), begin: r"\b\d+(\.\d+)?(%|em|ex|ch|rem|vw|vh|vmin|vmax|cm|mm|in|pt|pc|px|deg|grad|rad|turn|s|ms|Hz|kHz|dpi|dpcm|dppx)?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 28 */ Mode { scope: Some(r"selector-tag"), begin: r"([\w-]+|@\{[\w-]+\})", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 29 */ Mode { scope: Some(r"selector-id"), begin: r"#([\w-]+|@\{[\w-]+\})", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 30 */ Mode { scope: Some(r"selector-class"), begin: r"\.([\w-]+|@\{[\w-]+\})", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 31 */ Mode { scope: Some(r"selector-tag"), begin: r"&", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { scope: Some(r"selector-attr"), begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", illegal: Some(r"$"), contains: &[66, 67], ..MODE },
+    /* 33 */ Mode { scope: Some(r"selector-pseudo"), begin: r":(active|any-link|blank|checked|current|default|defined|dir|disabled|drop|empty|enabled|first|first-child|first-of-type|fullscreen|future|focus|focus-visible|focus-within|has|host|host-context|hover|indeterminate|in-range|invalid|is|lang|last-child|last-of-type|left|link|local-link|not|nth-child|nth-col|nth-last-child|nth-last-col|nth-last-of-type|nth-of-type|only-child|only-of-type|optional|out-of-range|past|placeholder-shown|read-only|read-write|required|right|root|scope|target|target-within|user-invalid|valid|visited|where)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 34 */ Mode { scope: Some(r"selector-pseudo"), begin: r":(:)?(after|backdrop|before|cue|cue-region|first-letter|first-line|grammar-error|marker|part|placeholder|selection|slotted|spelling-error)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 35 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[68, 69, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11, 58], relevance: 0.0, ..MODE },
+    /* 36 */ Mode { begin: r"!important", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 37 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[70, 71], ..MODE },
+    /* 38 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[72, 73], ..MODE },
+    /* 39 */ Mode { begin: r"\b(when)(?!\.)(?=\b|\s)", terminator_end: r"\{", keywords: Some(KEYWORDS_37), contains: &[40, 74, 75, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11], relevance: 0.0, ends_with_parent: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 40 */ Mode { begin: r"\b(and|not)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_38), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 41 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[76, 77], ..MODE },
+    /* 42 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[78, 79], ..MODE },
+    /* 43 */ Mode { scope: Some(r"string"), begin: r"~?'.*?'", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 44 */ Mode { scope: Some(r"string"), begin: r#"~?".*?""#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 45 */ Mode { begin: r"(url|data-uri)\(", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(80), ..MODE },
+    /* 46 */ Mode { scope: Some(r"number"), begin: r"#(([0-9a-fA-F]{3,4})|(([0-9a-fA-F]{2}){3,4}))\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 47 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_18), keyword_pattern: r"[a-z-]+", contains: &[81, 82, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11], relevance: 0.0, ..MODE },
+    /* 48 */ Mode { scope: Some(r"variable"), begin: r"@@?[\w-]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 10.0, ..MODE },
+    /* 49 */ Mode { scope: Some(r"variable"), begin: r"@\{[\w-]+\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 50 */ Mode { scope: Some(r"built_in"), begin: r"~?`[^`]*?`", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 51 */ Mode { scope: Some(r"attribute"), begin: r"[\w-]+\s*:", end: Some(r":"), terminator_end: r":", exclude_end: true, return_begin: true, ..MODE },
+    /* 52 */ Mode { scope: Some(r"meta"), begin: r"!important", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 53 */ Mode { begin: r"\b(and|not)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_38), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 54 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[83, 84], ..MODE },
+    /* 55 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[85, 86], ..MODE },
+    /* 56 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[87, 88], ..MODE },
+    /* 57 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[89, 90], ..MODE },
+    /* 58 */ Mode { begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", contains: &[91, 92, 3, 4, 5, 6, 7, 8, 9, 93, 11], ..MODE },
+    /* 59 */ Mode { begin: r"\B|\b", terminator_end: r"[;}]", illegal: Some(r"[<=$]"), contains: &[94, 95, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11], relevance: 0.0, ends_with_parent: true, ..MODE },
+    /* 60 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 61 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 62 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 63 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 64 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[96, 97], ..MODE },
+    /* 65 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[98, 99], ..MODE },
+    /* 66 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[100], ..MODE },
+    /* 67 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[101], ..MODE },
+    /* 68 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[102, 103], ..MODE },
+    /* 69 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[104, 105], ..MODE },
+    /* 70 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 71 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 72 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 73 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 74 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[106, 107], ..MODE },
+    /* 75 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[108, 109], ..MODE },
+    /* 76 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 77 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 78 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 79 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 80 */ Mode { scope: Some(r"string"), begin: r"\B|\b", end: Some(r"[\)\n]"), terminator_end: r"[\)\n]", exclude_end: true, ..MODE },
+    /* 81 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[110, 111], ..MODE },
+    /* 82 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[112, 113], ..MODE },
+    /* 83 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 84 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 85 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 86 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 87 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 88 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 89 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 90 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 91 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[114, 115], ..MODE },
+    /* 92 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[116, 117], ..MODE },
+    /* 93 */ Mode { begin: r"\b(when)(?!\.)(?=\b|\s)", terminator_end: r"\}", keywords: Some(KEYWORDS_37), contains: &[40, 118, 119, 43, 44, 27, 45, 46, 47, 48, 49, 50, 51, 52, 53, 11], relevance: 0.0, ends_with_parent: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 94 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[120, 121], ..MODE },
+    /* 95 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[122, 123], ..MODE },
+    /* 96 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 97 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 98 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 99 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 100 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 101 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 102 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 103 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 104 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 105 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 106 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 107 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 108 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 109 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 110 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 111 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 112 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 113 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 114 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 115 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 116 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 117 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 118 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[124, 125], ..MODE },
+    /* 119 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[126, 127], ..MODE },
+    /* 120 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 121 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 122 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 123 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 124 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 125 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 126 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 127 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static LUA: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_39), keyword_pattern: r"[a-zA-Z_]\w*", contains: &[1, 2, 3, 4, 5, 6, 7], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"--(?!\[=*\[)", end: Some(r"$"), terminator_end: r"$", contains: &[8, 9], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"--\[=*\[", end: Some(r"\]=*\]"), terminator_end: r"\]=*\]", contains: &[10, 11, 12], relevance: 10.0, ..MODE },
+    /* 3 */ Mode { scope: Some(r"function"), begin: r"\b(function)(?!\.)(?=\b|\s)", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_40), contains: &[13, 14, 1, 2], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 4 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[15], ..MODE },
+    /* 6 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[16], ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r"\[=*\[", end: Some(r"\]=*\]"), terminator_end: r"\]=*\]", contains: &[10], relevance: 5.0, ..MODE },
+    /* 8 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 9 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { begin: r"\[=*\[", end: Some(r"\]=*\]"), terminator_end: r"\]=*\]", contains: &[10], ..MODE },
+    /* 11 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 12 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 13 */ Mode { scope: Some(r"title"), begin: r"([_a-zA-Z]\w*\.)*([_a-zA-Z]\w*:)?[_a-zA-Z]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 14 */ Mode { scope: Some(r"params"), begin: r"\(", terminator_end: r"\)", contains: &[1, 2], ends_with_parent: true, ..MODE },
+    /* 15 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static MAKEFILE: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_41), keyword_pattern: r"[\w-]+", contains: &[1, 2, 3, 4, 5, 6, 7, 8], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[9, 10], ..MODE },
+    /* 2 */ Mode { scope: Some(r"variable"), begin: r"\$\([a-zA-Z_]\w*\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[11], ..MODE },
+    /* 3 */ Mode { scope: Some(r"variable"), begin: r"\$[@%<?\^\+\*]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 4 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[12, 2, 3], ..MODE },
+    /* 5 */ Mode { scope: Some(r"variable"), begin: r"\$\([\w-]+\s", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_42), contains: &[2, 3], ..MODE },
+    /* 6 */ Mode { begin: r"^[a-zA-Z_]\w*\s*(?=[:+?]?=)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { scope: Some(r"meta"), begin: r"^\.PHONY:", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_43), keyword_pattern: r"[\.\w]+", ..MODE },
+    /* 8 */ Mode { scope: Some(r"section"), begin: r"^[^\s]+:", end: Some(r"$"), terminator_end: r"$", contains: &[2, 3], ..MODE },
+    /* 9 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 10 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 12 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static XML: &[Mode] = &[
+    /* 0 */ Mode { contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta"), begin: r"<![a-z]", end: Some(r">"), terminator_end: r">", contains: &[12, 13, 14, 15, 16], relevance: 10.0, ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"<!--", end: Some(r"-->"), terminator_end: r"-->", contains: &[17, 18], relevance: 10.0, ..MODE },
+    /* 3 */ Mode { begin: r"<!\[CDATA\[", end: Some(r"\]\]>"), terminator_end: r"\]\]>", relevance: 10.0, ..MODE },
+    /* 4 */ Mode { scope: Some(r"symbol"), begin: r"&[a-z]+;|&#[0-9]+;|&#x[a-f0-9]+;", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 5 */ Mode { scope: Some(r"meta"), begin: r"<\?xml", end: Some(r"\?>"), terminator_end: r"\?>", contains: &[13], relevance: 10.0, ..MODE },
+    /* 6 */ Mode { scope: Some(r"meta"), begin: r"<\?[a-z][a-z0-9]+", end: Some(r"\?>"), terminator_end: r"\?>", ..MODE },
+    /* 7 */ Mode { scope: Some(r"tag"), begin: r"<style(?=\s|>)", end: Some(r">"), terminator_end: r">", keywords: Some(KEYWORDS_44), contains: &[19], starts: Some(20), ..MODE },
+    /* 8 */ Mode { scope: Some(r"tag"), begin: r"<script(?=\s|>)", end: Some(r">"), terminator_end: r">", keywords: Some(KEYWORDS_45), contains: &[21], starts: Some(22), ..MODE },
+    /* 9 */ Mode { scope: Some(r"tag"), begin: r"<>|<\/>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { scope: Some(r"tag"), begin: r"<(?=[\p{L}_](?:[\p{L}0-9_.-]*:)?[\p{L}0-9_.-]*(?:\/>|>|\s))", end: Some(r"\/?>"), terminator_end: r"\/?>", contains: &[23], ..MODE },
+    /* 11 */ Mode { scope: Some(r"tag"), begin: r"<\/(?=[\p{L}_](?:[\p{L}0-9_.-]*:)?[\p{L}0-9_.-]*>)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[24, 25], ..MODE },
+    /* 12 */ Mode { begin: r"\s", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[26], ..MODE },
+    /* 13 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[27], ..MODE },
+    /* 14 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[28], ..MODE },
+    /* 15 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[26], ..MODE },
+    /* 16 */ Mode { begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", contains: &[29], ..MODE },
+    /* 17 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 18 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 19 */ Mode { begin: r"\B|\b", terminator_end: r">", illegal: Some(r"<"), contains: &[30, 31], relevance: 0.0, ends_with_parent: true, ..MODE },
+    /* 20 */ Mode { begin: r"\B|\b", end: Some(r"<\/style>"), terminator_end: r"<\/style>", sub_language: Some(SubLanguage::Any(&[r"css", r"xml"])), return_end: true, ..MODE },
+    /* 21 */ Mode { begin: r"\B|\b", terminator_end: r">", illegal: Some(r"<"), contains: &[30, 31], relevance: 0.0, ends_with_parent: true, ..MODE },
+    /* 22 */ Mode { begin: r"\B|\b", end: Some(r"<\/script>"), terminator_end: r"<\/script>", sub_language: Some(SubLanguage::Any(&[r"javascript", r"handlebars", r"xml"])), return_end: true, ..MODE },
+    /* 23 */ Mode { scope: Some(r"name"), begin: r"[\p{L}_](?:[\p{L}0-9_.-]*:)?[\p{L}0-9_.-]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(32), relevance: 0.0, ..MODE },
+    /* 24 */ Mode { scope: Some(r"name"), begin: r"[\p{L}_](?:[\p{L}0-9_.-]*:)?[\p{L}0-9_.-]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 25 */ Mode { begin: r">", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ends_parent: true, ..MODE },
+    /* 26 */ Mode { scope: Some(r"keyword"), begin: r"#?[a-z_][a-z1-9_-]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", illegal: Some(r"\n"), ..MODE },
+    /* 27 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 28 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 29 */ Mode { scope: Some(r"meta"), begin: r"<![a-z]", end: Some(r">"), terminator_end: r">", contains: &[12, 15, 13, 14], ..MODE },
+    /* 30 */ Mode { scope: Some(r"attr"), begin: r"[\p{L}0-9._:-]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 31 */ Mode { begin: r"=\s*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[33, 34, 35], relevance: 0.0, ..MODE },
+    /* 32 */ Mode { begin: r"\B|\b", terminator_end: r"\/?>", illegal: Some(r"<"), contains: &[30, 31], relevance: 0.0, ends_with_parent: true, ..MODE },
+    /* 33 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[4], ends_parent: true, ..MODE },
+    /* 34 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[4], ends_parent: true, ..MODE },
+    /* 35 */ Mode { scope: Some(r"string"), begin: r#"[^\s"'=<>`]+"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ends_parent: true, ..MODE },
+];
+
+static MARKDOWN: &[Mode] = &[
+    /* 0 */ Mode { contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22], ..MODE },
+    /* 1 */ Mode { scope: Some(r"section"), begin: r"^#{1,6}", end: Some(r"$"), terminator_end: r"$", contains: &[3, 17, 18, 19, 20, 21, 5, 6, 7, 8], ..MODE },
+    /* 2 */ Mode { scope: Some(r"section"), begin: r"(?=^.+?\n[=-]{2,}$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[23, 24], ..MODE },
+    /* 3 */ Mode { begin: r"<\/?[A-Za-z_]", end: Some(r">"), terminator_end: r">", relevance: 0.0, sub_language: Some(SubLanguage::One(r"xml")), ..MODE },
+    /* 4 */ Mode { scope: Some(r"bullet"), begin: "^[ \u{9}]*([*+-]|(\\d+\\.))(?=\\s+)", end: Some(r"\s+"), terminator_end: r"\s+", exclude_end: true, ..MODE },
+    /* 5 */ Mode { scope: Some(r"strong"), begin: r"_{2}(?!\s)", end: Some(r"_{2}"), terminator_end: r"_{2}", contains: &[25, 26, 3, 17, 18, 19, 20, 21], ..MODE },
+    /* 6 */ Mode { scope: Some(r"strong"), begin: r"\*{2}(?!\s)", end: Some(r"\*{2}"), terminator_end: r"\*{2}", contains: &[25, 26, 3, 17, 18, 19, 20, 21], ..MODE },
+    /* 7 */ Mode { scope: Some(r"emphasis"), begin: r"\*(?![*\s])", end: Some(r"\*"), terminator_end: r"\*", contains: &[27, 28, 3, 17, 18, 19, 20, 21], ..MODE },
+    /* 8 */ Mode { scope: Some(r"emphasis"), begin: r"_(?![_\s])", end: Some(r"_"), terminator_end: r"_", contains: &[27, 28, 3, 17, 18, 19, 20, 21], relevance: 0.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"quote"), begin: r"^>\s+", end: Some(r"$"), terminator_end: r"$", contains: &[3, 17, 18, 19, 20, 21, 5, 6, 7, 8], ..MODE },
+    /* 10 */ Mode { scope: Some(r"code"), begin: r"(`{3,})[^`](.|\n)*?\1`*[ ]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { scope: Some(r"code"), begin: r"(~{3,})[^~](.|\n)*?\1~*[ ]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"code"), begin: r"```", end: Some(r"```+[ ]*$"), terminator_end: r"```+[ ]*$", ..MODE },
+    /* 13 */ Mode { scope: Some(r"code"), begin: r"~~~", end: Some(r"~~~+[ ]*$"), terminator_end: r"~~~+[ ]*$", ..MODE },
+    /* 14 */ Mode { scope: Some(r"code"), begin: r"`.+?`", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 15 */ Mode { scope: Some(r"code"), begin: r"(?=^( {4}|\t))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[29], relevance: 0.0, ..MODE },
+    /* 16 */ Mode { begin: r"^[-\*]{3,}", end: Some(r"$"), terminator_end: r"$", ..MODE },
+    /* 17 */ Mode { begin: r"\[.+?\]\[.*?\]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[30, 31, 32, 33], relevance: 0.0, return_begin: true, ..MODE },
+    /* 18 */ Mode { begin: r"\[.+?\]\(((data|javascript|mailto):|(?:http|ftp)s?:\/\/).*?\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[30, 31, 32, 33], relevance: 2.0, return_begin: true, ..MODE },
+    /* 19 */ Mode { begin: r"\[.+?\]\([A-Za-z][A-Za-z0-9+.-]*:\/\/.*?\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[30, 31, 32, 33], relevance: 2.0, return_begin: true, ..MODE },
+    /* 20 */ Mode { begin: r"\[.+?\]\([./?&#].*?\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[30, 31, 32, 33], return_begin: true, ..MODE },
+    /* 21 */ Mode { begin: r"\[.*?\]\(.*?\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[30, 31, 32, 33], relevance: 0.0, return_begin: true, ..MODE },
+    /* 22 */ Mode { begin: r"^\[[^\n]+\]:", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[34, 35], return_begin: true, ..MODE },
+    /* 23 */ Mode { begin: r"^[=-]*$", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 24 */ Mode { begin: r"^", end: Some(r"\n"), terminator_end: r"\n", contains: &[3, 17, 18, 19, 20, 21, 5, 6, 7, 8], ..MODE },
+    /* 25 */ Mode { scope: Some(r"emphasis"), begin: r"\*(?![*\s])", end: Some(r"\*"), terminator_end: r"\*", contains: &[3, 17, 18, 19, 20, 21], ..MODE },
+    /* 26 */ Mode { scope: Some(r"emphasis"), begin: r"_(?![_\s])", end: Some(r"_"), terminator_end: r"_", contains: &[3, 17, 18, 19, 20, 21], relevance: 0.0, ..MODE },
+    /* 27 */ Mode { scope: Some(r"strong"), begin: r"_{2}(?!\s)", end: Some(r"_{2}"), terminator_end: r"_{2}", contains: &[3, 17, 18, 19, 20, 21], ..MODE },
+    /* 28 */ Mode { scope: Some(r"strong"), begin: r"\*{2}(?!\s)", end: Some(r"\*{2}"), terminator_end: r"\*{2}", contains: &[3, 17, 18, 19, 20, 21], ..MODE },
+    /* 29 */ Mode { begin: r"^( {4}|\t)", end: Some(r"(\n)$"), terminator_end: r"(\n)$", ..MODE },
+    /* 30 */ Mode { begin: r"\[(?=\])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 31 */ Mode { scope: Some(r"string"), begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", relevance: 0.0, exclude_begin: true, return_end: true, ..MODE },
+    /* 32 */ Mode { scope: Some(r"link"), begin: r"\]\(", end: Some(r"\)"), terminator_end: r"\)", relevance: 0.0, exclude_begin: true, exclude_end: true, ..MODE },
+    /* 33 */ Mode { scope: Some(r"symbol"), begin: r"\]\[", end: Some(r"\]"), terminator_end: r"\]", relevance: 0.0, exclude_begin: true, exclude_end: true, ..MODE },
+    /* 34 */ Mode { scope: Some(r"symbol"), begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", exclude_begin: true, exclude_end: true, ..MODE },
+    /* 35 */ Mode { scope: Some(r"link"), begin: r":\s*", end: Some(r"$"), terminator_end: r"$", exclude_begin: true, ..MODE },
+];
+
+static OBJECTIVEC: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"</"), keywords: Some(KEYWORDS_46), keyword_pattern: r"[a-zA-Z@][a-zA-Z0-9_]*", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], ..MODE },
+    /* 1 */ Mode { scope: Some(r"built_in"), begin: r"\b(AV|CA|CF|CG|CI|CL|CM|CN|CT|MK|MP|MTK|MTL|NS|SCN|SK|UI|WK|XC)\w+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[11, 12], ..MODE },
+    /* 3 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[13, 14], ..MODE },
+    /* 4 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[15], ..MODE },
+    /* 6 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[16], ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r#"@""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[17], ..MODE },
+    /* 8 */ Mode { scope: Some(r"meta"), begin: r"#\s*[a-z]+\b", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_47), contains: &[18, 19, 20, 21, 22], ..MODE },
+    /* 9 */ Mode { scope: Some(r"class"), begin: r"(@interface|@class|@protocol|@implementation)\b", end: Some(r"(\{|$)"), terminator_end: r"(\{|$)", keywords: Some(KEYWORDS_48), keyword_pattern: r"[a-zA-Z@][a-zA-Z0-9_]*", contains: &[23], exclude_end: true, ..MODE },
+    /* 10 */ Mode { begin: r"\.[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 12 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 13 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 14 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 15 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { begin: r"\\\n", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[24], ..MODE },
+    /* 20 */ Mode { scope: Some(r"string"), begin: r"<.*?>", end: Some(r"$"), terminator_end: r"$", illegal: Some(r"\n"), ..MODE },
+    /* 21 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[25, 26], ..MODE },
+    /* 22 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[27, 28], ..MODE },
+    /* 23 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 24 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 25 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 26 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 27 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 28 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static PERL: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_49), keyword_pattern: r"[\w.]+", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22], ..MODE },
+    /* 1 */ Mode { begin: r"\$\d", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 2 */ Mode { begin: r"[$%@](\^\w\b|#\w+(::\w+)*|\{\w+\}|\w+(::\w*)*)(?![A-Za-z])(?![@$%])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 3 */ Mode { begin: r"[$%@][^\s\w{]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 4 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[23, 24], ..MODE },
+    /* 5 */ Mode { scope: Some(r"comment"), begin: r"^=\w", end: Some(r"=cut"), terminator_end: r"=cut", contains: &[25, 26], ends_with_parent: true, ..MODE },
+    /* 6 */ Mode { begin: r"->\{", end: Some(r"\}"), terminator_end: r"\}", contains: &[1, 2, 3, 27, 28, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22], ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r"q[qwxr]?\s*\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[29, 30, 1, 2, 3], relevance: 5.0, ..MODE },
+    /* 8 */ Mode { scope: Some(r"string"), begin: r"q[qwxr]?\s*\[", end: Some(r"\]"), terminator_end: r"\]", contains: &[31, 30, 1, 2, 3], relevance: 5.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"string"), begin: r"q[qwxr]?\s*\{", end: Some(r"\}"), terminator_end: r"\}", contains: &[32, 30, 1, 2, 3], relevance: 5.0, ..MODE },
+    /* 10 */ Mode { scope: Some(r"string"), begin: r"q[qwxr]?\s*\|", end: Some(r"\|"), terminator_end: r"\|", contains: &[33, 30, 1, 2, 3], relevance: 5.0, ..MODE },
+    /* 11 */ Mode { scope: Some(r"string"), begin: r"q[qwxr]?\s*<", end: Some(r">"), terminator_end: r">", contains: &[34, 30, 1, 2, 3], relevance: 5.0, ..MODE },
+    /* 12 */ Mode { scope: Some(r"string"), begin: r"qw\s+q", end: Some(r"q"), terminator_end: r"q", contains: &[35, 30, 1, 2, 3], relevance: 5.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[36], ..MODE },
+    /* 14 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[37, 30, 1, 2, 3], ..MODE },
+    /* 15 */ Mode { scope: Some(r"string"), begin: r"`", end: Some(r"`"), terminator_end: r"`", contains: &[38], ..MODE },
+    /* 16 */ Mode { scope: Some(r"string"), begin: r"\{\w+\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[39, 30, 1, 2, 3], relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"string"), begin: r"-?\w+\s*=>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[40, 30, 1, 2, 3], relevance: 0.0, ..MODE },
+    /* 18 */ Mode { scope: Some(r"number"), begin: r"(\b0[0-7_]+)|(\b0x[0-9a-fA-F_]+)|(\b[1-9][0-9_]*(\.[0-9_]+)?)|[0_]\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { begin: r"(\/\/|!|!=|!==|%|%=|&|&&|&=|\*|\*=|\+|\+=|,|-|-=|/=|/|:|;|<<|<<=|<=|<|===|==|=|>>>=|>>=|>=|>>>|>>|>|\?|\[|\{|\(|\^|\^=|\||\|=|\|\||~|\b(split|return|print|reverse|grep)\b)\s*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_50), contains: &[41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51], relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"function"), begin: r"\b(sub)(?!\.)(?=\b|\s)", end: Some(r"(\s*\(.*?\))?[;{]"), terminator_end: r"(\s*\(.*?\))?[;{]", keywords: Some(KEYWORDS_51), contains: &[52], relevance: 5.0, exclude_end: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 21 */ Mode { begin: r"-\w\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 22 */ Mode { begin: r"^__DATA__$", end: Some(r"^__END__$"), terminator_end: r"^__END__$", contains: &[53], sub_language: Some(SubLanguage::One(r"mojolicious")), ..MODE },
+    /* 23 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 24 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 25 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 26 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 27 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[54, 55], ..MODE },
+    /* 28 */ Mode { scope: Some(r"comment"), begin: r"^=\w", end: Some(r"=cut"), terminator_end: r"=cut|\}", contains: &[25, 26], ends_with_parent: true, ..MODE },
+    /* 29 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 30 */ Mode { scope: Some(r"subst"), begin: r"[$@]\{", end: Some(r"\}"), terminator_end: r"\}", keywords: Some(KEYWORDS_49), keyword_pattern: r"[\w.]+", contains: &[1, 2, 3, 56, 57, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22], ..MODE },
+    /* 31 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 33 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 34 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 35 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 36 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 37 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 38 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 39 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 40 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 41 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[58, 59], ..MODE },
+    /* 42 */ Mode { scope: Some(r"regexp"), begin: r#"(?:s|tr|y)(!|\/|\||\?|'|"|#)(?:\\.|[^\\\/])*?\1(?:\\.|[^\\\/])*?\1[dualxmsipngr]{0,12}"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 2.0, ..MODE },
+    /* 43 */ Mode { scope: Some(r"regexp"), begin: r"(?:s|tr|y)\((?:\\.|[^\\\/])*?\)\((?:\\.|[^\\\/])*?\)[dualxmsipngr]{0,12}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 2.0, ..MODE },
+    /* 44 */ Mode { scope: Some(r"regexp"), begin: r"(?:s|tr|y)\[(?:\\.|[^\\\/])*?\]\[(?:\\.|[^\\\/])*?\][dualxmsipngr]{0,12}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 2.0, ..MODE },
+    /* 45 */ Mode { scope: Some(r"regexp"), begin: r"(?:s|tr|y)\{(?:\\.|[^\\\/])*?\}\{(?:\\.|[^\\\/])*?\}[dualxmsipngr]{0,12}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 2.0, ..MODE },
+    /* 46 */ Mode { scope: Some(r"regexp"), begin: r"(m|qr)\/\/", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 47 */ Mode { scope: Some(r"regexp"), begin: r"(?:(?:m|qr)?)\/(?:\\.|[^\\\/])*?\/[dualxmsipngr]{0,12}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 48 */ Mode { scope: Some(r"regexp"), begin: r#"(?:m|qr)(!|\/|\||\?|'|"|#)(?:\\.|[^\\\/])*?\1[dualxmsipngr]{0,12}"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 49 */ Mode { scope: Some(r"regexp"), begin: r"(?:m|qr)\((?:\\.|[^\\\/])*?\)[dualxmsipngr]{0,12}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 50 */ Mode { scope: Some(r"regexp"), begin: r"(?:m|qr)\[(?:\\.|[^\\\/])*?\][dualxmsipngr]{0,12}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 51 */ Mode { scope: Some(r"regexp"), begin: r"(?:m|qr)\{(?:\\.|[^\\\/])*?\}[dualxmsipngr]{0,12}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 52 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 53 */ Mode { scope: Some(r"comment"), begin: r"^@@.*", end: Some(r"$"), terminator_end: r"$", ..MODE },
+    /* 54 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 55 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 56 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[60, 61], ..MODE },
+    /* 57 */ Mode { scope: Some(r"comment"), begin: r"^=\w", end: Some(r"=cut"), terminator_end: r"=cut|\}", contains: &[25, 26], ends_with_parent: true, ..MODE },
+    /* 58 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 59 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 60 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 61 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static PHP: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_52), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 1 */ Mode { begin_scope: Some(Scopes::Wrap(r"meta")), end_scope: Some(Scopes::Wrap(r"meta")), begin: r"#\[\s*(\\?[A-Z][a-z0-9_\x7f-\xff]+|\\?[A-Z]+(?=[A-Z][a-z0-9_\x7f-\xff])){1,}(?![A-Za-z0-9])(?![$])", end: Some(r"]"), terminator_end: r"]", keywords: Some(KEYWORDS_53), contains: &[32, 33, 13, 14, 15, 16, 17, 34, 25, 26, 27, 28, 29, 30, 31, 19, 35], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[36, 37], ..MODE },
+    /* 3 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[38, 39], ..MODE },
+    /* 4 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[40, 41, 42], ..MODE },
+    /* 5 */ Mode { begin: r"__halt_compiler\(\);", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_54), starts: Some(43), ..MODE },
+    /* 6 */ Mode { scope: Some(r"meta"), begin: r"<\?php", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 10.0, ..MODE },
+    /* 7 */ Mode { scope: Some(r"meta"), begin: r"<\?=", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 8 */ Mode { scope: Some(r"meta"), begin: r"<\?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.1, ..MODE },
+    /* 9 */ Mode { scope: Some(r"meta"), begin: r"\?>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { scope: Some(r"variable.language"), begin: r"\$this\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { scope: Some(r"variable"), begin: r"\$+[a-zA-Z_\x7f-\xff][a-zA-Z0-9_\x7f-\xff]*(?![A-Za-z0-9])(?![$])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, None), (3, Some(r"title.function.invoke")), (4, None), (5, None)])), begin: "(\\b)((?!fn\\b|function\\b|__CLASS__\\b|__DIR__\\b|__FILE__\\b|__FUNCTION__\\b|__COMPILER_HALT_OFFSET__\\b|__LINE__\\b|__METHOD__\\b|__NAMESPACE__\\b|__TRAIT__\\b|die\\b|echo\\b|exit\\b|include\\b|include_once\\b|print\\b|require\\b|require_once\\b|array\\b|abstract\\b|and\\b|as\\b|binary\\b|bool\\b|boolean\\b|break\\b|callable\\b|case\\b|catch\\b|class\\b|clone\\b|const\\b|continue\\b|declare\\b|default\\b|do\\b|double\\b|else\\b|elseif\\b|empty\\b|enddeclare\\b|endfor\\b|endforeach\\b|endif\\b|endswitch\\b|endwhile\\b|enum\\b|eval\\b|extends\\b|final\\b|finally\\b|float\\b|for\\b|foreach\\b|from\\b|global\\b|goto\\b|if\\b|implements\\b|instanceof\\b|insteadof\\b|int\\b|integer\\b|interface\\b|isset\\b|iterable\\b|list\\b|match\\b|mixed\\b|new\\b|never\\b|object\\b|or\\b|private\\b|protected\\b|public\\b|readonly\\b|real\\b|return\\b|string\\b|switch\\b|throw\\b|trait\\b|try\\b|unset\\b|use\\b|var\\b|void\\b|while\\b|xor\\b|yield|Error\\b|AppendIterator\\b|ArgumentCountError\\b|ArithmeticError\\b|ArrayIterator\\b|ArrayObject\\b|AssertionError\\b|BadFunctionCallException\\b|BadMethodCallException\\b|CachingIterator\\b|CallbackFilterIterator\\b|CompileError\\b|Countable\\b|DirectoryIterator\\b|DivisionByZeroError\\b|DomainException\\b|EmptyIterator\\b|ErrorException\\b|Exception\\b|FilesystemIterator\\b|FilterIterator\\b|GlobIterator\\b|InfiniteIterator\\b|InvalidArgumentException\\b|IteratorIterator\\b|LengthException\\b|LimitIterator\\b|LogicException\\b|MultipleIterator\\b|NoRewindIterator\\b|OutOfBoundsException\\b|OutOfRangeException\\b|OuterIterator\\b|OverflowException\\b|ParentIterator\\b|ParseError\\b|RangeException\\b|RecursiveArrayIterator\\b|RecursiveCachingIterator\\b|RecursiveCallbackFilterIterator\\b|RecursiveDirectoryIterator\\b|RecursiveFilterIterator\\b|RecursiveIterator\\b|RecursiveIteratorIterator\\b|RecursiveRegexIterator\\b|RecursiveTreeIterator\\b|RegexIterator\\b|RuntimeException\\b|SeekableIterator\\b|SplDoublyLinkedList\\b|SplFileInfo\\b|SplFileObject\\b|SplFixedArray\\b|SplHeap\\b|SplMaxHeap\\b|SplMinHeap\\b|SplObjectStorage\\b|SplObserver\\b|SplPriorityQueue\\b|SplQueue\\b|SplStack\\b|SplSubject\\b|SplTempFileObject\\b|TypeError\\b|UnderflowException\\b|UnexpectedValueException\\b|UnhandledMatchError\\b|ArrayAccess\\b|BackedEnum\\b|Closure\\b|Fiber\\b|Generator\\b|Iterator\\b|IteratorAggregate\\b|Serializable\\b|Stringable\\b|Throwable\\b|Traversable\\b|UnitEnum\\b|WeakReference\\b|WeakMap\\b|Directory\\b|__PHP_Incomplete_Class\\b|parent\\b|php_user_filter\\b|self\\b|static\\b|stdClass\\b))([a-zA-Z_\\x7f-\\xff][a-zA-Z0-9_\\x7f-\\xff]*(?![A-Za-z0-9])(?![$]))([ \u{9}\u{a}]*)((?=(?=\\()))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[44], relevance: 0.0, ..MODE },
+    /* 13 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, Some(r"variable.constant"))])), begin: r"(::(?=(?!class\b)))([a-zA-Z_\x7f-\xff][a-zA-Z0-9_\x7f-\xff]*(?![A-Za-z0-9])(?![$])\b(?!\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 14 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, Some(r"variable.language"))])), begin: r"(::)(class)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 15 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"title.class")), (3, None), (4, Some(r"variable.constant"))])), begin: r"((\\?[A-Z][a-z0-9_\x7f-\xff]+|\\?[A-Z]+(?=[A-Z][a-z0-9_\x7f-\xff])){1,}(?![A-Za-z0-9])(?![$]))(::(?=(?!class\b)))([a-zA-Z_\x7f-\xff][a-zA-Z0-9_\x7f-\xff]*(?![A-Za-z0-9])(?![$])\b(?!\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 16 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"title.class")), (3, None)])), begin: r"((\\?[A-Z][a-z0-9_\x7f-\xff]+|\\?[A-Z]+(?=[A-Z][a-z0-9_\x7f-\xff])){1,}(?![A-Za-z0-9])(?![$]))(::(?=(?!class\b)))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 17 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"title.class")), (3, None), (4, Some(r"variable.language"))])), begin: r"((\\?[A-Z][a-z0-9_\x7f-\xff]+|\\?[A-Z]+(?=[A-Z][a-z0-9_\x7f-\xff])){1,}(?![A-Za-z0-9])(?![$]))(::)(class)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 18 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"variable.constant"))])), begin: r"(const)(\s)([a-zA-Z_\x7f-\xff][a-zA-Z0-9_\x7f-\xff]*(?![A-Za-z0-9])(?![$]))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 19 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, None), (4, Some(r"title.class"))])), begin: "(new)([ \u{9}\u{a}]+)((?!Error\\b|AppendIterator\\b|ArgumentCountError\\b|ArithmeticError\\b|ArrayIterator\\b|ArrayObject\\b|AssertionError\\b|BadFunctionCallException\\b|BadMethodCallException\\b|CachingIterator\\b|CallbackFilterIterator\\b|CompileError\\b|Countable\\b|DirectoryIterator\\b|DivisionByZeroError\\b|DomainException\\b|EmptyIterator\\b|ErrorException\\b|Exception\\b|FilesystemIterator\\b|FilterIterator\\b|GlobIterator\\b|InfiniteIterator\\b|InvalidArgumentException\\b|IteratorIterator\\b|LengthException\\b|LimitIterator\\b|LogicException\\b|MultipleIterator\\b|NoRewindIterator\\b|OutOfBoundsException\\b|OutOfRangeException\\b|OuterIterator\\b|OverflowException\\b|ParentIterator\\b|ParseError\\b|RangeException\\b|RecursiveArrayIterator\\b|RecursiveCachingIterator\\b|RecursiveCallbackFilterIterator\\b|RecursiveDirectoryIterator\\b|RecursiveFilterIterator\\b|RecursiveIterator\\b|RecursiveIteratorIterator\\b|RecursiveRegexIterator\\b|RecursiveTreeIterator\\b|RegexIterator\\b|RuntimeException\\b|SeekableIterator\\b|SplDoublyLinkedList\\b|SplFileInfo\\b|SplFileObject\\b|SplFixedArray\\b|SplHeap\\b|SplMaxHeap\\b|SplMinHeap\\b|SplObjectStorage\\b|SplObserver\\b|SplPriorityQueue\\b|SplQueue\\b|SplStack\\b|SplSubject\\b|SplTempFileObject\\b|TypeError\\b|UnderflowException\\b|UnexpectedValueException\\b|UnhandledMatchError\\b|ArrayAccess\\b|BackedEnum\\b|Closure\\b|Fiber\\b|Generator\\b|Iterator\\b|IteratorAggregate\\b|Serializable\\b|Stringable\\b|Throwable\\b|Traversable\\b|UnitEnum\\b|WeakReference\\b|WeakMap\\b|Directory\\b|__PHP_Incomplete_Class\\b|parent\\b|php_user_filter\\b|self\\b|static\\b|stdClass\\b))((\\\\?[A-Z][a-z0-9_\\x7f-\\xff]+|\\\\?[A-Z]+(?=[A-Z][a-z0-9_\\x7f-\\xff])){1,}(?![A-Za-z0-9])(?![$]))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 20 */ Mode { scope: Some(r"function"), begin: r"\b(fn|function)(?!\.)(?=\b|\s)", end: Some(r"[;{]"), terminator_end: r"[;{]", illegal: Some(r"[$%\[]"), keywords: Some(KEYWORDS_55), contains: &[45, 46, 47, 48], relevance: 0.0, exclude_end: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 21 */ Mode { scope: Some(r"class"), begin: r"\b(enum)(?!\.)(?=\b|\s)", end: Some(r"\{"), terminator_end: r"\{", illegal: Some(r#"[($"]"#), keywords: Some(KEYWORDS_56), contains: &[49, 50], relevance: 0.0, exclude_end: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 22 */ Mode { scope: Some(r"class"), begin: r"\b(class|interface|trait)(?!\.)(?=\b|\s)", end: Some(r"\{"), terminator_end: r"\{", illegal: Some(r#"[:($"]"#), keywords: Some(KEYWORDS_34), contains: &[49, 51], relevance: 0.0, exclude_end: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 23 */ Mode { begin: r"\b(namespace)(?!\.)(?=\b|\s)", end: Some(r";"), terminator_end: r";", illegal: Some(r"[.']"), keywords: Some(KEYWORDS_10), contains: &[52], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 24 */ Mode { begin: r"\b(use)(?!\.)(?=\b|\s)", end: Some(r";"), terminator_end: r";", keywords: Some(KEYWORDS_57), contains: &[53, 54], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 25 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[55, 56, 57], ..MODE },
+    /* 26 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[58], ..MODE },
+    /* 27 */ Mode { scope: Some(r"string"), begin: r"<<<[ \t]*(\w+)\n", end: Some(r"[ \t]*(\w+)\b"), terminator_end: r"[ \t]*(\w+)\b", contains: &[59, 56, 57], on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 28 */ Mode { scope: Some(r"number"), begin: r"\b0[bB][01]+(?:_[01]+)*\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 29 */ Mode { scope: Some(r"number"), begin: r"\b0[oO][0-7]+(?:_[0-7]+)*\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 30 */ Mode { scope: Some(r"number"), begin: r"\b0[xX][\da-fA-F]+(?:_[\da-fA-F]+)*\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 31 */ Mode { scope: Some(r"number"), begin: r"(?:\b\d+(?:_\d+)*(\.(?:\d+(?:_\d+)*))?|\B\.\d+)(?:[eE][+-]?\d+)?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { begin: r"\[", end: Some(r"]"), terminator_end: r"]", keywords: Some(KEYWORDS_53), contains: &[32, 33, 13, 14, 15, 16, 17, 60, 25, 26, 27, 28, 29, 30, 31, 19], ..MODE },
+    /* 33 */ Mode { scope: Some(r"attr"), begin: r"[a-zA-Z_\x7f-\xff][a-zA-Z0-9_\x7f-\xff]*(?![A-Za-z0-9])(?![$])(?=:)(?=(?!::))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 34 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[61, 62], ..MODE },
+    /* 35 */ Mode { scope: Some(r"meta"), begin: r"(\\?[A-Z][a-z0-9_\x7f-\xff]+|\\?[A-Z]+(?=[A-Z][a-z0-9_\x7f-\xff])){1,}(?![A-Za-z0-9])(?![$])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 36 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 37 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 38 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 39 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 40 */ Mode { scope: Some(r"doctag"), begin: r"@[A-Za-z]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 41 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 42 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 43 */ Mode { scope: Some(r"comment"), begin: r"\B|\b", end: Some(r"\b\B"), terminator_end: r"\b\B", contains: &[63], ..MODE },
+    /* 44 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_52), contains: &[33, 11, 13, 14, 15, 16, 17, 64, 25, 26, 27, 28, 29, 30, 31, 19, 12], relevance: 0.0, ..MODE },
+    /* 45 */ Mode { begin: r"\b(use)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_57), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 46 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 47 */ Mode { begin: r"=>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ends_parent: true, ..MODE },
+    /* 48 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_52), contains: &[48, 11, 13, 14, 15, 16, 17, 65, 25, 26, 27, 28, 29, 30, 31], exclude_begin: true, exclude_end: true, ..MODE },
+    /* 49 */ Mode { begin: r"\b(extends|implements)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_58), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 50 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 51 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 52 */ Mode { scope: Some(r"title.class"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 53 */ Mode { scope: Some(r"keyword"), begin: r"\b(as|const|function)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 54 */ Mode { scope: Some(r"title"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 55 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 56 */ Mode { scope: Some(r"subst"), begin: r"\$\w+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 57 */ Mode { scope: Some(r"subst"), begin: r"\{\$", end: Some(r"\}"), terminator_end: r"\}", ..MODE },
+    /* 58 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 59 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 60 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[66, 67], ..MODE },
+    /* 61 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 62 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 63 */ Mode { scope: Some(r"meta"), begin: r"\?>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ends_parent: true, ..MODE },
+    /* 64 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[68, 69], ..MODE },
+    /* 65 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[70, 71], ..MODE },
+    /* 66 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 67 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 68 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 69 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 70 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 71 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static PHP_TEMPLATE: &[Mode] = &[
+    /* 0 */ Mode { contains: &[1], sub_language: Some(SubLanguage::One(r"xml")), ..MODE },
+    /* 1 */ Mode { begin: r"<\?(php|=)?", end: Some(r"\?>"), terminator_end: r"\?>", contains: &[2, 3, 4, 5, 6], sub_language: Some(SubLanguage::One(r"php")), ..MODE },
+    /* 2 */ Mode { begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", skip: true, ..MODE },
+    /* 3 */ Mode { begin: r#"b""#, end: Some(r#"""#), terminator_end: r#"""#, skip: true, ..MODE },
+    /* 4 */ Mode { begin: r"b'", end: Some(r"'"), terminator_end: r"'", skip: true, ..MODE },
+    /* 5 */ Mode { begin: r"'", end: Some(r"'"), terminator_end: r"'", skip: true, ..MODE },
+    /* 6 */ Mode { begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, skip: true, ..MODE },
+];
+
+static PLAINTEXT: &[Mode] = &[
+    /* 0 */ Mode { ..MODE },
+];
+
+static PYTHON: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"(<\/|->|\?)|=>"), keywords: Some(KEYWORDS_59), keyword_pattern: r"[A-Za-z]\w+|__\w+__", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta"), begin: r"^(>>>|\.\.\.) ", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 2 */ Mode { scope: Some(r"number"), begin: r"(\b([0-9](_?[0-9])*)|((\b([0-9](_?[0-9])*))?\.([0-9](_?[0-9])*)|\b([0-9](_?[0-9])*)\.))[eE][+-]?([0-9](_?[0-9])*)[jJ]?(?=\b|and|as|assert|async|await|break|case|class|continue|def|del|elif|else|except|finally|for|from|global|if|import|in|is|lambda|match|nonlocal|10|not|or|pass|raise|return|try|while|with|yield)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 3 */ Mode { scope: Some(r"number"), begin: r"((\b([0-9](_?[0-9])*))?\.([0-9](_?[0-9])*)|\b([0-9](_?[0-9])*)\.)[jJ]?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 4 */ Mode { scope: Some(r"number"), begin: r"\b([1-9](_?[0-9])*|0+(_?0)*)[lLjJ]?(?=\b|and|as|assert|async|await|break|case|class|continue|def|del|elif|else|except|finally|for|from|global|if|import|in|is|lambda|match|nonlocal|10|not|or|pass|raise|return|try|while|with|yield)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 5 */ Mode { scope: Some(r"number"), begin: r"\b0[bB](_?[01])+[lL]?(?=\b|and|as|assert|async|await|break|case|class|continue|def|del|elif|else|except|finally|for|from|global|if|import|in|is|lambda|match|nonlocal|10|not|or|pass|raise|return|try|while|with|yield)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 6 */ Mode { scope: Some(r"number"), begin: r"\b0[oO](_?[0-7])+[lL]?(?=\b|and|as|assert|async|await|break|case|class|continue|def|del|elif|else|except|finally|for|from|global|if|import|in|is|lambda|match|nonlocal|10|not|or|pass|raise|return|try|while|with|yield)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 7 */ Mode { scope: Some(r"number"), begin: r"\b0[xX](_?[0-9a-fA-F])+[lL]?(?=\b|and|as|assert|async|await|break|case|class|continue|def|del|elif|else|except|finally|for|from|global|if|import|in|is|lambda|match|nonlocal|10|not|or|pass|raise|return|try|while|with|yield)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 8 */ Mode { scope: Some(r"number"), begin: r"\b([0-9](_?[0-9])*)[jJ](?=\b|and|as|assert|async|await|break|case|class|continue|def|del|elif|else|except|finally|for|from|global|if|import|in|is|lambda|match|nonlocal|10|not|or|pass|raise|return|try|while|with|yield)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 9 */ Mode { begin: r"\bself\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { begin: r"\b(if)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_60), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 11 */ Mode { scope: Some(r"string"), begin: r"([uU]|[bB]|[rR]|[bB][rR]|[rR][bB])?'''", end: Some(r"'''"), terminator_end: r"'''", contains: &[29, 1], relevance: 10.0, ..MODE },
+    /* 12 */ Mode { scope: Some(r"string"), begin: r#"([uU]|[bB]|[rR]|[bB][rR]|[rR][bB])?""""#, end: Some(r#"""""#), terminator_end: r#"""""#, contains: &[30, 1], relevance: 10.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"string"), begin: r"([fF][rR]|[rR][fF]|[fF])'''", end: Some(r"'''"), terminator_end: r"'''", contains: &[31, 1, 32, 33], ..MODE },
+    /* 14 */ Mode { scope: Some(r"string"), begin: r#"([fF][rR]|[rR][fF]|[fF])""""#, end: Some(r#"""""#), terminator_end: r#"""""#, contains: &[34, 1, 32, 33], ..MODE },
+    /* 15 */ Mode { scope: Some(r"string"), begin: r"([uU]|[rR])'", end: Some(r"'"), terminator_end: r"'", contains: &[35], relevance: 10.0, ..MODE },
+    /* 16 */ Mode { scope: Some(r"string"), begin: r#"([uU]|[rR])""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[36], relevance: 10.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"string"), begin: r"([bB]|[bB][rR]|[rR][bB])'", end: Some(r"'"), terminator_end: r"'", contains: &[37], ..MODE },
+    /* 18 */ Mode { scope: Some(r"string"), begin: r#"([bB]|[bB][rR]|[rR][bB])""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[38], ..MODE },
+    /* 19 */ Mode { scope: Some(r"string"), begin: r"([fF][rR]|[rR][fF]|[fF])'", end: Some(r"'"), terminator_end: r"'", contains: &[39, 32, 33], ..MODE },
+    /* 20 */ Mode { scope: Some(r"string"), begin: r#"([fF][rR]|[rR][fF]|[fF])""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[40, 32, 33], ..MODE },
+    /* 21 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[41], ..MODE },
+    /* 22 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[42], ..MODE },
+    /* 23 */ Mode { scope: Some(r"comment"), begin: r"(?=# type:)", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_59), keyword_pattern: r"[A-Za-z]\w+|__\w+__", contains: &[43, 44], ..MODE },
+    /* 24 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[45, 46], ..MODE },
+    /* 25 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function"))])), begin: r"(\bdef)(\s+)([\p{XID_Start}_]\p{XID_Continue}*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[47, 48], ..MODE },
+    /* 26 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class")), (4, None), (5, None), (6, Some(r"title.class.inherited")), (7, None)])), begin: r"(\bclass)(\s+)([\p{XID_Start}_]\p{XID_Continue}*)(\s*)(\(\s*)([\p{XID_Start}_]\p{XID_Continue}*)(\s*\))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 27 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class"))])), begin: r"(\bclass)(\s+)([\p{XID_Start}_]\p{XID_Continue}*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 28 */ Mode { scope: Some(r"meta"), begin: r"^[\t ]*@", end: Some(r"(?=#)|$"), terminator_end: r"(?=#)|$", contains: &[2, 3, 4, 5, 6, 7, 8, 47, 48, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22], ..MODE },
+    /* 29 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 30 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 31 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { begin: r"\{\{", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 33 */ Mode { scope: Some(r"subst"), begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", illegal: Some(r"#"), keywords: Some(KEYWORDS_59), keyword_pattern: r"[A-Za-z]\w+|__\w+__", contains: &[11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 2, 3, 4, 5, 6, 7, 8, 1], ..MODE },
+    /* 34 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 35 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 36 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 37 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 38 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 39 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 40 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 41 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 42 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 43 */ Mode { begin: r"# type:", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 44 */ Mode { begin: r"#", end: Some(r"\b\B"), terminator_end: r"\b\B|$", ends_with_parent: true, ..MODE },
+    /* 45 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 46 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 47 */ Mode { scope: Some(r""), begin: r"\(\s*\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", skip: true, ..MODE },
+    /* 48 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_59), keyword_pattern: r"[A-Za-z]\w+|__\w+__", contains: &[48, 1, 2, 3, 4, 5, 6, 7, 8, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 49], exclude_begin: true, exclude_end: true, ..MODE },
+    /* 49 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[50, 51], ..MODE },
+    /* 50 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 51 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static PYTHON_REPL: &[Mode] = &[
+    /* 0 */ Mode { contains: &[1, 2], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta.prompt"), begin: r"^>>>(?=[ ]|$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(3), ..MODE },
+    /* 2 */ Mode { scope: Some(r"meta.prompt"), begin: r"^\.\.\.(?=[ ]|$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(3), ..MODE },
+    /* 3 */ Mode { begin: r"\B|\b", end: Some(r" |$"), terminator_end: r" |$", starts: Some(4), ..MODE },
+    /* 4 */ Mode { begin: r"\B|\b", end: Some(r"$"), terminator_end: r"$", sub_language: Some(SubLanguage::One(r"python")), ..MODE },
+];
+
+static R: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_61), keyword_pattern: r"(?:(?:[a-zA-Z]|\.[._a-zA-Z])[._a-zA-Z0-9]*)|\.(?!\d)", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"#'", end: Some(r"$"), terminator_end: r"$", contains: &[20, 21, 22, 23, 24, 25], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[26, 27], ..MODE },
+    /* 3 */ Mode { scope: Some(r"string"), begin: r#"[rR]"(-*)\("#, end: Some(r#"\)(-*)""#), terminator_end: r#"\)(-*)""#, contains: &[28], on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 4 */ Mode { scope: Some(r"string"), begin: r#"[rR]"(-*)\{"#, end: Some(r#"\}(-*)""#), terminator_end: r#"\}(-*)""#, contains: &[29], on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r#"[rR]"(-*)\["#, end: Some(r#"\](-*)""#), terminator_end: r#"\](-*)""#, contains: &[30], on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 6 */ Mode { scope: Some(r"string"), begin: r"[rR]'(-*)\(", end: Some(r"\)(-*)'"), terminator_end: r"\)(-*)'", contains: &[31], on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r"[rR]'(-*)\{", end: Some(r"\}(-*)'"), terminator_end: r"\}(-*)'", contains: &[32], on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 8 */ Mode { scope: Some(r"string"), begin: r"[rR]'(-*)\[", end: Some(r"\](-*)'"), terminator_end: r"\](-*)'", contains: &[33], on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 9 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[34], relevance: 0.0, ..MODE },
+    /* 10 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[35], relevance: 0.0, ..MODE },
+    /* 11 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"operator")), (2, Some(r"number"))])), begin: r"([=!<>:]=|\|\||&&|:::?|<-|<<-|->>|->|\|>|[-+*\/?!$&|:<=>@^~]|\*\*)((?:0[xX][0-9a-fA-F]+\.[0-9a-fA-F]*[pP][+-]?\d+i?|0[xX][0-9a-fA-F]+(?:[pP][+-]?\d+)?[Li]?|(?:\d+(?:\.\d*)?|\.\d+)(?:[eE][+-]?\d+)?[Li]?))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 12 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"operator")), (2, Some(r"number"))])), begin: r"(%[^%]*%)((?:0[xX][0-9a-fA-F]+\.[0-9a-fA-F]*[pP][+-]?\d+i?|0[xX][0-9a-fA-F]+(?:[pP][+-]?\d+)?[Li]?|(?:\d+(?:\.\d*)?|\.\d+)(?:[eE][+-]?\d+)?[Li]?))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 13 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"punctuation")), (2, Some(r"number"))])), begin: r"((?:[()]|[{}]|\[\[|[[\]]|\\|,))((?:0[xX][0-9a-fA-F]+\.[0-9a-fA-F]*[pP][+-]?\d+i?|0[xX][0-9a-fA-F]+(?:[pP][+-]?\d+)?[Li]?|(?:\d+(?:\.\d*)?|\.\d+)(?:[eE][+-]?\d+)?[Li]?))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 14 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, Some(r"number"))])), begin: r"([^a-zA-Z0-9._]|^)((?:0[xX][0-9a-fA-F]+\.[0-9a-fA-F]*[pP][+-]?\d+i?|0[xX][0-9a-fA-F]+(?:[pP][+-]?\d+)?[Li]?|(?:\d+(?:\.\d*)?|\.\d+)(?:[eE][+-]?\d+)?[Li]?))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 15 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, None), (3, Some(r"operator")), (4, None)])), begin: r"((?:(?:[a-zA-Z]|\.[._a-zA-Z])[._a-zA-Z0-9]*)|\.(?!\d))(\s+)(<-)(\s+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 16 */ Mode { scope: Some(r"operator"), begin: r"[=!<>:]=|\|\||&&|:::?|<-|<<-|->>|->|\|>|[-+*\/?!$&|:<=>@^~]|\*\*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"operator"), begin: r"%[^%]*%", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { scope: Some(r"punctuation"), begin: r"(?:[()]|[{}]|\[\[|[[\]]|\\|,)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { begin: r"`", end: Some(r"`"), terminator_end: r"`", contains: &[36], ..MODE },
+    /* 20 */ Mode { scope: Some(r"doctag"), begin: r"@examples", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(37), ..MODE },
+    /* 21 */ Mode { scope: Some(r"doctag"), begin: r"@param", end: Some(r"$"), terminator_end: r"$", contains: &[38, 39], ..MODE },
+    /* 22 */ Mode { scope: Some(r"doctag"), begin: r"@[a-zA-Z]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 23 */ Mode { scope: Some(r"keyword"), begin: r"\\[a-zA-Z]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 24 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 25 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 26 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 27 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 28 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 29 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 30 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 31 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 33 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 34 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 35 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 36 */ Mode { begin: r"\\.", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 37 */ Mode { begin: r"\B|\b", end: Some(r"(?=(?:\n^#'\s*(?=@[a-zA-Z]+)|\n^(?!#')))"), terminator_end: r"(?=(?:\n^#'\s*(?=@[a-zA-Z]+)|\n^(?!#')))", ends_parent: true, ..MODE },
+    /* 38 */ Mode { scope: Some(r"variable"), begin: r"(?:(?:[a-zA-Z]|\.[._a-zA-Z])[._a-zA-Z0-9]*)|\.(?!\d)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ends_parent: true, ..MODE },
+    /* 39 */ Mode { scope: Some(r"variable"), begin: r"`(?:\\.|[^`\\])+`", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ends_parent: true, ..MODE },
+];
+
+static RUBY: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"\/\*"), keywords: Some(KEYWORDS_62), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 4, 5, 6, 7], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta"), begin: r"^#![ ]*\/.*\bruby\b.*", end: Some(r"$"), terminator_end: r"$", relevance: 0.0, on_begin: Some(Callback::IgnoreUnlessFirst), ..MODE },
+    /* 2 */ Mode { begin: r"^\s*=>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(45), ..MODE },
+    /* 3 */ Mode { scope: Some(r"meta.prompt"), begin: r"^([>?]>|[\w#]+\(\w+\):\d+:\d+[>*]|(\w+-)?\d+\.\d+\.\d+(p\d+)?[^\d][^>]+>)(?=[ ])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(46), ..MODE },
+    /* 4 */ Mode { begin: r"#<", end: Some(r">"), terminator_end: r">", ..MODE },
+    /* 5 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[47, 48, 49], ..MODE },
+    /* 6 */ Mode { scope: Some(r"comment"), begin: r"^=begin", end: Some(r"^=end"), terminator_end: r"^=end", contains: &[47, 50, 51], relevance: 10.0, ..MODE },
+    /* 7 */ Mode { scope: Some(r"comment"), begin: r"^__END__", end: Some(r"\b\B"), terminator_end: r"\b\B", contains: &[52, 53], ..MODE },
+    /* 8 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[54, 55], ..MODE },
+    /* 9 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[56, 55], ..MODE },
+    /* 10 */ Mode { scope: Some(r"string"), begin: r"`", end: Some(r"`"), terminator_end: r"`", contains: &[57, 55], ..MODE },
+    /* 11 */ Mode { scope: Some(r"string"), begin: r"%[qQwWx]?\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[58, 55], ..MODE },
+    /* 12 */ Mode { scope: Some(r"string"), begin: r"%[qQwWx]?\[", end: Some(r"\]"), terminator_end: r"\]", contains: &[59, 55], ..MODE },
+    /* 13 */ Mode { scope: Some(r"string"), begin: r"%[qQwWx]?\{", end: Some(r"\}"), terminator_end: r"\}", contains: &[60, 55], ..MODE },
+    /* 14 */ Mode { scope: Some(r"string"), begin: r"%[qQwWx]?<", end: Some(r">"), terminator_end: r">", contains: &[61, 55], ..MODE },
+    /* 15 */ Mode { scope: Some(r"string"), begin: r"%[qQwWx]?\/", end: Some(r"\/"), terminator_end: r"\/", contains: &[62, 55], ..MODE },
+    /* 16 */ Mode { scope: Some(r"string"), begin: r"%[qQwWx]?%", end: Some(r"%"), terminator_end: r"%", contains: &[63, 55], ..MODE },
+    /* 17 */ Mode { scope: Some(r"string"), begin: r"%[qQwWx]?-", end: Some(r"-"), terminator_end: r"-", contains: &[64, 55], ..MODE },
+    /* 18 */ Mode { scope: Some(r"string"), begin: r"%[qQwWx]?\|", end: Some(r"\|"), terminator_end: r"\|", contains: &[65, 55], ..MODE },
+    /* 19 */ Mode { scope: Some(r"string"), begin: r"\B\?(\\\d{1,3})", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[66, 55], ..MODE },
+    /* 20 */ Mode { scope: Some(r"string"), begin: r"\B\?(\\x[A-Fa-f0-9]{1,2})", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[67, 55], ..MODE },
+    /* 21 */ Mode { scope: Some(r"string"), begin: r"\B\?(\\u\{?[A-Fa-f0-9]{1,6}\}?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[68, 55], ..MODE },
+    /* 22 */ Mode { scope: Some(r"string"), begin: r"\B\?(\\M-\\C-|\\M-\\c|\\c\\M-|\\M-|\\C-\\M-)[\x20-\x7e]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[69, 55], ..MODE },
+    /* 23 */ Mode { scope: Some(r"string"), begin: r"\B\?\\(c|C-)[\x20-\x7e]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[70, 55], ..MODE },
+    /* 24 */ Mode { scope: Some(r"string"), begin: r"\B\?\\?\S", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[71, 55], ..MODE },
+    /* 25 */ Mode { scope: Some(r"string"), begin: r"<<[-~]?'?(?=(\w+)(?=\W)[^\n]*\n(?:[^\n]*\n)*?\s*\1\b)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[72], ..MODE },
+    /* 26 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, Some(r"title.class")), (6, None), (7, Some(r"title.class.inherited"))])), begin: r"(class\s+)((?:\b([A-Z]+[a-z0-9]+)+|\b([A-Z]+[a-z0-9]+)+[A-Z]+)(::\w+)*)(\s+<\s+)((?:\b([A-Z]+[a-z0-9]+)+|\b([A-Z]+[a-z0-9]+)+[A-Z]+)(::\w+)*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_62), ..MODE },
+    /* 27 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (3, Some(r"title.class"))])), begin: r"(\b(class|module)\s+)((?:\b([A-Z]+[a-z0-9]+)+|\b([A-Z]+[a-z0-9]+)+[A-Z]+)(::\w+)*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_62), ..MODE },
+    /* 28 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (3, Some(r"title.class"))])), begin: r"((include|extend)\s+)((?:\b([A-Z]+[a-z0-9]+)+|\b([A-Z]+[a-z0-9]+)+[A-Z]+)(::\w+)*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_62), ..MODE },
+    /* 29 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"title.class")), (5, None)])), begin: r"((?:\b([A-Z]+[a-z0-9]+)+|\b([A-Z]+[a-z0-9]+)+[A-Z]+)(::\w+)*)(\.new[. (])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 30 */ Mode { scope: Some(r"variable.constant"), begin: r"\b[A-Z][A-Z_0-9]+\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 31 */ Mode { scope: Some(r"title.class"), begin: r"(?:\b([A-Z]+[a-z0-9]+)+|\b([A-Z]+[a-z0-9]+)+[A-Z]+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function"))])), begin: r"(def)(\s+)(([a-zA-Z_]\w*[!?=]?|[-+~]@|<<|>>|=~|===?|<=>|[<>]=?|\*\*|[-/+%^&*~`|]|\[\]=?))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[73, 74], ..MODE },
+    /* 33 */ Mode { begin: r"[a-zA-Z]\w*::", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 34 */ Mode { scope: Some(r"symbol"), begin: r"[a-zA-Z_]\w*(!|\?)?:", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 35 */ Mode { scope: Some(r"symbol"), begin: r":(?!\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 75], relevance: 0.0, ..MODE },
+    /* 36 */ Mode { scope: Some(r"number"), begin: r"\b([1-9](_?[0-9])*|0)(\.([0-9](_?[0-9])*))?([eE][+-]?([0-9](_?[0-9])*)|r)?i?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 37 */ Mode { scope: Some(r"number"), begin: r"\b0[dD][0-9](_?[0-9])*r?i?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 38 */ Mode { scope: Some(r"number"), begin: r"\b0[bB][0-1](_?[0-1])*r?i?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 39 */ Mode { scope: Some(r"number"), begin: r"\b0[oO][0-7](_?[0-7])*r?i?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 40 */ Mode { scope: Some(r"number"), begin: r"\b0[xX][0-9a-fA-F](_?[0-9a-fA-F])*r?i?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 41 */ Mode { scope: Some(r"number"), begin: r"\b0(_?[0-7])+r?i?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 42 */ Mode { scope: Some(r"variable"), begin: r"(\$\W)|((\$|@@?)(\w+))(?=[^@$?])(?![A-Za-z])(?![@$?'])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 43 */ Mode { scope: Some(r"params"), begin: r"\|", end: Some(r"\|"), terminator_end: r"\|", keywords: Some(KEYWORDS_62), relevance: 0.0, exclude_begin: true, exclude_end: true, ..MODE },
+    /* 44 */ Mode { begin: r"(!|!=|!==|%|%=|&|&&|&=|\*|\*=|\+|\+=|,|-|-=|/=|/|:|;|<<|<<=|<=|<|===|==|=|>>>=|>>=|>=|>>>|>>|>|\?|\[|\{|\(|\^|\^=|\||\|=|\|\||~|unless)\s*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_63), contains: &[76, 77, 78, 79, 80, 4, 5, 6, 7], relevance: 0.0, ..MODE },
+    /* 45 */ Mode { begin: r"\B|\b", end: Some(r"$"), terminator_end: r"$", contains: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 4, 5, 6, 7], ..MODE },
+    /* 46 */ Mode { begin: r"\B|\b", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_62), contains: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 4, 5, 6, 7], ..MODE },
+    /* 47 */ Mode { scope: Some(r"doctag"), begin: r"@[A-Za-z]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 48 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 49 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 50 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 51 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 52 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 53 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 54 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 55 */ Mode { scope: Some(r"subst"), begin: r"#\{", end: Some(r"\}"), terminator_end: r"\}", keywords: Some(KEYWORDS_62), contains: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 4, 5, 6, 7], ..MODE },
+    /* 56 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 57 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 58 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 59 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 60 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 61 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 62 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 63 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 64 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 65 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 66 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 67 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 68 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 69 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 70 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 71 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 72 */ Mode { begin: r"(\w+)", end: Some(r"(\w+)"), terminator_end: r"(\w+)", contains: &[81, 55], on_begin: Some(Callback::SaveBeginMatch), on_end: Some(Callback::IgnoreUnlessSameAsBegin), ..MODE },
+    /* 73 */ Mode { begin: r"\(\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 4, 5, 6, 7], ..MODE },
+    /* 74 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"(?=\))"), terminator_end: r"(?=\))", keywords: Some(KEYWORDS_62), contains: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 4, 5, 6, 7], exclude_begin: true, ends_parent: true, ..MODE },
+    /* 75 */ Mode { begin: r"([a-zA-Z_]\w*[!?=]?|[-+~]@|<<|>>|=~|===?|<=>|[<>]=?|\*\*|[-/+%^&*~`|]|\[\]=?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 76 */ Mode { scope: Some(r"regexp"), begin: r"/", end: Some(r"\/[a-z]*"), terminator_end: r"/[a-z]*", illegal: Some(r"\n"), contains: &[82, 55], ..MODE },
+    /* 77 */ Mode { scope: Some(r"regexp"), begin: r"%r\{", end: Some(r"\}[a-z]*"), terminator_end: r"\}[a-z]*", illegal: Some(r"\n"), contains: &[83, 55], ..MODE },
+    /* 78 */ Mode { scope: Some(r"regexp"), begin: r"%r\(", end: Some(r"\)[a-z]*"), terminator_end: r"\)[a-z]*", illegal: Some(r"\n"), contains: &[84, 55], ..MODE },
+    /* 79 */ Mode { scope: Some(r"regexp"), begin: r"%r!", end: Some(r"![a-z]*"), terminator_end: r"![a-z]*", illegal: Some(r"\n"), contains: &[85, 55], ..MODE },
+    /* 80 */ Mode { scope: Some(r"regexp"), begin: r"%r\[", end: Some(r"\][a-z]*"), terminator_end: r"\][a-z]*", illegal: Some(r"\n"), contains: &[86, 55], ..MODE },
+    /* 81 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 82 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 83 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 84 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 85 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 86 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static RUST: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"</"), keywords: Some(KEYWORDS_64), keyword_pattern: r"[a-zA-Z]\w*!?", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[20, 21], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[2, 22, 23], ..MODE },
+    /* 3 */ Mode { scope: Some(r"string"), begin: r#"b?""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[24], ..MODE },
+    /* 4 */ Mode { scope: Some(r"string"), begin: r#"b?r(#*)"(.|\n)*?"\1(?!#)"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r"b?'\\?(x\w{2}|u\w{4}|U\w{8}|.)'", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 6 */ Mode { scope: Some(r"symbol"), begin: r"'[a-zA-Z_][a-zA-Z0-9_]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { scope: Some(r"number"), begin: r"\b0b([01_]+)([ui](8|16|32|64|128|size)|f(32|64))?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 8 */ Mode { scope: Some(r"number"), begin: r"\b0o([0-7_]+)([ui](8|16|32|64|128|size)|f(32|64))?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"number"), begin: r"\b0x([A-Fa-f0-9_]+)([ui](8|16|32|64|128|size)|f(32|64))?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 10 */ Mode { scope: Some(r"number"), begin: r"\b(\d[\d_]*(\.[0-9_]+)?([eE][+-]?[0-9_]+)?)([ui](8|16|32|64|128|size)|f(32|64))?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function"))])), begin: r"(fn)(\s+)([a-zA-Z_]\w*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"meta"), begin: r"#!?\[", end: Some(r"\]"), terminator_end: r"\]", contains: &[25], ..MODE },
+    /* 13 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"keyword")), (4, Some(r"variable"))])), begin: r"(let)(\s+)((?:mut\s+)?)([a-zA-Z_]\w*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 14 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"variable")), (4, None), (5, Some(r"keyword"))])), begin: r"(for)(\s+)([a-zA-Z_]\w*)(\s+)(in)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 15 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class"))])), begin: r"(type)(\s+)([a-zA-Z_]\w*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 16 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class"))])), begin: r"((?:trait|enum|struct|union|impl|for))(\s+)([a-zA-Z_]\w*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 17 */ Mode { begin: r"[a-zA-Z]\w*::", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_65), ..MODE },
+    /* 18 */ Mode { scope: Some(r"punctuation"), begin: r"->", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 19 */ Mode { scope: Some(r"title.function.invoke"), begin: r"\b(?!let\b)[a-zA-Z]\w*(?=\s*\()", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 21 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 22 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 23 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 24 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 25 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, ..MODE },
+];
+
+static SCSS: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"[=/|']"), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[19, 20], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[21, 22], ..MODE },
+    /* 3 */ Mode { scope: Some(r"number"), begin: r"\b\d+(\.\d+)?(%|em|ex|ch|rem|vw|vh|vmin|vmax|cm|mm|in|pt|pc|px|deg|grad|rad|turn|s|ms|Hz|kHz|dpi|dpcm|dppx)?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 4 */ Mode { scope: Some(r"selector-id"), begin: r"#[A-Za-z0-9_-]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 5 */ Mode { scope: Some(r"selector-class"), begin: r"\.[A-Za-z0-9_-]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 6 */ Mode { scope: Some(r"selector-attr"), begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", illegal: Some(r"$"), contains: &[23, 24], ..MODE },
+    /* 7 */ Mode { scope: Some(r"selector-tag"), begin: r"\b(a|abbr|address|article|aside|audio|b|blockquote|body|button|canvas|caption|cite|code|dd|del|details|dfn|div|dl|dt|em|fieldset|figcaption|figure|footer|form|h1|h2|h3|h4|h5|h6|header|hgroup|html|i|iframe|img|input|ins|kbd|label|legend|li|main|mark|menu|nav|object|ol|p|q|quote|samp|section|span|strong|summary|sup|table|tbody|td|textarea|tfoot|th|thead|time|tr|ul|var|video)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 8 */ Mode { scope: Some(r"selector-pseudo"), begin: r":(active|any-link|blank|checked|current|default|defined|dir|disabled|drop|empty|enabled|first|first-child|first-of-type|fullscreen|future|focus|focus-visible|focus-within|has|host|host-context|hover|indeterminate|in-range|invalid|is|lang|last-child|last-of-type|left|link|local-link|not|nth-child|nth-col|nth-last-child|nth-last-col|nth-last-of-type|nth-of-type|only-child|only-of-type|optional|out-of-range|past|placeholder-shown|read-only|read-write|required|right|root|scope|target|target-within|user-invalid|valid|visited|where)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 9 */ Mode { scope: Some(r"selector-pseudo"), begin: r":(:)?(after|backdrop|before|cue|cue-region|first-letter|first-line|grammar-error|marker|part|placeholder|selection|slotted|spelling-error)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { scope: Some(r"variable"), begin: r"(\$[a-zA-Z-][a-zA-Z0-9_-]*)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[3], ..MODE },
+    /* 12 */ Mode { scope: Some(r"attr"), begin: r"--[A-Za-z][A-Za-z0-9_-]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 13 */ Mode { scope: Some(r"attribute"), begin: r"\b(z-index|writing-mode|word-wrap|word-spacing|word-break|will-change|width|widows|white-space|voice-volume|voice-stress|voice-rate|voice-range|voice-pitch|voice-family|voice-duration|voice-balance|visibility|vertical-align|unicode-bidi|transition-timing-function|transition-property|transition-duration|transition-delay|transition|transform-style|transform-origin|transform-box|transform|top|text-underline-position|text-transform|text-shadow|text-rendering|text-overflow|text-orientation|text-justify|text-indent|text-emphasis-style|text-emphasis-position|text-emphasis-color|text-emphasis|text-decoration-style|text-decoration-line|text-decoration-color|text-decoration|text-combine-upright|text-align-last|text-align-all|text-align|table-layout|tab-size|src|speak-as|speak|shape-outside|shape-margin|shape-image-threshold|scrollbar-width|scrollbar-gutter|scrollbar-color|scroll-snap-type|scroll-snap-stop|scroll-snap-align|scroll-padding-top|scroll-padding-right|scroll-padding-left|scroll-padding-inline-start|scroll-padding-inline-end|scroll-padding-inline|scroll-padding-bottom|scroll-padding-block-start|scroll-padding-block-end|scroll-padding-block|scroll-padding|scroll-margin-top|scroll-margin-right|scroll-margin-left|scroll-margin-inline-start|scroll-margin-inline-end|scroll-margin-inline|scroll-margin-bottom|scroll-margin-block-start|scroll-margin-block-end|scroll-margin-block|scroll-margin|row-gap|right|rest-before|rest-after|rest|resize|quotes|position|pointer-events|perspective-origin|perspective|pause-before|pause-after|pause|page-break-inside|page-break-before|page-break-after|padding-top|padding-right|padding-left|padding-inline-start|padding-inline-end|padding-inline|padding-bottom|padding-block-start|padding-block-end|padding-block|padding|overflow-y|overflow-x|overflow-wrap|overflow|outline-width|outline-style|outline-offset|outline-color|outline|orphans|order|opacity|object-position|object-fit|normal|none|nav-up|nav-right|nav-left|nav-index|nav-down|mix-blend-mode|min-width|min-inline-size|min-height|min-block-size|max-width|max-inline-size|max-height|max-block-size|mask-type|mask-size|mask-repeat|mask-position|mask-origin|mask-mode|mask-image|mask-composite|mask-clip|mask-border-width|mask-border-source|mask-border-slice|mask-border-repeat|mask-border-outset|mask-border-mode|mask-border|mask|marks|margin-top|margin-right|margin-left|margin-inline-start|margin-inline-end|margin-inline|margin-bottom|margin-block-start|margin-block-end|margin-block|margin|list-style-type|list-style-position|list-style-image|list-style|line-height|line-break|letter-spacing|left|justify-content|isolation|inline-size|ime-mode|image-resolution|image-rendering|image-orientation|icon|hyphens|height|hanging-punctuation|grid-template-rows|grid-template-columns|grid-template-areas|grid-template|grid-row-start|grid-row-end|grid-row|grid-gap|grid-column-start|grid-column-end|grid-column|grid-auto-rows|grid-auto-flow|grid-auto-columns|grid-area|grid|glyph-orientation-vertical|gap|font-weight|font-variation-settings|font-variant-position|font-variant-numeric|font-variant-ligatures|font-variant-east-asian|font-variant-caps|font-variant|font-synthesis|font-style|font-stretch|font-smoothing|font-size-adjust|font-size|font-language-override|font-kerning|font-feature-settings|font-family|font-display|font|flow|float|flex-wrap|flex-shrink|flex-grow|flex-flow|flex-direction|flex-basis|flex|filter|empty-cells|display|direction|cursor|cue-before|cue-after|cue|counter-reset|counter-increment|content-visibility|content|contain|columns|column-width|column-span|column-rule-width|column-rule-style|column-rule-color|column-rule|column-gap|column-fill|column-count|color|clip-rule|clip-path|clip|clear|caret-color|caption-side|break-inside|break-before|break-after|box-sizing|box-shadow|box-decoration-break|bottom|border-width|border-top-width|border-top-style|border-top-right-radius|border-top-left-radius|border-top-color|border-top|border-style|border-spacing|border-right-width|border-right-style|border-right-color|border-right|border-radius|border-left-width|border-left-style|border-left-color|border-left|border-inline-width|border-inline-style|border-inline-start-width|border-inline-start-style|border-inline-start-color|border-inline-start|border-inline-end-width|border-inline-end-style|border-inline-end-color|border-inline-end|border-inline-color|border-inline|border-image-width|border-image-source|border-image-slice|border-image-repeat|border-image-outset|border-image|border-color|border-collapse|border-bottom-width|border-bottom-style|border-bottom-right-radius|border-bottom-left-radius|border-bottom-color|border-bottom|border-block-width|border-block-style|border-block-start-width|border-block-start-style|border-block-start-color|border-block-start|border-block-end-width|border-block-end-style|border-block-end-color|border-block-end|border-block-color|border-block|border|block-size|background-size|background-repeat|background-position|background-origin|background-image|background-color|background-clip|background-blend-mode|background-attachment|background|backface-visibility|animation-timing-function|animation-play-state|animation-name|animation-iteration-count|animation-fill-mode|animation-duration|animation-direction|animation-delay|animation|all|align-self|align-items|align-content)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 14 */ Mode { begin: r"\b(whitespace|wait|w-resize|visible|vertical-text|vertical-ideographic|uppercase|upper-roman|upper-alpha|underline|transparent|top|thin|thick|text|text-top|text-bottom|tb-rl|table-header-group|table-footer-group|sw-resize|super|strict|static|square|solid|small-caps|separate|se-resize|scroll|s-resize|rtl|row-resize|ridge|right|repeat|repeat-y|repeat-x|relative|progress|pointer|overline|outside|outset|oblique|nowrap|not-allowed|normal|none|nw-resize|no-repeat|no-drop|newspaper|ne-resize|n-resize|move|middle|medium|ltr|lr-tb|lowercase|lower-roman|lower-alpha|loose|list-item|line|line-through|line-edge|lighter|left|keep-all|justify|italic|inter-word|inter-ideograph|inside|inset|inline|inline-block|inherit|inactive|ideograph-space|ideograph-parenthesis|ideograph-numeric|ideograph-alpha|horizontal|hidden|help|hand|groove|fixed|ellipsis|e-resize|double|dotted|distribute|distribute-space|distribute-letter|distribute-all-lines|disc|disabled|default|decimal|dashed|crosshair|collapse|col-resize|circle|char|center|capitalize|break-word|break-all|bottom|both|bolder|bold|block|bidi-override|below|baseline|auto|always|all-scroll|absolute|table|table-cell)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 15 */ Mode { begin: r":", end: Some(r"[;}{]"), terminator_end: r"[;}{]", contains: &[25, 10, 26, 3, 27, 28, 29, 18], relevance: 0.0, ..MODE },
+    /* 16 */ Mode { begin: r"@(page|font-face)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_66), keyword_pattern: r"@[a-z-]+", ..MODE },
+    /* 17 */ Mode { begin: r"@", end: Some(r"[{;]"), terminator_end: r"[{;]", keywords: Some(KEYWORDS_18), keyword_pattern: r"[a-z-]+", contains: &[30, 31, 10, 32, 33, 26, 3], return_begin: true, ..MODE },
+    /* 18 */ Mode { scope: Some(r"built_in"), begin: r"[\w-]+(?=\()", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 19 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 20 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 21 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 22 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 23 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[34], ..MODE },
+    /* 24 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[35], ..MODE },
+    /* 25 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[36, 37], ..MODE },
+    /* 26 */ Mode { scope: Some(r"number"), begin: r"#(([0-9a-fA-F]{3,4})|(([0-9a-fA-F]{2}){3,4}))\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 27 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[38], ..MODE },
+    /* 28 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[39], ..MODE },
+    /* 29 */ Mode { scope: Some(r"meta"), begin: r"!important", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 30 */ Mode { scope: Some(r"keyword"), begin: r"@[a-z-]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 31 */ Mode { scope: Some(r"attribute"), begin: r"[a-z-]+(?=:)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 32 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[40], ..MODE },
+    /* 33 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[41], ..MODE },
+    /* 34 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 35 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 36 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 37 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 38 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 39 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 40 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 41 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static SHELL: &[Mode] = &[
+    /* 0 */ Mode { contains: &[1], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta.prompt"), begin: r"^\s{0,3}[/~\w\d[\]()@-]*[>%$#][ ]?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(2), ..MODE },
+    /* 2 */ Mode { begin: r"\B|\b", end: Some(r"[^\\](?=\s*$)"), terminator_end: r"[^\\](?=\s*$)", sub_language: Some(SubLanguage::One(r"bash")), ..MODE },
+];
+
+static SQL: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"[{}]|<\/"), keywords: Some(KEYWORDS_67), keyword_pattern: r"\b[\w\.]+", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], ..MODE },
+    /* 1 */ Mode { begin: r"(?:create table|insert into|primary key|foreign key|not null|alter table|add constraint|grouping sets|on overflow|character set|respect nulls|ignore nulls|nulls first|nulls last|depth first|breadth first)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_68), keyword_pattern: r"[\w\.]+", relevance: 0.0, ..MODE },
+    /* 2 */ Mode { scope: Some(r"type"), begin: r"(?:double precision|large object|with timezone|without timezone)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 3 */ Mode { begin: r"\b(?:abs|acos|array_agg|asin|atan|avg|cast|ceil|ceiling|coalesce|corr|cos|cosh|count|covar_pop|covar_samp|cume_dist|dense_rank|deref|element|exp|extract|first_value|floor|json_array|json_arrayagg|json_exists|json_object|json_objectagg|json_query|json_table|json_table_primitive|json_value|lag|last_value|lead|listagg|ln|log|log10|lower|max|min|mod|nth_value|ntile|nullif|percent_rank|percentile_cont|percentile_disc|position|position_regex|power|rank|regr_avgx|regr_avgy|regr_count|regr_intercept|regr_r2|regr_slope|regr_sxx|regr_sxy|regr_syy|row_number|sin|sinh|sqrt|stddev_pop|stddev_samp|substring|substring_regex|sum|tan|tanh|translate|translate_regex|treat|trim|trim_array|unnest|upper|value_of|var_pop|var_samp|width_bucket)\s*\(", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_69), relevance: 0.0, ..MODE },
+    /* 4 */ Mode { scope: Some(r"variable"), begin: r"@[a-z0-9]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[11], ..MODE },
+    /* 6 */ Mode { begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[12], ..MODE },
+    /* 7 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 8 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[13, 14], ..MODE },
+    /* 9 */ Mode { scope: Some(r"comment"), begin: r"--", end: Some(r"$"), terminator_end: r"$", contains: &[15, 16], ..MODE },
+    /* 10 */ Mode { scope: Some(r"operator"), begin: r"[-+*/=%^~]|&&?|\|\|?|!=?|<(?:=>?|<|>)?|>[>=]?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { begin: r"''", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { begin: r#""""#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 13 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 14 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 15 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 16 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static SWIFT: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[37, 38], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[2, 39, 40], ..MODE },
+    /* 3 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function"))])), begin: r"(func)(\s+)((?:`(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*`|(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*|(?:[/=\-+!*%<>&|^~?]|[\u00A1-\u00A7]|[\u00A9\u00AB]|[\u00AC\u00AE]|[\u00B0\u00B1]|[\u00B6\u00BB\u00BF\u00D7\u00F7]|[\u2016-\u2017]|[\u2020-\u2027]|[\u2030-\u203E]|[\u2041-\u2053]|[\u2055-\u205E]|[\u2190-\u23FF]|[\u2500-\u2775]|[\u2794-\u2BFF]|[\u2E00-\u2E7F]|[\u3001-\u3003]|[\u3008-\u3020]|[\u3030])(?:(?:[/=\-+!*%<>&|^~?]|[\u00A1-\u00A7]|[\u00A9\u00AB]|[\u00AC\u00AE]|[\u00B0\u00B1]|[\u00B6\u00BB\u00BF\u00D7\u00F7]|[\u2016-\u2017]|[\u2020-\u2027]|[\u2030-\u203E]|[\u2041-\u2053]|[\u2055-\u205E]|[\u2190-\u23FF]|[\u2500-\u2775]|[\u2794-\u2BFF]|[\u2E00-\u2E7F]|[\u3001-\u3003]|[\u3008-\u3020]|[\u3030])|[\u0300-\u036F]|[\u1DC0-\u1DFF]|[\u20D0-\u20FF]|[\uFE00-\uFE0F]|[\uFE20-\uFE2F])*))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", illegal: Some(r"(?:\[|%)"), contains: &[41, 42, 43], ..MODE },
+    /* 4 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None)])), begin: r"(\b(?:subscript|init[?!]?))(\s*(?=[<(]))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", illegal: Some(r"\[|%"), contains: &[41, 42, 43], ..MODE },
+    /* 5 */ Mode { begin: r"\b(struct|protocol|class|extension|enum|actor)(?!\.)(?=\b|\s)", end: Some(r"\{"), terminator_end: r"\{", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[44, 9, 10, 11], relevance: 0.0, exclude_end: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 6 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title"))])), begin: r"(operator)(\s+)((?:[/=\-+!*%<>&|^~?]|[\u00A1-\u00A7]|[\u00A9\u00AB]|[\u00AC\u00AE]|[\u00B0\u00B1]|[\u00B6\u00BB\u00BF\u00D7\u00F7]|[\u2016-\u2017]|[\u2020-\u2027]|[\u2030-\u203E]|[\u2041-\u2053]|[\u2055-\u205E]|[\u2190-\u23FF]|[\u2500-\u2775]|[\u2794-\u2BFF]|[\u2E00-\u2E7F]|[\u3001-\u3003]|[\u3008-\u3020]|[\u3030])(?:(?:[/=\-+!*%<>&|^~?]|[\u00A1-\u00A7]|[\u00A9\u00AB]|[\u00AC\u00AE]|[\u00B0\u00B1]|[\u00B6\u00BB\u00BF\u00D7\u00F7]|[\u2016-\u2017]|[\u2020-\u2027]|[\u2030-\u203E]|[\u2041-\u2053]|[\u2055-\u205E]|[\u2190-\u23FF]|[\u2500-\u2775]|[\u2794-\u2BFF]|[\u2E00-\u2E7F]|[\u3001-\u3003]|[\u3008-\u3020]|[\u3030])|[\u0300-\u036F]|[\u1DC0-\u1DFF]|[\u20D0-\u20FF]|[\uFE00-\uFE0F]|[\uFE20-\uFE2F])*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title"))])), begin: r"(precedencegroup)(\s+)([A-Z](?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*)", end: Some(r"}"), terminator_end: r"}", keywords: Some(KEYWORDS_71), contains: &[35], ..MODE },
+    /* 8 */ Mode { begin: r"\b(import)(?!\.)(?=\b|\s)", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_23), contains: &[45, 2], relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 9 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, None), (2, Some(r"keyword"))])), begin: r"(\.)((?:\bProtocol\b|\bType\b|\binit\b|\bself\b))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { begin: r"\.(?:actor|any|associatedtype|async|await|as\?|as!|as|break|case|catch|class|continue|convenience|default|defer|deinit|didSet|distributed|do|dynamic|else|enum|extension|fallthrough|fileprivate\(set\)|fileprivate|final|for|func|get|guard|if|import|indirect|infix|init\?|init!|inout|internal\(set\)|internal|in|is|isolated|nonisolated|lazy|let|mutating|nonmutating|open\(set\)|open|operator|optional|override|postfix|precedencegroup|prefix|private\(set\)|private|protocol|public\(set\)|public|repeat|required|rethrows|return|set|some|static|struct|subscript|super|switch|throws|throw|try\?|try!|try|typealias|unowned\(safe\)|unowned\(unsafe\)|unowned|var|weak|where|while|willSet)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { scope: Some(r"keyword"), begin: r"(?:\bas\?\B|\bas!\B|\bfileprivate\(set\)\B|\binit\?\B|\binit!\B|\binternal\(set\)\B|\bopen\(set\)\B|\bprivate\(set\)\B|\bpublic\(set\)\B|\btry\?\B|\btry!\B|\bunowned\(safe\)\B|\bunowned\(unsafe\)\B|\bAny\b|\bSelf\b|\binit\b|\bself\b)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { begin: r"\.(?:abs|all|any|assert|assertionFailure|debugPrint|dump|fatalError|getVaList|isKnownUniquelyReferenced|max|min|numericCast|pointwiseMax|pointwiseMin|precondition|preconditionFailure|print|readLine|repeatElement|sequence|stride|swap|swift_unboxFromSwiftValueWithType|transcode|type|unsafeBitCast|unsafeDowncast|withExtendedLifetime|withUnsafeMutablePointer|withUnsafePointer|withVaList|withoutActuallyEscaping|zip)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"built_in"), begin: r"\b(?:abs|all|any|assert|assertionFailure|debugPrint|dump|fatalError|getVaList|isKnownUniquelyReferenced|max|min|numericCast|pointwiseMax|pointwiseMin|precondition|preconditionFailure|print|readLine|repeatElement|sequence|stride|swap|swift_unboxFromSwiftValueWithType|transcode|type|unsafeBitCast|unsafeDowncast|withExtendedLifetime|withUnsafeMutablePointer|withUnsafePointer|withVaList|withoutActuallyEscaping|zip)(?=\()", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 14 */ Mode { begin: r"->", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 15 */ Mode { scope: Some(r"operator"), begin: r"(?:[/=\-+!*%<>&|^~?]|[\u00A1-\u00A7]|[\u00A9\u00AB]|[\u00AC\u00AE]|[\u00B0\u00B1]|[\u00B6\u00BB\u00BF\u00D7\u00F7]|[\u2016-\u2017]|[\u2020-\u2027]|[\u2030-\u203E]|[\u2041-\u2053]|[\u2055-\u205E]|[\u2190-\u23FF]|[\u2500-\u2775]|[\u2794-\u2BFF]|[\u2E00-\u2E7F]|[\u3001-\u3003]|[\u3008-\u3020]|[\u3030])(?:(?:[/=\-+!*%<>&|^~?]|[\u00A1-\u00A7]|[\u00A9\u00AB]|[\u00AC\u00AE]|[\u00B0\u00B1]|[\u00B6\u00BB\u00BF\u00D7\u00F7]|[\u2016-\u2017]|[\u2020-\u2027]|[\u2030-\u203E]|[\u2041-\u2053]|[\u2055-\u205E]|[\u2190-\u23FF]|[\u2500-\u2775]|[\u2794-\u2BFF]|[\u2E00-\u2E7F]|[\u3001-\u3003]|[\u3008-\u3020]|[\u3030])|[\u0300-\u036F]|[\u1DC0-\u1DFF]|[\u20D0-\u20FF]|[\uFE00-\uFE0F]|[\uFE20-\uFE2F])*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { scope: Some(r"operator"), begin: r"\.(\.|(?:(?:[/=\-+!*%<>&|^~?]|[\u00A1-\u00A7]|[\u00A9\u00AB]|[\u00AC\u00AE]|[\u00B0\u00B1]|[\u00B6\u00BB\u00BF\u00D7\u00F7]|[\u2016-\u2017]|[\u2020-\u2027]|[\u2030-\u203E]|[\u2041-\u2053]|[\u2055-\u205E]|[\u2190-\u23FF]|[\u2500-\u2775]|[\u2794-\u2BFF]|[\u2E00-\u2E7F]|[\u3001-\u3003]|[\u3008-\u3020]|[\u3030])|[\u0300-\u036F]|[\u1DC0-\u1DFF]|[\u20D0-\u20FF]|[\uFE00-\uFE0F]|[\uFE20-\uFE2F]))+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"number"), begin: r"\b(([0-9]_*)+)(\.(([0-9]_*)+))?([eE][+-]?(([0-9]_*)+))?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { scope: Some(r"number"), begin: r"\b0x(([0-9a-fA-F]_*)+)(\.(([0-9a-fA-F]_*)+))?([pP][+-]?(([0-9]_*)+))?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { scope: Some(r"number"), begin: r"\b0o([0-7]_*)+\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"number"), begin: r"\b0b([01]_*)+\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 21 */ Mode { scope: Some(r"string"), begin: r#"""""#, end: Some(r#"""""#), terminator_end: r#"""""#, contains: &[46, 47, 48, 49], ..MODE },
+    /* 22 */ Mode { scope: Some(r"string"), begin: r#"#""""#, end: Some(r##""""#"##), terminator_end: r##""""#"##, contains: &[50, 51, 52, 53], ..MODE },
+    /* 23 */ Mode { scope: Some(r"string"), begin: r#"##""""#, end: Some(r###""""##"###), terminator_end: r###""""##"###, contains: &[54, 55, 56, 57], ..MODE },
+    /* 24 */ Mode { scope: Some(r"string"), begin: r#"###""""#, end: Some(r####""""###"####), terminator_end: r####""""###"####, contains: &[58, 59, 60, 61], ..MODE },
+    /* 25 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[62, 63, 64], ..MODE },
+    /* 26 */ Mode { scope: Some(r"string"), begin: r#"#""#, end: Some(r##""#"##), terminator_end: r##""#"##, contains: &[65, 66, 67], ..MODE },
+    /* 27 */ Mode { scope: Some(r"string"), begin: r#"##""#, end: Some(r###""##"###), terminator_end: r###""##"###, contains: &[68, 69, 70], ..MODE },
+    /* 28 */ Mode { scope: Some(r"string"), begin: r#"###""#, end: Some(r####""###"####), terminator_end: r####""###"####, contains: &[71, 72, 73], ..MODE },
+    /* 29 */ Mode { begin: r"`(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*`", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 30 */ Mode { scope: Some(r"variable"), begin: r"\$\d+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 31 */ Mode { scope: Some(r"variable"), begin: r"\$(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 32 */ Mode { scope: Some(r"keyword"), begin: r"(@|#(un)?)available", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(74), ..MODE },
+    /* 33 */ Mode { scope: Some(r"keyword"), begin: r"@(?:autoclosure|convention\((?:swift|block|c)\)|discardableResult|dynamicCallable|dynamicMemberLookup|escaping|frozen|GKInspectable|IBAction|IBDesignable|IBInspectable|IBOutlet|IBSegueAction|inlinable|main|nonobjc|NSApplicationMain|NSCopying|NSManaged|objc\((?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*\)|objc|objcMembers|propertyWrapper|requires_stored_property_inits|resultBuilder|testable|UIApplicationMain|unknown|usableFromInline)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 34 */ Mode { scope: Some(r"meta"), begin: r"@(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 35 */ Mode { begin: r"(?=\b[A-Z])", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[75, 76, 77, 78, 79, 80], relevance: 0.0, ..MODE },
+    /* 36 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[36, 81, 82, 2, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35], relevance: 0.0, ..MODE },
+    /* 37 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 38 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 39 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 40 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 41 */ Mode { begin: r"<", end: Some(r">"), terminator_end: r">", contains: &[83, 2, 35], ..MODE },
+    /* 42 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", illegal: Some(r#"["']"#), keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[84, 85, 2, 9, 10, 11, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 32, 33, 34, 35, 36], ends_parent: true, ..MODE },
+    /* 43 */ Mode { begin: r"\s+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 44 */ Mode { scope: Some(r"title.class"), begin: r"[A-Za-z$_][\u00C0-\u02B80-9A-Za-z$_]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 45 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[86, 87], ..MODE },
+    /* 46 */ Mode { scope: Some(r"subst"), begin: r#"\\[0\\tnr"']"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 47 */ Mode { scope: Some(r"subst"), begin: r"\\u\{[0-9a-fA-F]{1,8}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 48 */ Mode { scope: Some(r"subst"), begin: r"\\[\t ]*(?:[\r\n]|\r\n)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 49 */ Mode { scope: Some(r"subst"), begin: r"\\\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 88], ..MODE },
+    /* 50 */ Mode { scope: Some(r"subst"), begin: r#"\\#[0\\tnr"']"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 51 */ Mode { scope: Some(r"subst"), begin: r"\\#u\{[0-9a-fA-F]{1,8}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 52 */ Mode { scope: Some(r"subst"), begin: r"\\#[\t ]*(?:[\r\n]|\r\n)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 53 */ Mode { scope: Some(r"subst"), begin: r"\\#\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 89], ..MODE },
+    /* 54 */ Mode { scope: Some(r"subst"), begin: r#"\\##[0\\tnr"']"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 55 */ Mode { scope: Some(r"subst"), begin: r"\\##u\{[0-9a-fA-F]{1,8}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 56 */ Mode { scope: Some(r"subst"), begin: r"\\##[\t ]*(?:[\r\n]|\r\n)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 57 */ Mode { scope: Some(r"subst"), begin: r"\\##\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 90], ..MODE },
+    /* 58 */ Mode { scope: Some(r"subst"), begin: r#"\\###[0\\tnr"']"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 59 */ Mode { scope: Some(r"subst"), begin: r"\\###u\{[0-9a-fA-F]{1,8}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 60 */ Mode { scope: Some(r"subst"), begin: r"\\###[\t ]*(?:[\r\n]|\r\n)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 61 */ Mode { scope: Some(r"subst"), begin: r"\\###\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 91], ..MODE },
+    /* 62 */ Mode { scope: Some(r"subst"), begin: r#"\\[0\\tnr"']"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 63 */ Mode { scope: Some(r"subst"), begin: r"\\u\{[0-9a-fA-F]{1,8}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 64 */ Mode { scope: Some(r"subst"), begin: r"\\\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 92], ..MODE },
+    /* 65 */ Mode { scope: Some(r"subst"), begin: r#"\\#[0\\tnr"']"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 66 */ Mode { scope: Some(r"subst"), begin: r"\\#u\{[0-9a-fA-F]{1,8}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 67 */ Mode { scope: Some(r"subst"), begin: r"\\#\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 93], ..MODE },
+    /* 68 */ Mode { scope: Some(r"subst"), begin: r#"\\##[0\\tnr"']"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 69 */ Mode { scope: Some(r"subst"), begin: r"\\##u\{[0-9a-fA-F]{1,8}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 70 */ Mode { scope: Some(r"subst"), begin: r"\\##\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 94], ..MODE },
+    /* 71 */ Mode { scope: Some(r"subst"), begin: r#"\\###[0\\tnr"']"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 72 */ Mode { scope: Some(r"subst"), begin: r"\\###u\{[0-9a-fA-F]{1,8}\}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 73 */ Mode { scope: Some(r"subst"), begin: r"\\###\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 95], ..MODE },
+    /* 74 */ Mode { begin: r"\B|\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[96], ..MODE },
+    /* 75 */ Mode { scope: Some(r"type"), begin: r"(AV|CA|CF|CG|CI|CL|CM|CN|CT|MK|MP|MTK|MTL|NS|SCN|SK|UI|WK|XC)(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 76 */ Mode { scope: Some(r"type"), begin: r"[A-Z](?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 77 */ Mode { begin: r"[?!]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 78 */ Mode { begin: r"\.\.\.", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 79 */ Mode { begin: r"\s+&\s+(?=[A-Z](?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 80 */ Mode { begin: r"<", end: Some(r">"), terminator_end: r">", keywords: Some(KEYWORDS_70), keyword_pattern: r"(?:\b\w+|#\w+)", contains: &[97, 2, 9, 10, 11, 32, 33, 34, 14, 35], ..MODE },
+    /* 81 */ Mode { begin: r"(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*\s*:", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_72), relevance: 0.0, ..MODE },
+    /* 82 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[98, 99], ..MODE },
+    /* 83 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[100, 101], ..MODE },
+    /* 84 */ Mode { begin: r"(?:(?=(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*\s*:)|(?=(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*\s+(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*\s*:))", end: Some(r":"), terminator_end: r":", contains: &[102, 103], relevance: 0.0, ..MODE },
+    /* 85 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[104, 105], ..MODE },
+    /* 86 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 87 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 88 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[88, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 89 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[89, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 90 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[90, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 91 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[91, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 92 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[92, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 93 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[93, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 94 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[94, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 95 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", contains: &[95, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], ..MODE },
+    /* 96 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_73), contains: &[14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28], ..MODE },
+    /* 97 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[106, 107], ..MODE },
+    /* 98 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 99 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 100 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 101 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 102 */ Mode { scope: Some(r"keyword"), begin: r"\b_\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 103 */ Mode { scope: Some(r"params"), begin: r"(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])(?:(?:[a-zA-Z_]|[\u00A8\u00AA\u00AD\u00AF\u00B2-\u00B5\u00B7-\u00BA]|[\u00BC-\u00BE\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u00FF]|[\u0100-\u02FF\u0370-\u167F\u1681-\u180D\u180F-\u1DBF]|[\u1E00-\u1FFF]|[\u200B-\u200D\u202A-\u202E\u203F-\u2040\u2054\u2060-\u206F]|[\u2070-\u20CF\u2100-\u218F\u2460-\u24FF\u2776-\u2793]|[\u2C00-\u2DFF\u2E80-\u2FFF]|[\u3004-\u3007\u3021-\u302F\u3031-\u303F\u3040-\uD7FF]|[\uF900-\uFD3D\uFD40-\uFDCF\uFDF0-\uFE1F\uFE30-\uFE44]|[\uFE47-\uFEFE\uFF00-\uFFFD])|\d|[\u0300-\u036F\u1DC0-\u1DFF\u20D0-\u20FF\uFE20-\uFE2F])*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 104 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 105 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 106 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 107 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static TYPESCRIPT: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"#(?![$_A-z])"), keywords: Some(KEYWORDS_74), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39], ..MODE },
+    /* 1 */ Mode { scope: Some(r"meta"), begin: r"^#![ ]*\/", end: Some(r"$"), terminator_end: r"$", relevance: 0.0, on_begin: Some(Callback::IgnoreUnlessFirst), ..MODE },
+    /* 2 */ Mode { scope: Some(r"meta"), begin: r#"^\s*['"]use strict['"]"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 10.0, ..MODE },
+    /* 3 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[40], ..MODE },
+    /* 4 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[41], ..MODE },
+    /* 5 */ Mode { begin: r"html`", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(42), ..MODE },
+    /* 6 */ Mode { begin: r"css`", end: Some(r"\B|\b"), terminator_end: r"\B|\b", starts: Some(43), ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r"`", end: Some(r"`"), terminator_end: r"`", contains: &[44, 45], ..MODE },
+    /* 8 */ Mode { scope: Some(r"comment"), begin: r"\/\*\*(?!\/)", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[46, 47, 48], relevance: 0.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"comment"), begin: r"/\*", end: Some(r"\*\/"), terminator_end: r"\*/", contains: &[49, 50], ..MODE },
+    /* 10 */ Mode { scope: Some(r"comment"), begin: r"//", end: Some(r"$"), terminator_end: r"$", contains: &[51, 52], ..MODE },
+    /* 11 */ Mode { begin: r"\$\d+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"number"), begin: r"(\b(0|[1-9](_?[0-9])*|0[0-7]*[89][0-9]*)((\.([0-9](_?[0-9])*))|\.)?|(\.([0-9](_?[0-9])*)))[eE][+-]?([0-9](_?[0-9])*)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 13 */ Mode { scope: Some(r"number"), begin: r"\b(0|[1-9](_?[0-9])*|0[0-7]*[89][0-9]*)\b((\.([0-9](_?[0-9])*))\b|\.)?|(\.([0-9](_?[0-9])*))\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 14 */ Mode { scope: Some(r"number"), begin: r"\b(0|[1-9](_?[0-9])*)n\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 15 */ Mode { scope: Some(r"number"), begin: r"\b0[xX][0-9a-fA-F](_?[0-9a-fA-F])*n?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 16 */ Mode { scope: Some(r"number"), begin: r"\b0[bB][0-1](_?[0-1])*n?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 17 */ Mode { scope: Some(r"number"), begin: r"\b0[oO][0-7](_?[0-7])*n?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { scope: Some(r"number"), begin: r"\b0[0-7]+n?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 19 */ Mode { scope: Some(r"title.class"), begin: r"(?:\bJSON|\b[A-Z][a-z]+([A-Z][a-z]*|\d)*|\b[A-Z]{2,}([A-Z][a-z]+|\d)+([A-Z][a-z]*)*|\b[A-Z]{2,}[a-z]+([A-Z][a-z]+|\d)*([A-Z][a-z]*)*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_26), relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"attr"), begin: r"[A-Za-z$_][0-9A-Za-z$_]*(?=:)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 21 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function")), (4, None), (5, None), (6, None), (8, None)])), begin: r"(const|var|let)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)(\s*)(=\s*)((async\s*)?)((?=(\([^()]*(\([^()]*(\([^()]*\)[^()]*)*\)[^()]*)*\)|[a-zA-Z_]\w*)\s*=>))", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_27), contains: &[53], ..MODE },
+    /* 22 */ Mode { begin: r"(!|!=|!==|%|%=|&|&&|&=|\*|\*=|\+|\+=|,|-|-=|/=|/|:|;|<<|<<=|<=|<|===|==|=|>>>=|>>=|>=|>>>|>>|>|\?|\[|\{|\(|\^|\^=|\||\|=|\|\||~|\b(case|return|throw)\b)\s*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_28), contains: &[8, 9, 10, 54, 55, 56, 57, 58, 59, 60], relevance: 0.0, ..MODE },
+    /* 23 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function")), (4, None)])), begin: r"(function)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)((?=\s*\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", illegal: Some(r"%"), contains: &[53], relevance: 0.0, ..MODE },
+    /* 24 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None)])), begin: r"(function)(\s*(?=\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", illegal: Some(r"%"), contains: &[53], relevance: 0.0, ..MODE },
+    /* 25 */ Mode { begin: r"\b(while|if|switch|catch|for)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_29), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 26 */ Mode { begin: r"\b(?!function)[a-zA-Z_]\w*\([^()]*(\([^()]*(\([^()]*\)[^()]*)*\)[^()]*)*\)\s*\{", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[53, 61], return_begin: true, ..MODE },
+    /* 27 */ Mode { begin: r"\.\.\.", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 28 */ Mode { scope: Some(r"property"), begin: r"\.(?=[A-Za-z$_][0-9A-Za-z$_]*(?![0-9A-Za-z$_(]))", end: Some(r"[A-Za-z$_][0-9A-Za-z$_]*"), terminator_end: r"[A-Za-z$_][0-9A-Za-z$_]*", keywords: Some(KEYWORDS_30), relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 29 */ Mode { begin: r"\$[A-Za-z$_][0-9A-Za-z$_]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 30 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"title.function"))])), begin: r"(\bconstructor(?=\s*\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[53], ..MODE },
+    /* 31 */ Mode { scope: Some(r"title.function"), begin: r"\b(?!setInterval|setTimeout|clearInterval|clearTimeout|require|exports|eval|isFinite|isNaN|parseFloat|parseInt|decodeURI|decodeURIComponent|encodeURI|encodeURIComponent|escape|unescape|super|import)[A-Za-z$_][0-9A-Za-z$_]*(?=\()", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { scope: Some(r"variable.constant"), begin: r"\b[A-Z][A-Z_0-9]+\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 33 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class")), (4, None), (5, Some(r"keyword")), (6, None), (7, Some(r"title.class.inherited"))])), begin: r"(class)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)(\s+)(extends)(\s+)([A-Za-z$_][0-9A-Za-z$_]*(\.[A-Za-z$_][0-9A-Za-z$_]*)*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 34 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.class"))])), begin: r"(class)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 35 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function")), (4, None)])), begin: r"(get|set)(\s+)([A-Za-z$_][0-9A-Za-z$_]*)((?=\())", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[62, 53], ..MODE },
+    /* 36 */ Mode { begin: r"\$[(.]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 37 */ Mode { scope: Some(r"meta"), begin: r"@[A-Za-z$_][0-9A-Za-z$_]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 38 */ Mode { begin: r"\b(namespace)(?!\.)(?=\b|\s)", end: Some(r"\{"), terminator_end: r"\{", keywords: Some(KEYWORDS_10), contains: &[19], relevance: 0.0, exclude_end: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 39 */ Mode { begin: r"\b(interface)(?!\.)(?=\b|\s)", end: Some(r"\{"), terminator_end: r"\{", keywords: Some(KEYWORDS_75), contains: &[19], relevance: 0.0, exclude_end: true, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 40 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 41 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 42 */ Mode { begin: r"\B|\b", end: Some(r"`"), terminator_end: r"`", contains: &[63, 45], sub_language: Some(SubLanguage::One(r"xml")), ..MODE },
+    /* 43 */ Mode { begin: r"\B|\b", end: Some(r"`"), terminator_end: r"`", contains: &[64, 45], sub_language: Some(SubLanguage::One(r"css")), ..MODE },
+    /* 44 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 45 */ Mode { scope: Some(r"subst"), begin: r"\$\{", end: Some(r"\}"), terminator_end: r"\}", keywords: Some(KEYWORDS_74), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[65, 66, 5, 6, 7, 67, 12, 13, 14, 15, 16, 17, 18, 68], ..MODE },
+    /* 46 */ Mode { begin: r"(?=@[A-Za-z]+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[69, 70, 71, 72], relevance: 0.0, ..MODE },
+    /* 47 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 48 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 49 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 50 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 51 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 52 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 53 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_74), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[8, 9, 10, 73, 74, 5, 6, 7, 67, 12, 13, 14, 15, 16, 17, 18, 68, 75, 37], exclude_begin: true, exclude_end: true, ..MODE },
+    /* 54 */ Mode { begin: r"(?=\/[^/\n]*\/)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[76], ..MODE },
+    /* 55 */ Mode { scope: Some(r"function"), begin: r"(\([^()]*(\([^()]*(\([^()]*\)[^()]*)*\)[^()]*)*\)|[a-zA-Z_]\w*)\s*=>", end: Some(r"\s*=>"), terminator_end: r"\s*=>", contains: &[77, 78, 79], return_begin: true, ..MODE },
+    /* 56 */ Mode { begin: r",", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 57 */ Mode { begin: r"\s+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 58 */ Mode { begin: r"<>", end: Some(r"<\/>"), terminator_end: r"</>", contains: &[80], sub_language: Some(SubLanguage::One(r"xml")), ..MODE },
+    /* 59 */ Mode { begin: r"<[A-Za-z0-9\\._:-]+\s*\/>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[80], sub_language: Some(SubLanguage::One(r"xml")), ..MODE },
+    /* 60 */ Mode { begin: r"<[A-Za-z0-9\\._:-]+", end: Some(r"\/[A-Za-z0-9\\._:-]+>|\/>"), terminator_end: r"\/[A-Za-z0-9\\._:-]+>|\/>", contains: &[80], sub_language: Some(SubLanguage::One(r"xml")), on_begin: Some(Callback::IgnoreUnlessJsxTag), ..MODE },
+    /* 61 */ Mode { scope: Some(r"title.function"), begin: r"[A-Za-z$_][0-9A-Za-z$_]*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 62 */ Mode { begin: r"\(\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 63 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 64 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 65 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[81], ..MODE },
+    /* 66 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[82], ..MODE },
+    /* 67 */ Mode { begin: r"\$\d+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 68 */ Mode { begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", keywords: Some(KEYWORDS_74), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[68, 83, 84, 5, 6, 7, 67, 12, 13, 14, 15, 16, 17, 18], ..MODE },
+    /* 69 */ Mode { scope: Some(r"doctag"), begin: r"@[A-Za-z]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 70 */ Mode { scope: Some(r"type"), begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", relevance: 0.0, exclude_begin: true, exclude_end: true, ..MODE },
+    /* 71 */ Mode { scope: Some(r"variable"), begin: r"[A-Za-z$_][0-9A-Za-z$_]*(?=\s*(-)|$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ends_parent: true, ..MODE },
+    /* 72 */ Mode { begin: r"(?=[^\n])\s", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 73 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[85], ..MODE },
+    /* 74 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[86], ..MODE },
+    /* 75 */ Mode { begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_74), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[75, 8, 9, 10, 87, 88, 5, 6, 7, 67, 12, 13, 14, 15, 16, 17, 18, 68], ..MODE },
+    /* 76 */ Mode { scope: Some(r"regexp"), begin: r"\/", end: Some(r"\/[gimuy]*"), terminator_end: r"\/[gimuy]*", illegal: Some(r"\n"), contains: &[89, 90], ..MODE },
+    /* 77 */ Mode { scope: Some(r"params"), begin: r"[a-zA-Z_]\w*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 78 */ Mode { begin: r"\(\s*\)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", skip: true, ..MODE },
+    /* 79 */ Mode { scope: Some(r"params"), begin: r"\(", end: Some(r"\)"), terminator_end: r"\)", keywords: Some(KEYWORDS_74), keyword_pattern: r"[A-Za-z$_][0-9A-Za-z$_]*", contains: &[8, 9, 10, 91, 92, 5, 6, 7, 67, 12, 13, 14, 15, 16, 17, 18, 68, 75, 37], exclude_begin: true, exclude_end: true, ..MODE },
+    /* 80 */ Mode { begin: r"<[A-Za-z0-9\\._:-]+", end: Some(r"\/[A-Za-z0-9\\._:-]+>|\/>"), terminator_end: r"\/[A-Za-z0-9\\._:-]+>|\/>", contains: &[80], skip: true, ..MODE },
+    /* 81 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 82 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 83 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[93], ..MODE },
+    /* 84 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[94], ..MODE },
+    /* 85 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 86 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 87 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[95], ..MODE },
+    /* 88 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[96], ..MODE },
+    /* 89 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 90 */ Mode { begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", contains: &[97], relevance: 0.0, ..MODE },
+    /* 91 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", illegal: Some(r"\n"), contains: &[98], ..MODE },
+    /* 92 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[99], ..MODE },
+    /* 93 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 94 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 95 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 96 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 97 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 98 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 99 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static VBNET: &[Mode] = &[
+    /* 0 */ Mode { illegal: Some(r"//|\{|\}|endif|gosub|variant|wend|^\$ "), keywords: Some(KEYWORDS_76), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16], ..MODE },
+    /* 1 */ Mode { scope: Some(r"string"), begin: r#""(""|[^/n])"C\b"#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 2 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[17], ..MODE },
+    /* 3 */ Mode { scope: Some(r"literal"), begin: r"# *(?:\d{4}-\d{1,2}-\d{1,2}|\d{1,2}\/\d{1,2}\/\d{4}) *#", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 4 */ Mode { scope: Some(r"literal"), begin: r"# *\d{1,2}(:\d{1,2}){1,2} *#", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 5 */ Mode { scope: Some(r"literal"), begin: r"# *(\d|1[012])(:\d+){0,2} *(AM|PM) *#", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 6 */ Mode { scope: Some(r"literal"), begin: r"# *(?:\d{4}-\d{1,2}-\d{1,2}|\d{1,2}\/\d{1,2}\/\d{4}) +(?:(\d|1[012])(:\d+){0,2} *(AM|PM)|\d{1,2}(:\d{1,2}){1,2}) *#", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { scope: Some(r"number"), begin: r"\b\d[\d_]*((\.[\d_]+(E[+-]?[\d_]+)?)|(E[+-]?[\d_]+))[RFD@!#]?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 8 */ Mode { scope: Some(r"number"), begin: r"\b\d[\d_]*((U?[SIL])|[%&])?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 9 */ Mode { scope: Some(r"number"), begin: r"&H[\dA-F_]+((U?[SIL])|[%&])?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 10 */ Mode { scope: Some(r"number"), begin: r"&O[0-7_]+((U?[SIL])|[%&])?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { scope: Some(r"number"), begin: r"&B[01_]+((U?[SIL])|[%&])?", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 12 */ Mode { scope: Some(r"label"), begin: r"^\w+:", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 13 */ Mode { scope: Some(r"comment"), begin: r"'''", end: Some(r"$"), terminator_end: r"$", contains: &[18, 19, 20], ..MODE },
+    /* 14 */ Mode { scope: Some(r"comment"), begin: r"'", end: Some(r"$"), terminator_end: r"$", contains: &[21, 22], ..MODE },
+    /* 15 */ Mode { scope: Some(r"comment"), begin: r"([\t ]|^)REM(?=\s)", end: Some(r"$"), terminator_end: r"$", contains: &[21, 22], ..MODE },
+    /* 16 */ Mode { scope: Some(r"meta"), begin: r"[\t ]*#(const|disable|else|elseif|enable|end|externalsource|if|region)\b", end: Some(r"$"), terminator_end: r"$", keywords: Some(KEYWORDS_77), contains: &[14, 15], ..MODE },
+    /* 17 */ Mode { begin: r#""""#, end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 18 */ Mode { scope: Some(r"doctag"), begin: r"<\/?", end: Some(r">"), terminator_end: r">", ..MODE },
+    /* 19 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 20 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 21 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 22 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+static WASM: &[Mode] = &[
+    /* 0 */ Mode { keywords: Some(KEYWORDS_78), keyword_pattern: r"[\w.]+", contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], ..MODE },
+    /* 1 */ Mode { scope: Some(r"comment"), begin: r";;", end: Some(r"$"), terminator_end: r"$", contains: &[11, 12], ..MODE },
+    /* 2 */ Mode { scope: Some(r"comment"), begin: r"\(;", end: Some(r";\)"), terminator_end: r";\)", contains: &[13, 14, 2], ..MODE },
+    /* 3 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"operator"))])), begin: r"((?:offset|align))(\s*)(=)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 4 */ Mode { scope: Some(r"variable"), begin: r"\$[\w_]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 5 */ Mode { scope: Some(r"punctuation"), begin: r"(\((?!;)|\))+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 6 */ Mode { begin_scope: Some(Scopes::Multi(&[(1, Some(r"keyword")), (2, None), (3, Some(r"title.function"))])), begin: r"((?:func|call|call_indirect))(\s+)(\$[^\s)]+)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 7 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, illegal: Some(r"\n"), contains: &[15], ..MODE },
+    /* 8 */ Mode { scope: Some(r"type"), begin: r"(i32|i64|f32|f64)(?!\.)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 9 */ Mode { scope: Some(r"keyword"), begin: r"\b(f32|f64|i32|i64)(?:\.(?:abs|add|and|ceil|clz|const|convert_[su]\/i(?:32|64)|copysign|ctz|demote\/f64|div(?:_[su])?|eqz?|extend_[su]\/i32|floor|ge(?:_[su])?|gt(?:_[su])?|le(?:_[su])?|load(?:(?:8|16|32)_[su])?|lt(?:_[su])?|max|min|mul|nearest|neg?|or|popcnt|promote\/f32|reinterpret\/[fi](?:32|64)|rem_[su]|rot[lr]|shl|shr_[su]|store(?:8|16|32)?|sqrt|sub|trunc(?:_[su]\/f(?:32|64))?|wrap\/i64|xor))\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { scope: Some(r"number"), begin: r"[+-]?\b(?:\d(?:_?\d)*(?:\.\d(?:_?\d)*)?(?:[eE][+-]?\d(?:_?\d)*)?|0x[\da-fA-F](?:_?[\da-fA-F])*(?:\.[\da-fA-F](?:_?[\da-fA-D])*)?(?:[pP][+-]?\d(?:_?\d)*)?)\b|\binf\b|\bnan(?::0x[\da-fA-F](?:_?[\da-fA-D])*)?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 11 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 12 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 13 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 14 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 15 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+];
+
+static YAML: &[Mode] = &[
+    /* 0 */ Mode { contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22], ..MODE },
+    /* 1 */ Mode { scope: Some(r"attr"), begin: "\\w[\\w :\\/.-]*:(?=[ \u{9}]|$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 2 */ Mode { scope: Some(r"attr"), begin: "\"\\w[\\w :\\/.-]*\":(?=[ \u{9}]|$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 3 */ Mode { scope: Some(r"attr"), begin: "'\\w[\\w :\\/.-]*':(?=[ \u{9}]|$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 4 */ Mode { scope: Some(r"meta"), begin: r"^---\s*$", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 10.0, ..MODE },
+    /* 5 */ Mode { scope: Some(r"string"), begin: r"[\|>]([1-9]?[+-])?[ ]*\n( +)[^ ][^\n]*\n(\2[^\n]+\n?)*", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 6 */ Mode { begin: r"<%[%=-]?", end: Some(r"[%-]?%>"), terminator_end: r"[%-]?%>", relevance: 0.0, sub_language: Some(SubLanguage::One(r"ruby")), exclude_begin: true, exclude_end: true, ..MODE },
+    /* 7 */ Mode { scope: Some(r"type"), begin: r"!\w+![\w#;/?:@&=+$,.~*'()[\]]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 8 */ Mode { scope: Some(r"type"), begin: r"!<[\w#;/?:@&=+$,.~*'()[\]]+>", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 9 */ Mode { scope: Some(r"type"), begin: r"![\w#;/?:@&=+$,.~*'()[\]]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 10 */ Mode { scope: Some(r"type"), begin: r"!![\w#;/?:@&=+$,.~*'()[\]]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 11 */ Mode { scope: Some(r"meta"), begin: r"&[a-zA-Z_]\w*$", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 12 */ Mode { scope: Some(r"meta"), begin: r"\*[a-zA-Z_]\w*$", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 13 */ Mode { scope: Some(r"bullet"), begin: r"-(?=[ ]|$)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 14 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[23, 24], ..MODE },
+    /* 15 */ Mode { begin: r"\b(true|false|yes|no|null)(?!\.)(?=\b|\s)", end: Some(r"\B|\b"), terminator_end: r"\B|\b", keywords: Some(KEYWORDS_79), relevance: 0.0, before_begin: Some(Callback::IgnoreAfterDot), ..MODE },
+    /* 16 */ Mode { scope: Some(r"number"), begin: r"\b[0-9]{4}(-[0-9][0-9]){0,2}([Tt \t][0-9][0-9]?(:[0-9][0-9]){2})?(\.[0-9]*)?([ \t])*(Z|[-+][0-9][0-9]?(:[0-9][0-9])?)?\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 17 */ Mode { scope: Some(r"number"), begin: r"(-?)(\b0[xX][a-fA-F0-9]+|(\b\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?)\b", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 18 */ Mode { begin: r"\{", end: Some(r"\}"), terminator_end: r"\}", illegal: Some(r"\n"), contains: &[25], relevance: 0.0, ..MODE },
+    /* 19 */ Mode { begin: r"\[", end: Some(r"\]"), terminator_end: r"\]", illegal: Some(r"\n"), contains: &[26], relevance: 0.0, ..MODE },
+    /* 20 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[27, 28, 29], relevance: 0.0, ..MODE },
+    /* 21 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[30, 28, 29], relevance: 0.0, ..MODE },
+    /* 22 */ Mode { scope: Some(r"string"), begin: r"\S+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[31, 28, 29], relevance: 0.0, ..MODE },
+    /* 23 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 24 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 25 */ Mode { begin: r"\B|\b", end: Some(r","), terminator_end: r",|\}", keywords: Some(KEYWORDS_80), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 32, 15, 16, 17, 18, 19, 33, 34, 35], relevance: 0.0, exclude_end: true, ends_with_parent: true, ..MODE },
+    /* 26 */ Mode { begin: r"\B|\b", end: Some(r","), terminator_end: r",|\]", keywords: Some(KEYWORDS_80), contains: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 36, 15, 16, 17, 18, 19, 33, 34, 35], relevance: 0.0, exclude_end: true, ends_with_parent: true, ..MODE },
+    /* 27 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 28 */ Mode { scope: Some(r"template-variable"), begin: r"\{\{", end: Some(r"\}\}"), terminator_end: r"\}\}", ..MODE },
+    /* 29 */ Mode { scope: Some(r"template-variable"), begin: r"%\{", end: Some(r"\}"), terminator_end: r"\}", ..MODE },
+    /* 30 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 31 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 32 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[37, 38], ..MODE },
+    /* 33 */ Mode { scope: Some(r"string"), begin: r"'", end: Some(r"'"), terminator_end: r"'", contains: &[39, 28, 29], relevance: 0.0, ..MODE },
+    /* 34 */ Mode { scope: Some(r"string"), begin: r#"""#, end: Some(r#"""#), terminator_end: r#"""#, contains: &[40, 28, 29], relevance: 0.0, ..MODE },
+    /* 35 */ Mode { scope: Some(r"string"), begin: r"[^\s,{}[\]]+", end: Some(r"\B|\b"), terminator_end: r"\B|\b", contains: &[41, 28, 29], relevance: 0.0, ..MODE },
+    /* 36 */ Mode { scope: Some(r"comment"), begin: r"#", end: Some(r"$"), terminator_end: r"$", contains: &[42, 43], ..MODE },
+    /* 37 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 38 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+    /* 39 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 40 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 41 */ Mode { begin: r"\\[\s\S]", end: Some(r"\B|\b"), terminator_end: r"\B|\b", relevance: 0.0, ..MODE },
+    /* 42 */ Mode { scope: Some(r"doctag"), begin: r"[ ]*(?=(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):)", end: Some(r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):"), terminator_end: r"(TODO|FIXME|NOTE|BUG|OPTIMIZE|HACK|XXX):", relevance: 0.0, exclude_begin: true, ..MODE },
+    /* 43 */ Mode { begin: r"[ ]+((?:I|a|is|so|us|to|at|if|in|it|on|[A-Za-z]+['](d|ve|re|ll|t|s|n)|[A-Za-z]+[-][a-z]+|[A-Za-z][a-z]{2,})[.]?[:]?([.][ ]|[ ])){3}", end: Some(r"\B|\b"), terminator_end: r"\B|\b", ..MODE },
+];
+
+
+static KEYWORDS_0: &[(&str, &str, u8)] = &[(r"alias", r"built_in", 1), (r"arch", r"built_in", 1), (r"autoload", r"built_in", 1), (r"b2sum", r"built_in", 1), (r"base32", r"built_in", 1), (r"base64", r"built_in", 1), (r"basename", r"built_in", 1), (r"bg", r"built_in", 1), (r"bind", r"built_in", 1), (r"bindkey", r"built_in", 1), (r"break", r"built_in", 1), (r"builtin", r"built_in", 1), (r"bye", r"built_in", 1), (r"caller", r"built_in", 1), (r"cap", r"built_in", 1), (r"case", r"keyword", 1), (r"cat", r"built_in", 1), (r"cd", r"built_in", 1), (r"chcon", r"built_in", 1), (r"chdir", r"built_in", 1), (r"chgrp", r"built_in", 1), (r"chmod", r"built_in", 1), (r"chown", r"built_in", 1), (r"chroot", r"built_in", 1), (r"cksum", r"built_in", 1), (r"clone", r"built_in", 1), (r"comm", r"built_in", 1), (r"command", r"built_in", 1), (r"comparguments", r"built_in", 1), (r"compcall", r"built_in", 1), (r"compctl", r"built_in", 1), (r"compdescribe", r"built_in", 1), (r"compfiles", r"built_in", 1), (r"compgroups", r"built_in", 1), (r"compquote", r"built_in", 1), (r"comptags", r"built_in", 1), (r"comptry", r"built_in", 1), (r"compvalues", r"built_in", 1), (r"continue", r"built_in", 1), (r"cp", r"built_in", 1), (r"csplit", r"built_in", 1), (r"cut", r"built_in", 1), (r"date", r"built_in", 1), (r"dd", r"built_in", 1), (r"declare", r"built_in", 1), (r"df", r"built_in", 1), (r"dir", r"built_in", 1), (r"dircolors", r"built_in", 1), (r"dirname", r"built_in", 1), (r"dirs", r"built_in", 1), (r"disable", r"built_in", 1), (r"disown", r"built_in", 1), (r"do", r"keyword", 1), (r"done", r"keyword", 1), (r"du", r"built_in", 1), (r"echo", r"built_in", 1), (r"echotc", r"built_in", 1), (r"echoti", r"built_in", 1), (r"elif", r"keyword", 1), (r"else", r"keyword", 1), (r"emulate", r"built_in", 1), (r"enable", r"built_in", 1), (r"env", r"built_in", 1), (r"esac", r"keyword", 1), (r"eval", r"built_in", 1), (r"exec", r"built_in", 1), (r"exit", r"built_in", 1), (r"expand", r"built_in", 1), (r"export", r"built_in", 1), (r"expr", r"built_in", 1), (r"factor", r"built_in", 1), (r"false", r"literal", 1), (r"fc", r"built_in", 1), (r"fg", r"built_in", 1), (r"fi", r"keyword", 1), (r"float", r"built_in", 1), (r"fmt", r"built_in", 1), (r"fold", r"built_in", 1), (r"for", r"keyword", 0), (r"function", r"keyword", 1), (r"functions", r"built_in", 1), (r"getcap", r"built_in", 1), (r"getln", r"built_in", 1), (r"getopts", r"built_in", 1), (r"groups", r"built_in", 1), (r"hash", r"built_in", 1), (r"head", r"built_in", 1), (r"help", r"built_in", 1), (r"history", r"built_in", 1), (r"hostid", r"built_in", 1), (r"id", r"built_in", 1), (r"if", r"keyword", 0), (r"in", r"keyword", 0), (r"integer", r"built_in", 1), (r"jobs", r"built_in", 1), (r"join", r"built_in", 1), (r"kill", r"built_in", 1), (r"let", r"built_in", 1), (r"limit", r"built_in", 1), (r"link", r"built_in", 1), (r"ln", r"built_in", 1), (r"local", r"built_in", 1), (r"log", r"built_in", 1), (r"logname", r"built_in", 1), (r"logout", r"built_in", 1), (r"ls", r"built_in", 1), (r"mapfile", r"built_in", 1), (r"md5sum", r"built_in", 1), (r"mkdir", r"built_in", 1), (r"mkfifo", r"built_in", 1), (r"mknod", r"built_in", 1), (r"mktemp", r"built_in", 1), (r"mv", r"built_in", 1), (r"nice", r"built_in", 1), (r"nl", r"built_in", 1), (r"noglob", r"built_in", 1), (r"nohup", r"built_in", 1), (r"nproc", r"built_in", 1), (r"numfmt", r"built_in", 1), (r"od", r"built_in", 1), (r"paste", r"built_in", 1), (r"pathchk", r"built_in", 1), (r"pinky", r"built_in", 1), (r"popd", r"built_in", 1), (r"pr", r"built_in", 1), (r"print", r"built_in", 1), (r"printenv", r"built_in", 1), (r"printf", r"built_in", 1), (r"ptx", r"built_in", 1), (r"pushd", r"built_in", 1), (r"pushln", r"built_in", 1), (r"pwd", r"built_in", 1), (r"read", r"built_in", 1), (r"readarray", r"built_in", 1), (r"readlink", r"built_in", 1), (r"readonly", r"built_in", 1), (r"realpath", r"built_in", 1), (r"rehash", r"built_in", 1), (r"return", r"built_in", 1), (r"rm", r"built_in", 1), (r"rmdir", r"built_in", 1), (r"runcon", r"built_in", 1), (r"sched", r"built_in", 1), (r"seq", r"built_in", 1), (r"set", r"built_in", 1), (r"setcap", r"built_in", 1), (r"setopt", r"built_in", 1), (r"sha1sum", r"built_in", 1), (r"sha224sum", r"built_in", 1), (r"sha256sum", r"built_in", 1), (r"sha384sum", r"built_in", 1), (r"sha512sum", r"built_in", 1), (r"shift", r"built_in", 1), (r"shopt", r"built_in", 1), (r"shred", r"built_in", 1), (r"shuf", r"built_in", 1), (r"sleep", r"built_in", 1), (r"sort", r"built_in", 1), (r"source", r"built_in", 1), (r"split", r"built_in", 1), (r"stat", r"built_in", 1), (r"stdbuf", r"built_in", 1), (r"stty", r"built_in", 1), (r"sum", r"built_in", 1), (r"suspend", r"built_in", 1), (r"sync", r"built_in", 1), (r"tac", r"built_in", 1), (r"tail", r"built_in", 1), (r"tee", r"built_in", 1), (r"test", r"built_in", 1), (r"then", r"keyword", 0), (r"timeout", r"built_in", 1), (r"times", r"built_in", 1), (r"touch", r"built_in", 1), (r"tr", r"built_in", 1), (r"trap", r"built_in", 1), (r"true", r"literal", 1), (r"truncate", r"built_in", 1), (r"tsort", r"built_in", 1), (r"tty", r"built_in", 1), (r"ttyctl", r"built_in", 1), (r"type", r"built_in", 1), (r"typeset", r"built_in", 1), (r"ulimit", r"built_in", 1), (r"umask", r"built_in", 1), (r"unalias", r"built_in", 1), (r"uname", r"built_in", 1), (r"unexpand", r"built_in", 1), (r"unfunction", r"built_in", 1), (r"unhash", r"built_in", 1), (r"uniq", r"built_in", 1), (r"unlimit", r"built_in", 1), (r"unlink", r"built_in", 1), (r"unset", r"built_in", 1), (r"unsetopt", r"built_in", 1), (r"uptime", r"built_in", 1), (r"users", r"built_in", 1), (r"vared", r"built_in", 1), (r"vdir", r"built_in", 1), (r"wait", r"built_in", 1), (r"wc", r"built_in", 1), (r"whence", r"built_in", 1), (r"where", r"built_in", 1), (r"which", r"built_in", 1), (r"while", r"keyword", 1), (r"who", r"built_in", 1), (r"whoami", r"built_in", 1), (r"yes", r"built_in", 1), (r"zcompile", r"built_in", 1), (r"zformat", r"built_in", 1), (r"zftp", r"built_in", 1), (r"zle", r"built_in", 1), (r"zmodload", r"built_in", 1), (r"zparseopts", r"built_in", 1), (r"zprof", r"built_in", 1), (r"zpty", r"built_in", 1), (r"zregexparse", r"built_in", 1), (r"zsocket", r"built_in", 1), (r"zstyle", r"built_in", 1), (r"ztcp", r"built_in", 1)];
+
+static KEYWORDS_1: &[(&str, &str, u8)] = &[(r"NULL", r"literal", 1), (r"_Alignas", r"keyword", 1), (r"_Alignof", r"keyword", 1), (r"_Atomic", r"keyword", 1), (r"_Bool", r"type", 1), (r"_Complex", r"type", 1), (r"_Decimal128", r"type", 1), (r"_Decimal32", r"type", 1), (r"_Decimal64", r"type", 1), (r"_Generic", r"keyword", 1), (r"_Imaginary", r"type", 1), (r"_Noreturn", r"keyword", 1), (r"_Pragma", r"keyword", 1), (r"_Static_assert", r"keyword", 1), (r"_Thread_local", r"keyword", 1), (r"abort", r"built_in", 1), (r"abs", r"built_in", 1), (r"acos", r"built_in", 1), (r"alignas", r"keyword", 1), (r"alignof", r"keyword", 1), (r"array", r"built_in", 1), (r"asin", r"built_in", 1), (r"asm", r"keyword", 1), (r"atan", r"built_in", 1), (r"atan2", r"built_in", 1), (r"auto", r"keyword", 1), (r"auto_ptr", r"built_in", 1), (r"bitset", r"built_in", 1), (r"bool", r"type", 1), (r"break", r"keyword", 1), (r"calloc", r"built_in", 1), (r"case", r"keyword", 1), (r"ceil", r"built_in", 1), (r"cerr", r"built_in", 1), (r"char", r"type", 1), (r"cin", r"built_in", 1), (r"clog", r"built_in", 1), (r"complex", r"type", 1), (r"const", r"type", 1), (r"continue", r"keyword", 1), (r"cos", r"built_in", 1), (r"cosh", r"built_in", 1), (r"cout", r"built_in", 1), (r"default", r"keyword", 1), (r"deque", r"built_in", 1), (r"do", r"keyword", 1), (r"double", r"type", 1), (r"else", r"keyword", 1), (r"endl", r"built_in", 1), (r"enum", r"keyword", 1), (r"exit", r"built_in", 1), (r"exp", r"built_in", 1), (r"extern", r"keyword", 1), (r"fabs", r"built_in", 1), (r"false", r"literal", 1), (r"float", r"type", 1), (r"floor", r"built_in", 1), (r"fmod", r"built_in", 1), (r"for", r"keyword", 0), (r"fortran", r"keyword", 1), (r"fprintf", r"built_in", 1), (r"fputs", r"built_in", 1), (r"free", r"built_in", 1), (r"frexp", r"built_in", 1), (r"fscanf", r"built_in", 1), (r"future", r"built_in", 1), (r"goto", r"keyword", 1), (r"if", r"keyword", 0), (r"imaginary", r"type", 1), (r"initializer_list", r"built_in", 1), (r"inline", r"keyword", 1), (r"int", r"type", 1), (r"isalnum", r"built_in", 1), (r"isalpha", r"built_in", 1), (r"iscntrl", r"built_in", 1), (r"isdigit", r"built_in", 1), (r"isgraph", r"built_in", 1), (r"islower", r"built_in", 1), (r"isprint", r"built_in", 1), (r"ispunct", r"built_in", 1), (r"isspace", r"built_in", 1), (r"istringstream", r"built_in", 1), (r"isupper", r"built_in", 1), (r"isxdigit", r"built_in", 1), (r"labs", r"built_in", 1), (r"ldexp", r"built_in", 1), (r"list", r"built_in", 0), (r"log", r"built_in", 1), (r"log10", r"built_in", 1), (r"long", r"type", 1), (r"make_pair", r"built_in", 1), (r"malloc", r"built_in", 1), (r"map", r"built_in", 1), (r"memchr", r"built_in", 1), (r"memcmp", r"built_in", 1), (r"memcpy", r"built_in", 1), (r"memset", r"built_in", 1), (r"modf", r"built_in", 1), (r"multimap", r"built_in", 1), (r"multiset", r"built_in", 1), (r"noreturn", r"keyword", 1), (r"ostringstream", r"built_in", 1), (r"pair", r"built_in", 1), (r"pow", r"built_in", 1), (r"printf", r"built_in", 1), (r"priority_queue", r"built_in", 1), (r"putchar", r"built_in", 1), (r"puts", r"built_in", 1), (r"queue", r"built_in", 1), (r"realloc", r"built_in", 1), (r"register", r"keyword", 1), (r"restrict", r"keyword", 1), (r"return", r"keyword", 1), (r"scanf", r"built_in", 1), (r"set", r"built_in", 1), (r"shared_ptr", r"built_in", 1), (r"short", r"type", 1), (r"signed", r"type", 1), (r"sin", r"built_in", 1), (r"sinh", r"built_in", 1), (r"sizeof", r"keyword", 1), (r"snprintf", r"built_in", 1), (r"sprintf", r"built_in", 1), (r"sqrt", r"built_in", 1), (r"sscanf", r"built_in", 1), (r"stack", r"built_in", 1), (r"static", r"type", 1), (r"static_assert", r"keyword", 1), (r"std", r"built_in", 1), (r"stderr", r"built_in", 1), (r"stdin", r"built_in", 1), (r"stdout", r"built_in", 1), (r"strcat", r"built_in", 1), (r"strchr", r"built_in", 1), (r"strcmp", r"built_in", 1), (r"strcpy", r"built_in", 1), (r"strcspn", r"built_in", 1), (r"string", r"built_in", 1), (r"stringstream", r"built_in", 1), (r"strlen", r"built_in", 1), (r"strncat", r"built_in", 1), (r"strncmp", r"built_in", 1), (r"strncpy", r"built_in", 1), (r"strpbrk", r"built_in", 1), (r"strrchr", r"built_in", 1), (r"strspn", r"built_in", 1), (r"strstr", r"built_in", 1), (r"struct", r"keyword", 1), (r"switch", r"keyword", 1), (r"tan", r"built_in", 1), (r"tanh", r"built_in", 1), (r"terminate", r"built_in", 1), (r"thread_local", r"keyword", 1), (r"tolower", r"built_in", 1), (r"toupper", r"built_in", 1), (r"true", r"literal", 1), (r"typedef", r"keyword", 1), (r"union", r"keyword", 1), (r"unique_ptr", r"built_in", 1), (r"unordered_map", r"built_in", 1), (r"unordered_multimap", r"built_in", 1), (r"unordered_multiset", r"built_in", 1), (r"unordered_set", r"built_in", 1), (r"unsigned", r"type", 1), (r"vector", r"built_in", 1), (r"vfprintf", r"built_in", 1), (r"void", r"type", 1), (r"volatile", r"keyword", 1), (r"vprintf", r"built_in", 1), (r"vsprintf", r"built_in", 1), (r"while", r"keyword", 1), (r"wstring", r"built_in", 1)];
+
+static KEYWORDS_2: &[(&str, &str, u8)] = &[(r"_Pragma", r"keyword", 1), (r"define", r"keyword", 1), (r"elif", r"keyword", 1), (r"else", r"keyword", 1), (r"endif", r"keyword", 1), (r"error", r"keyword", 1), (r"if", r"keyword", 0), (r"ifdef", r"keyword", 1), (r"ifndef", r"keyword", 1), (r"include", r"keyword", 1), (r"line", r"keyword", 1), (r"pragma", r"keyword", 1), (r"undef", r"keyword", 1), (r"warning", r"keyword", 1)];
+
+static KEYWORDS_3: &[(&str, &str, u8)] = &[(r"class", r"keyword", 1), (r"enum", r"keyword", 1), (r"struct", r"keyword", 1), (r"union", r"keyword", 1)];
+
+static KEYWORDS_4: &[(&str, &str, u8)] = &[(r"class", r"keyword", 1), (r"final", r"keyword", 1), (r"struct", r"keyword", 1)];
+
+static KEYWORDS_5: &[(&str, &str, u8)] = &[(r"NULL", r"literal", 1), (r"_Pragma", r"built_in", 1), (r"alignas", r"keyword", 1), (r"alignof", r"keyword", 1), (r"and", r"keyword", 0), (r"and_eq", r"keyword", 1), (r"any", r"_type_hints", 1), (r"asm", r"keyword", 1), (r"atomic_cancel", r"keyword", 1), (r"atomic_commit", r"keyword", 1), (r"atomic_noexcept", r"keyword", 1), (r"auto", r"keyword", 1), (r"auto_ptr", r"_type_hints", 1), (r"barrier", r"_type_hints", 1), (r"binary_semaphore", r"_type_hints", 1), (r"bitand", r"keyword", 1), (r"bitor", r"keyword", 1), (r"bitset", r"_type_hints", 1), (r"bool", r"type", 1), (r"break", r"keyword", 1), (r"case", r"keyword", 1), (r"catch", r"keyword", 1), (r"char", r"type", 1), (r"char16_t", r"type", 1), (r"char32_t", r"type", 1), (r"char8_t", r"type", 1), (r"class", r"keyword", 1), (r"co_await", r"keyword", 1), (r"co_return", r"keyword", 1), (r"co_yield", r"keyword", 1), (r"compl", r"keyword", 1), (r"complex", r"_type_hints", 1), (r"concept", r"keyword", 1), (r"condition_variable", r"_type_hints", 1), (r"condition_variable_any", r"_type_hints", 1), (r"const", r"type", 1), (r"const_cast", r"keyword", 10), (r"consteval", r"keyword", 1), (r"constexpr", r"keyword", 1), (r"constinit", r"keyword", 1), (r"continue", r"keyword", 1), (r"counting_semaphore", r"_type_hints", 1), (r"decltype", r"keyword", 1), (r"default", r"keyword", 1), (r"delete", r"keyword", 1), (r"deque", r"_type_hints", 1), (r"do", r"keyword", 1), (r"double", r"type", 1), (r"dynamic_cast", r"keyword", 10), (r"else", r"keyword", 1), (r"enum", r"keyword", 1), (r"explicit", r"keyword", 1), (r"export", r"keyword", 1), (r"extern", r"keyword", 1), (r"false", r"literal", 1), (r"false_type", r"_type_hints", 1), (r"final", r"keyword", 1), (r"float", r"type", 1), (r"for", r"keyword", 0), (r"friend", r"keyword", 1), (r"future", r"_type_hints", 1), (r"goto", r"keyword", 1), (r"if", r"keyword", 0), (r"imaginary", r"_type_hints", 1), (r"import", r"keyword", 1), (r"initializer_list", r"_type_hints", 1), (r"inline", r"keyword", 1), (r"int", r"type", 1), (r"istringstream", r"_type_hints", 1), (r"jthread", r"_type_hints", 1), (r"latch", r"_type_hints", 1), (r"lock_guard", r"_type_hints", 1), (r"long", r"type", 1), (r"module", r"keyword", 1), (r"multimap", r"_type_hints", 1), (r"multiset", r"_type_hints", 1), (r"mutable", r"keyword", 1), (r"mutex", r"_type_hints", 1), (r"namespace", r"keyword", 1), (r"new", r"keyword", 1), (r"noexcept", r"keyword", 1), (r"not", r"keyword", 0), (r"not_eq", r"keyword", 1), (r"nullopt", r"literal", 1), (r"nullptr", r"literal", 1), (r"operator", r"keyword", 1), (r"optional", r"_type_hints", 1), (r"or", r"keyword", 0), (r"or_eq", r"keyword", 1), (r"ostringstream", r"_type_hints", 1), (r"override", r"keyword", 1), (r"packaged_task", r"_type_hints", 1), (r"pair", r"_type_hints", 1), (r"priority_queue", r"_type_hints", 1), (r"private", r"keyword", 1), (r"promise", r"_type_hints", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"queue", r"_type_hints", 1), (r"recursive_mutex", r"_type_hints", 1), (r"recursive_timed_mutex", r"_type_hints", 1), (r"reflexpr", r"keyword", 1), (r"register", r"keyword", 1), (r"reinterpret_cast", r"keyword", 10), (r"requires", r"keyword", 1), (r"return", r"keyword", 1), (r"scoped_lock", r"_type_hints", 1), (r"set", r"_type_hints", 1), (r"shared_future", r"_type_hints", 1), (r"shared_lock", r"_type_hints", 1), (r"shared_mutex", r"_type_hints", 1), (r"shared_ptr", r"_type_hints", 1), (r"shared_timed_mutex", r"_type_hints", 1), (r"short", r"type", 1), (r"signed", r"type", 1), (r"sizeof", r"keyword", 1), (r"stack", r"_type_hints", 1), (r"static", r"type", 1), (r"static_assert", r"keyword", 1), (r"static_cast", r"keyword", 10), (r"string_view", r"_type_hints", 1), (r"stringstream", r"_type_hints", 1), (r"struct", r"keyword", 1), (r"switch", r"keyword", 1), (r"synchronized", r"keyword", 1), (r"template", r"keyword", 1), (r"this", r"keyword", 1), (r"thread", r"_type_hints", 1), (r"thread_local", r"keyword", 1), (r"throw", r"keyword", 1), (r"timed_mutex", r"_type_hints", 1), (r"transaction_safe", r"keyword", 1), (r"transaction_safe_dynamic", r"keyword", 1), (r"true", r"literal", 1), (r"true_type", r"_type_hints", 1), (r"try", r"keyword", 1), (r"tuple", r"_type_hints", 1), (r"typedef", r"keyword", 1), (r"typeid", r"keyword", 1), (r"typename", r"keyword", 1), (r"union", r"keyword", 1), (r"unique_lock", r"_type_hints", 1), (r"unique_ptr", r"_type_hints", 1), (r"unordered_map", r"_type_hints", 1), (r"unordered_multimap", r"_type_hints", 1), (r"unordered_multiset", r"_type_hints", 1), (r"unordered_set", r"_type_hints", 1), (r"unsigned", r"type", 1), (r"using", r"keyword", 1), (r"variant", r"_type_hints", 1), (r"vector", r"_type_hints", 1), (r"virtual", r"keyword", 1), (r"void", r"type", 1), (r"volatile", r"keyword", 1), (r"wchar_t", r"type", 1), (r"weak_ptr", r"_type_hints", 1), (r"while", r"keyword", 1), (r"wstring", r"_type_hints", 1), (r"wstring_view", r"_type_hints", 1), (r"xor", r"keyword", 1), (r"xor_eq", r"keyword", 1)];
+
+static KEYWORDS_6: &[(&str, &str, u8)] = &[(r"abort", r"_hint", 1), (r"abs", r"_hint", 1), (r"acos", r"_hint", 1), (r"apply", r"_hint", 1), (r"as_const", r"_hint", 1), (r"asin", r"_hint", 1), (r"atan", r"_hint", 1), (r"atan2", r"_hint", 1), (r"calloc", r"_hint", 1), (r"ceil", r"_hint", 1), (r"cerr", r"_hint", 1), (r"cin", r"_hint", 1), (r"clog", r"_hint", 1), (r"cos", r"_hint", 1), (r"cosh", r"_hint", 1), (r"cout", r"_hint", 1), (r"declval", r"_hint", 1), (r"endl", r"_hint", 1), (r"exchange", r"_hint", 1), (r"exit", r"_hint", 1), (r"exp", r"_hint", 1), (r"fabs", r"_hint", 1), (r"floor", r"_hint", 1), (r"fmod", r"_hint", 1), (r"forward", r"_hint", 1), (r"fprintf", r"_hint", 1), (r"fputs", r"_hint", 1), (r"free", r"_hint", 1), (r"frexp", r"_hint", 1), (r"fscanf", r"_hint", 1), (r"future", r"_hint", 1), (r"invoke", r"_hint", 1), (r"isalnum", r"_hint", 1), (r"isalpha", r"_hint", 1), (r"iscntrl", r"_hint", 1), (r"isdigit", r"_hint", 1), (r"isgraph", r"_hint", 1), (r"islower", r"_hint", 1), (r"isprint", r"_hint", 1), (r"ispunct", r"_hint", 1), (r"isspace", r"_hint", 1), (r"isupper", r"_hint", 1), (r"isxdigit", r"_hint", 1), (r"labs", r"_hint", 1), (r"launder", r"_hint", 1), (r"ldexp", r"_hint", 1), (r"log", r"_hint", 1), (r"log10", r"_hint", 1), (r"make_pair", r"_hint", 1), (r"make_shared", r"_hint", 1), (r"make_shared_for_overwrite", r"_hint", 1), (r"make_tuple", r"_hint", 1), (r"make_unique", r"_hint", 1), (r"malloc", r"_hint", 1), (r"memchr", r"_hint", 1), (r"memcmp", r"_hint", 1), (r"memcpy", r"_hint", 1), (r"memset", r"_hint", 1), (r"modf", r"_hint", 1), (r"move", r"_hint", 1), (r"pow", r"_hint", 1), (r"printf", r"_hint", 1), (r"putchar", r"_hint", 1), (r"puts", r"_hint", 1), (r"realloc", r"_hint", 1), (r"scanf", r"_hint", 1), (r"sin", r"_hint", 1), (r"sinh", r"_hint", 1), (r"snprintf", r"_hint", 1), (r"sprintf", r"_hint", 1), (r"sqrt", r"_hint", 1), (r"sscanf", r"_hint", 1), (r"std", r"_hint", 1), (r"stderr", r"_hint", 1), (r"stdin", r"_hint", 1), (r"stdout", r"_hint", 1), (r"strcat", r"_hint", 1), (r"strchr", r"_hint", 1), (r"strcmp", r"_hint", 1), (r"strcpy", r"_hint", 1), (r"strcspn", r"_hint", 1), (r"strlen", r"_hint", 1), (r"strncat", r"_hint", 1), (r"strncmp", r"_hint", 1), (r"strncpy", r"_hint", 1), (r"strpbrk", r"_hint", 1), (r"strrchr", r"_hint", 1), (r"strspn", r"_hint", 1), (r"strstr", r"_hint", 1), (r"swap", r"_hint", 1), (r"tan", r"_hint", 1), (r"tanh", r"_hint", 1), (r"terminate", r"_hint", 1), (r"to_underlying", r"_hint", 1), (r"tolower", r"_hint", 1), (r"toupper", r"_hint", 1), (r"vfprintf", r"_hint", 1), (r"visit", r"_hint", 1), (r"vprintf", r"_hint", 1), (r"vsprintf", r"_hint", 1)];
+
+static KEYWORDS_7: &[(&str, &str, u8)] = &[(r"abstract", r"keyword", 1), (r"add", r"keyword", 1), (r"alias", r"keyword", 1), (r"and", r"keyword", 0), (r"as", r"keyword", 1), (r"ascending", r"keyword", 1), (r"async", r"keyword", 1), (r"await", r"keyword", 1), (r"base", r"keyword", 1), (r"bool", r"built_in", 1), (r"break", r"keyword", 1), (r"by", r"keyword", 1), (r"byte", r"built_in", 1), (r"case", r"keyword", 1), (r"catch", r"keyword", 1), (r"char", r"built_in", 1), (r"class", r"keyword", 1), (r"const", r"keyword", 1), (r"continue", r"keyword", 1), (r"decimal", r"built_in", 1), (r"default", r"literal", 1), (r"delegate", r"built_in", 1), (r"descending", r"keyword", 1), (r"do", r"keyword", 1), (r"double", r"built_in", 1), (r"dynamic", r"built_in", 1), (r"else", r"keyword", 1), (r"enum", r"built_in", 1), (r"equals", r"keyword", 1), (r"event", r"keyword", 1), (r"explicit", r"keyword", 1), (r"extern", r"keyword", 1), (r"false", r"literal", 1), (r"finally", r"keyword", 1), (r"fixed", r"keyword", 1), (r"float", r"built_in", 1), (r"for", r"keyword", 0), (r"foreach", r"keyword", 1), (r"from", r"keyword", 1), (r"get", r"keyword", 1), (r"global", r"keyword", 1), (r"goto", r"keyword", 1), (r"group", r"keyword", 1), (r"if", r"keyword", 0), (r"implicit", r"keyword", 1), (r"in", r"keyword", 0), (r"init", r"keyword", 1), (r"int", r"built_in", 1), (r"interface", r"keyword", 1), (r"internal", r"keyword", 1), (r"into", r"keyword", 1), (r"is", r"keyword", 1), (r"join", r"keyword", 1), (r"let", r"keyword", 1), (r"lock", r"keyword", 1), (r"long", r"built_in", 1), (r"nameof", r"keyword", 1), (r"namespace", r"keyword", 1), (r"new", r"keyword", 1), (r"nint", r"built_in", 1), (r"not", r"keyword", 0), (r"notnull", r"keyword", 1), (r"nuint", r"built_in", 1), (r"null", r"literal", 1), (r"object", r"built_in", 1), (r"on", r"keyword", 1), (r"operator", r"keyword", 1), (r"or", r"keyword", 0), (r"orderby", r"keyword", 1), (r"out", r"keyword", 1), (r"override", r"keyword", 1), (r"params", r"keyword", 1), (r"partial", r"keyword", 1), (r"private", r"keyword", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"readonly", r"keyword", 1), (r"record", r"keyword", 1), (r"ref", r"keyword", 1), (r"remove", r"keyword", 1), (r"return", r"keyword", 1), (r"sbyte", r"built_in", 1), (r"scoped", r"keyword", 1), (r"sealed", r"keyword", 1), (r"select", r"keyword", 1), (r"set", r"keyword", 1), (r"short", r"built_in", 1), (r"sizeof", r"keyword", 1), (r"stackalloc", r"keyword", 1), (r"static", r"keyword", 1), (r"string", r"built_in", 1), (r"struct", r"keyword", 1), (r"switch", r"keyword", 1), (r"this", r"keyword", 1), (r"throw", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"typeof", r"keyword", 1), (r"uint", r"built_in", 1), (r"ulong", r"built_in", 1), (r"unchecked", r"keyword", 1), (r"unmanaged", r"keyword", 1), (r"unsafe", r"keyword", 1), (r"ushort", r"built_in", 1), (r"using", r"keyword", 1), (r"value", r"keyword", 0), (r"var", r"keyword", 1), (r"virtual", r"keyword", 1), (r"void", r"keyword", 1), (r"volatile", r"keyword", 1), (r"when", r"keyword", 1), (r"where", r"keyword", 1), (r"while", r"keyword", 1), (r"with", r"keyword", 1), (r"yield", r"keyword", 1)];
+
+static KEYWORDS_8: &[(&str, &str, u8)] = &[(r"checksum", r"keyword", 1), (r"define", r"keyword", 1), (r"elif", r"keyword", 1), (r"else", r"keyword", 1), (r"endif", r"keyword", 1), (r"endregion", r"keyword", 1), (r"error", r"keyword", 1), (r"if", r"keyword", 0), (r"line", r"keyword", 1), (r"pragma", r"keyword", 1), (r"region", r"keyword", 1), (r"undef", r"keyword", 1), (r"warning", r"keyword", 1)];
+
+static KEYWORDS_9: &[(&str, &str, u8)] = &[(r"class", r"keyword", 1), (r"interface", r"keyword", 1)];
+
+static KEYWORDS_10: &[(&str, &str, u8)] = &[(r"namespace", r"keyword", 1)];
+
+static KEYWORDS_11: &[(&str, &str, u8)] = &[(r"record", r"keyword", 1)];
+
+static KEYWORDS_12: &[(&str, &str, u8)] = &[(r"await", r"keyword", 1), (r"else", r"keyword", 1), (r"new", r"keyword", 1), (r"return", r"keyword", 1), (r"throw", r"keyword", 1)];
+
+static KEYWORDS_13: &[(&str, &str, u8)] = &[(r"class", r"keyword", 1), (r"where", r"keyword", 1)];
+
+static KEYWORDS_14: &[(&str, &str, u8)] = &[(r"abstract", r"keyword", 1), (r"async", r"keyword", 1), (r"extern", r"keyword", 1), (r"internal", r"keyword", 1), (r"new", r"keyword", 1), (r"override", r"keyword", 1), (r"partial", r"keyword", 1), (r"private", r"keyword", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"sealed", r"keyword", 1), (r"static", r"keyword", 1), (r"unsafe", r"keyword", 1), (r"virtual", r"keyword", 1)];
+
+static KEYWORDS_15: &[(&str, &str, u8)] = &[(r"in", r"keyword", 0), (r"out", r"keyword", 1)];
+
+static KEYWORDS_16: &[(&str, &str, u8)] = &[(r"from", r"keyframePosition", 1), (r"to", r"keyframePosition", 1)];
+
+static KEYWORDS_17: &[(&str, &str, u8)] = &[(r"data-uri", r"built_in", 1), (r"url", r"built_in", 1)];
+
+static KEYWORDS_18: &[(&str, &str, u8)] = &[(r"and", r"keyword", 0), (r"any-hover", r"attribute", 1), (r"any-pointer", r"attribute", 1), (r"aspect-ratio", r"attribute", 1), (r"color", r"attribute", 1), (r"color-gamut", r"attribute", 1), (r"color-index", r"attribute", 1), (r"device-aspect-ratio", r"attribute", 1), (r"device-height", r"attribute", 1), (r"device-width", r"attribute", 1), (r"display-mode", r"attribute", 1), (r"forced-colors", r"attribute", 1), (r"grid", r"attribute", 1), (r"height", r"attribute", 1), (r"hover", r"attribute", 1), (r"inverted-colors", r"attribute", 1), (r"max-height", r"attribute", 1), (r"max-width", r"attribute", 1), (r"min-height", r"attribute", 1), (r"min-width", r"attribute", 1), (r"monochrome", r"attribute", 1), (r"not", r"keyword", 0), (r"only", r"keyword", 1), (r"or", r"keyword", 0), (r"orientation", r"attribute", 1), (r"overflow-block", r"attribute", 1), (r"overflow-inline", r"attribute", 1), (r"pointer", r"attribute", 1), (r"prefers-color-scheme", r"attribute", 1), (r"prefers-contrast", r"attribute", 1), (r"prefers-reduced-motion", r"attribute", 1), (r"prefers-reduced-transparency", r"attribute", 1), (r"resolution", r"attribute", 1), (r"scan", r"attribute", 1), (r"scripting", r"attribute", 1), (r"update", r"attribute", 1), (r"width", r"attribute", 1)];
+
+static KEYWORDS_19: &[(&str, &str, u8)] = &[(r"append", r"built_in", 1), (r"bool", r"type", 1), (r"break", r"keyword", 1), (r"byte", r"type", 1), (r"cap", r"built_in", 1), (r"case", r"keyword", 1), (r"chan", r"keyword", 1), (r"close", r"built_in", 1), (r"complex", r"built_in", 1), (r"complex128", r"type", 1), (r"complex64", r"type", 1), (r"const", r"keyword", 1), (r"continue", r"keyword", 1), (r"copy", r"built_in", 1), (r"default", r"keyword", 1), (r"defer", r"keyword", 1), (r"delete", r"built_in", 1), (r"else", r"keyword", 1), (r"error", r"type", 1), (r"fallthrough", r"keyword", 1), (r"false", r"literal", 1), (r"float32", r"type", 1), (r"float64", r"type", 1), (r"for", r"keyword", 0), (r"func", r"keyword", 1), (r"go", r"keyword", 1), (r"goto", r"keyword", 1), (r"if", r"keyword", 0), (r"imag", r"built_in", 1), (r"import", r"keyword", 1), (r"int", r"type", 1), (r"int16", r"type", 1), (r"int32", r"type", 1), (r"int64", r"type", 1), (r"int8", r"type", 1), (r"interface", r"keyword", 1), (r"iota", r"literal", 1), (r"len", r"built_in", 1), (r"make", r"built_in", 1), (r"map", r"keyword", 1), (r"new", r"built_in", 1), (r"nil", r"literal", 1), (r"package", r"keyword", 1), (r"panic", r"built_in", 1), (r"print", r"built_in", 1), (r"println", r"built_in", 1), (r"range", r"keyword", 1), (r"real", r"built_in", 1), (r"recover", r"built_in", 1), (r"return", r"keyword", 1), (r"rune", r"type", 1), (r"select", r"keyword", 1), (r"string", r"type", 1), (r"struct", r"keyword", 1), (r"switch", r"keyword", 1), (r"true", r"literal", 1), (r"type", r"keyword", 1), (r"uint", r"type", 1), (r"uint16", r"type", 1), (r"uint32", r"type", 1), (r"uint64", r"type", 1), (r"uint8", r"type", 1), (r"uintptr", r"type", 1), (r"var", r"keyword", 1)];
+
+static KEYWORDS_20: &[(&str, &str, u8)] = &[(r"func", r"keyword", 1)];
+
+static KEYWORDS_21: &[(&str, &str, u8)] = &[(r"directive", r"keyword", 1), (r"enum", r"keyword", 1), (r"false", r"literal", 1), (r"fragment", r"keyword", 1), (r"input", r"keyword", 1), (r"interface", r"keyword", 1), (r"mutation", r"keyword", 1), (r"null", r"literal", 1), (r"on", r"keyword", 1), (r"query", r"keyword", 1), (r"scalar", r"keyword", 1), (r"schema", r"keyword", 1), (r"subscription", r"keyword", 1), (r"true", r"literal", 1), (r"type", r"keyword", 1), (r"union", r"keyword", 1)];
+
+static KEYWORDS_22: &[(&str, &str, u8)] = &[(r"abstract", r"keyword", 1), (r"assert", r"keyword", 1), (r"boolean", r"type", 1), (r"break", r"keyword", 1), (r"byte", r"type", 1), (r"case", r"keyword", 1), (r"catch", r"keyword", 1), (r"char", r"type", 1), (r"const ", r"keyword", 1), (r"continue", r"keyword", 1), (r"default", r"keyword", 1), (r"do", r"keyword", 1), (r"double", r"type", 1), (r"else", r"keyword", 1), (r"enum", r"keyword", 1), (r"exports", r"keyword", 1), (r"false", r"literal", 1), (r"final", r"keyword", 1), (r"finally", r"keyword", 1), (r"float", r"type", 1), (r"for", r"keyword", 0), (r"if", r"keyword", 0), (r"import", r"keyword", 1), (r"instanceof", r"keyword", 1), (r"int", r"type", 1), (r"long", r"type", 1), (r"module", r"keyword", 1), (r"native", r"keyword", 1), (r"null", r"literal", 1), (r"package", r"keyword", 1), (r"permits", r"keyword", 1), (r"private", r"keyword", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"requires", r"keyword", 1), (r"sealed", r"keyword", 1), (r"short", r"type", 1), (r"static", r"keyword", 1), (r"strictfp", r"keyword", 1), (r"super", r"built_in", 1), (r"switch", r"keyword", 1), (r"synchronized", r"keyword", 1), (r"this", r"built_in", 1), (r"throws", r"keyword", 1), (r"transient", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"var", r"keyword", 1), (r"void", r"keyword", 1), (r"volatile", r"keyword", 1), (r"while", r"keyword", 1), (r"yield", r"keyword", 1)];
+
+static KEYWORDS_23: &[(&str, &str, u8)] = &[(r"import", r"keyword", 1)];
+
+static KEYWORDS_24: &[(&str, &str, u8)] = &[(r"else", r"keyword", 1), (r"new", r"keyword", 1), (r"return", r"keyword", 1), (r"throw", r"keyword", 1)];
+
+static KEYWORDS_25: &[(&str, &str, u8)] = &[(r"Array", r"built_in", 1), (r"ArrayBuffer", r"built_in", 1), (r"AsyncFunction", r"built_in", 1), (r"Atomics", r"built_in", 1), (r"BigInt", r"built_in", 1), (r"BigInt64Array", r"built_in", 1), (r"BigUint64Array", r"built_in", 1), (r"Boolean", r"built_in", 1), (r"DataView", r"built_in", 1), (r"Date", r"built_in", 1), (r"Error", r"built_in", 1), (r"EvalError", r"built_in", 1), (r"Float32Array", r"built_in", 1), (r"Float64Array", r"built_in", 1), (r"Function", r"built_in", 1), (r"Generator", r"built_in", 1), (r"GeneratorFunction", r"built_in", 1), (r"Infinity", r"literal", 1), (r"Int16Array", r"built_in", 1), (r"Int32Array", r"built_in", 1), (r"Int8Array", r"built_in", 1), (r"InternalError", r"built_in", 1), (r"Intl", r"built_in", 1), (r"JSON", r"built_in", 1), (r"Map", r"built_in", 1), (r"Math", r"built_in", 1), (r"NaN", r"literal", 1), (r"Number", r"built_in", 1), (r"Object", r"built_in", 1), (r"Promise", r"built_in", 1), (r"Proxy", r"built_in", 1), (r"RangeError", r"built_in", 1), (r"ReferenceError", r"built_in", 1), (r"Reflect", r"built_in", 1), (r"RegExp", r"built_in", 1), (r"Set", r"built_in", 1), (r"SharedArrayBuffer", r"built_in", 1), (r"String", r"built_in", 1), (r"Symbol", r"built_in", 1), (r"SyntaxError", r"built_in", 1), (r"TypeError", r"built_in", 1), (r"URIError", r"built_in", 1), (r"Uint16Array", r"built_in", 1), (r"Uint32Array", r"built_in", 1), (r"Uint8Array", r"built_in", 1), (r"Uint8ClampedArray", r"built_in", 1), (r"WeakMap", r"built_in", 1), (r"WeakSet", r"built_in", 1), (r"WebAssembly", r"built_in", 1), (r"arguments", r"variable.language", 1), (r"as", r"keyword", 1), (r"async", r"keyword", 1), (r"await", r"keyword", 1), (r"break", r"keyword", 1), (r"case", r"keyword", 1), (r"catch", r"keyword", 1), (r"class", r"keyword", 1), (r"clearInterval", r"built_in", 1), (r"clearTimeout", r"built_in", 1), (r"console", r"variable.language", 1), (r"const", r"keyword", 1), (r"continue", r"keyword", 1), (r"debugger", r"keyword", 1), (r"decodeURI", r"built_in", 1), (r"decodeURIComponent", r"built_in", 1), (r"default", r"keyword", 1), (r"delete", r"keyword", 1), (r"do", r"keyword", 1), (r"document", r"variable.language", 1), (r"else", r"keyword", 1), (r"encodeURI", r"built_in", 1), (r"encodeURIComponent", r"built_in", 1), (r"escape", r"built_in", 1), (r"eval", r"built_in", 1), (r"export", r"keyword", 1), (r"exports", r"built_in", 1), (r"extends", r"keyword", 1), (r"false", r"literal", 1), (r"finally", r"keyword", 1), (r"for", r"keyword", 0), (r"from", r"keyword", 1), (r"function", r"keyword", 1), (r"global", r"variable.language", 1), (r"if", r"keyword", 0), (r"import", r"keyword", 1), (r"in", r"keyword", 0), (r"instanceof", r"keyword", 1), (r"isFinite", r"built_in", 1), (r"isNaN", r"built_in", 1), (r"let", r"keyword", 1), (r"localStorage", r"variable.language", 1), (r"module", r"variable.language", 1), (r"new", r"keyword", 1), (r"null", r"literal", 1), (r"of", r"keyword", 0), (r"parseFloat", r"built_in", 1), (r"parseInt", r"built_in", 1), (r"require", r"built_in", 1), (r"return", r"keyword", 1), (r"setInterval", r"built_in", 1), (r"setTimeout", r"built_in", 1), (r"static", r"keyword", 1), (r"super", r"variable.language", 1), (r"switch", r"keyword", 1), (r"this", r"variable.language", 1), (r"throw", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"typeof", r"keyword", 1), (r"undefined", r"literal", 1), (r"unescape", r"built_in", 1), (r"var", r"keyword", 1), (r"void", r"keyword", 1), (r"while", r"keyword", 1), (r"window", r"variable.language", 1), (r"with", r"keyword", 1), (r"yield", r"keyword", 1)];
+
+static KEYWORDS_26: &[(&str, &str, u8)] = &[(r"Array", r"_", 1), (r"ArrayBuffer", r"_", 1), (r"AsyncFunction", r"_", 1), (r"Atomics", r"_", 1), (r"BigInt", r"_", 1), (r"BigInt64Array", r"_", 1), (r"BigUint64Array", r"_", 1), (r"Boolean", r"_", 1), (r"DataView", r"_", 1), (r"Date", r"_", 1), (r"Error", r"_", 1), (r"EvalError", r"_", 1), (r"Float32Array", r"_", 1), (r"Float64Array", r"_", 1), (r"Function", r"_", 1), (r"Generator", r"_", 1), (r"GeneratorFunction", r"_", 1), (r"Int16Array", r"_", 1), (r"Int32Array", r"_", 1), (r"Int8Array", r"_", 1), (r"InternalError", r"_", 1), (r"Intl", r"_", 1), (r"JSON", r"_", 1), (r"Map", r"_", 1), (r"Math", r"_", 1), (r"Number", r"_", 1), (r"Object", r"_", 1), (r"Promise", r"_", 1), (r"Proxy", r"_", 1), (r"RangeError", r"_", 1), (r"ReferenceError", r"_", 1), (r"Reflect", r"_", 1), (r"RegExp", r"_", 1), (r"Set", r"_", 1), (r"SharedArrayBuffer", r"_", 1), (r"String", r"_", 1), (r"Symbol", r"_", 1), (r"SyntaxError", r"_", 1), (r"TypeError", r"_", 1), (r"URIError", r"_", 1), (r"Uint16Array", r"_", 1), (r"Uint32Array", r"_", 1), (r"Uint8Array", r"_", 1), (r"Uint8ClampedArray", r"_", 1), (r"WeakMap", r"_", 1), (r"WeakSet", r"_", 1), (r"WebAssembly", r"_", 1)];
+
+static KEYWORDS_27: &[(&str, &str, u8)] = &[(r"async", r"keyword", 1)];
+
+static KEYWORDS_28: &[(&str, &str, u8)] = &[(r"case", r"keyword", 1), (r"return", r"keyword", 1), (r"throw", r"keyword", 1)];
+
+static KEYWORDS_29: &[(&str, &str, u8)] = &[(r"catch", r"keyword", 1), (r"for", r"keyword", 0), (r"if", r"keyword", 0), (r"switch", r"keyword", 1), (r"while", r"keyword", 1)];
+
+static KEYWORDS_30: &[(&str, &str, u8)] = &[(r"prototype", r"keyword", 1)];
+
+static KEYWORDS_31: &[(&str, &str, u8)] = &[(r"false", r"literal", 1), (r"null", r"literal", 1), (r"true", r"literal", 1)];
+
+static KEYWORDS_32: &[(&str, &str, u8)] = &[(r"false", r"keyword", 1), (r"null", r"keyword", 1), (r"true", r"keyword", 1)];
+
+static KEYWORDS_33: &[(&str, &str, u8)] = &[(r"Boolean", r"built_in", 1), (r"Byte", r"built_in", 1), (r"Char", r"built_in", 1), (r"Double", r"built_in", 1), (r"Float", r"built_in", 1), (r"Int", r"built_in", 1), (r"Long", r"built_in", 1), (r"Nothing", r"built_in", 1), (r"Short", r"built_in", 1), (r"Unit", r"built_in", 1), (r"Void", r"built_in", 1), (r"abstract", r"keyword", 1), (r"actual", r"keyword", 1), (r"annotation", r"keyword", 1), (r"as", r"keyword", 1), (r"by", r"keyword", 1), (r"catch", r"keyword", 1), (r"class", r"keyword", 1), (r"companion", r"keyword", 1), (r"const", r"keyword", 1), (r"constructor", r"keyword", 1), (r"crossinline", r"keyword", 1), (r"data", r"keyword", 1), (r"do", r"keyword", 1), (r"dynamic", r"keyword", 1), (r"else", r"keyword", 1), (r"enum", r"keyword", 1), (r"expect", r"keyword", 1), (r"external", r"keyword", 1), (r"false", r"literal", 1), (r"final", r"keyword", 1), (r"finally", r"keyword", 1), (r"for", r"keyword", 0), (r"fun", r"keyword", 1), (r"get", r"keyword", 1), (r"if", r"keyword", 0), (r"import", r"keyword", 1), (r"in", r"keyword", 0), (r"infix", r"keyword", 1), (r"init", r"keyword", 1), (r"inline", r"keyword", 1), (r"inner", r"keyword", 1), (r"interface", r"keyword", 1), (r"internal", r"keyword", 1), (r"is", r"keyword", 1), (r"lateinit", r"keyword", 1), (r"noinline", r"keyword", 1), (r"null", r"literal", 1), (r"object", r"keyword", 1), (r"open", r"keyword", 1), (r"operator", r"keyword", 1), (r"out", r"keyword", 1), (r"override", r"keyword", 1), (r"package", r"keyword", 1), (r"private", r"keyword", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"reified", r"keyword", 1), (r"sealed", r"keyword", 1), (r"set", r"keyword", 1), (r"super", r"keyword", 1), (r"suspend", r"keyword", 1), (r"tailrec", r"keyword", 1), (r"throw", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"typealias", r"keyword", 1), (r"val", r"keyword", 1), (r"var", r"keyword", 1), (r"vararg", r"keyword", 1), (r"when", r"keyword", 1), (r"where", r"keyword", 1), (r"while", r"keyword", 1)];
+
+static KEYWORDS_34: &[(&str, &str, u8)] = &[(r"class", r"keyword", 1), (r"interface", r"keyword", 1), (r"trait", r"keyword", 1)];
+
+static KEYWORDS_35: &[(&str, &str, u8)] = &[(r"reified", r"keyword", 1)];
+
+static KEYWORDS_36: &[(&str, &str, u8)] = &[(r"constructor", r"keyword", 1), (r"internal", r"keyword", 1), (r"private", r"keyword", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1)];
+
+static KEYWORDS_37: &[(&str, &str, u8)] = &[(r"when", r"keyword", 1)];
+
+static KEYWORDS_38: &[(&str, &str, u8)] = &[(r"and", r"keyword", 0), (r"not", r"keyword", 0)];
+
+static KEYWORDS_39: &[(&str, &str, u8)] = &[(r"_ENV", r"built_in", 1), (r"_G", r"built_in", 1), (r"_VERSION", r"built_in", 1), (r"__add", r"built_in", 1), (r"__call", r"built_in", 1), (r"__concat", r"built_in", 1), (r"__div", r"built_in", 1), (r"__eq", r"built_in", 1), (r"__gc", r"built_in", 1), (r"__index", r"built_in", 1), (r"__le", r"built_in", 1), (r"__len", r"built_in", 1), (r"__lt", r"built_in", 1), (r"__metatable", r"built_in", 1), (r"__mod", r"built_in", 1), (r"__mode", r"built_in", 1), (r"__mul", r"built_in", 1), (r"__newindex", r"built_in", 1), (r"__pow", r"built_in", 1), (r"__sub", r"built_in", 1), (r"__tostring", r"built_in", 1), (r"__unm", r"built_in", 1), (r"abs", r"built_in", 1), (r"acos", r"built_in", 1), (r"and", r"keyword", 0), (r"arg", r"built_in", 1), (r"asin", r"built_in", 1), (r"assert", r"built_in", 1), (r"atan", r"built_in", 1), (r"atan2", r"built_in", 1), (r"break", r"keyword", 1), (r"byte", r"built_in", 1), (r"ceil", r"built_in", 1), (r"char", r"built_in", 1), (r"clock", r"built_in", 1), (r"close", r"built_in", 1), (r"collectgarbage", r"built_in", 1), (r"concat", r"built_in", 1), (r"config", r"built_in", 1), (r"coroutine", r"built_in", 1), (r"cos", r"built_in", 1), (r"cosh", r"built_in", 1), (r"cpath", r"built_in", 1), (r"create", r"built_in", 1), (r"date", r"built_in", 1), (r"debug", r"built_in", 1), (r"deg", r"built_in", 1), (r"difftime", r"built_in", 1), (r"do", r"keyword", 1), (r"dofile", r"built_in", 1), (r"dump", r"built_in", 1), (r"else", r"keyword", 1), (r"elseif", r"keyword", 1), (r"end", r"keyword", 1), (r"error", r"built_in", 1), (r"execute", r"built_in", 1), (r"exit", r"built_in", 1), (r"exp", r"built_in", 1), (r"false", r"literal", 1), (r"find", r"built_in", 1), (r"floor", r"built_in", 1), (r"flush", r"built_in", 1), (r"fmod", r"built_in", 1), (r"for", r"keyword", 0), (r"foreach", r"built_in", 1), (r"foreachi", r"built_in", 1), (r"format", r"built_in", 1), (r"frexp", r"built_in", 1), (r"getenv", r"built_in", 1), (r"getfenv", r"built_in", 1), (r"gethook", r"built_in", 1), (r"getinfo", r"built_in", 1), (r"getlocal", r"built_in", 1), (r"getmetatable", r"built_in", 1), (r"getn", r"built_in", 1), (r"getregistry", r"built_in", 1), (r"getupvalue", r"built_in", 1), (r"gfind", r"built_in", 1), (r"gmatch", r"built_in", 1), (r"goto", r"keyword", 1), (r"gsub", r"built_in", 1), (r"huge", r"built_in", 1), (r"if", r"keyword", 0), (r"in", r"keyword", 0), (r"input", r"built_in", 1), (r"insert", r"built_in", 1), (r"io", r"built_in", 1), (r"ipairs", r"built_in", 1), (r"ldexp", r"built_in", 1), (r"len", r"built_in", 1), (r"lines", r"built_in", 1), (r"load", r"built_in", 1), (r"loaded", r"built_in", 1), (r"loaders", r"built_in", 1), (r"loadfile", r"built_in", 1), (r"loadlib", r"built_in", 1), (r"loadstring", r"built_in", 1), (r"local", r"keyword", 1), (r"log", r"built_in", 1), (r"log10", r"built_in", 1), (r"lower", r"built_in", 1), (r"match", r"built_in", 1), (r"math", r"built_in", 1), (r"max", r"built_in", 1), (r"maxn", r"built_in", 1), (r"min", r"built_in", 1), (r"mod", r"built_in", 1), (r"modf", r"built_in", 1), (r"module", r"built_in", 1), (r"next", r"built_in", 1), (r"nil", r"literal", 1), (r"not", r"keyword", 0), (r"open", r"built_in", 1), (r"or", r"keyword", 0), (r"os", r"built_in", 1), (r"output", r"built_in", 1), (r"package", r"built_in", 1), (r"pairs", r"built_in", 1), (r"path", r"built_in", 1), (r"pcall", r"built_in", 1), (r"pi", r"built_in", 1), (r"popen", r"built_in", 1), (r"pow", r"built_in", 1), (r"preload", r"built_in", 1), (r"print", r"built_in", 1), (r"rad", r"built_in", 1), (r"random", r"built_in", 1), (r"randomseed", r"built_in", 1), (r"rawequal", r"built_in", 1), (r"rawget", r"built_in", 1), (r"rawset", r"built_in", 1), (r"read", r"built_in", 1), (r"remove", r"built_in", 1), (r"rename", r"built_in", 1), (r"rep", r"built_in", 1), (r"repeat", r"keyword", 1), (r"require", r"built_in", 1), (r"resume", r"built_in", 1), (r"return", r"keyword", 1), (r"reverse", r"built_in", 1), (r"running", r"built_in", 1), (r"seeall", r"built_in", 1), (r"select", r"built_in", 1), (r"self", r"built_in", 1), (r"setfenv", r"built_in", 1), (r"sethook", r"built_in", 1), (r"setlocal", r"built_in", 1), (r"setlocale", r"built_in", 1), (r"setmetatable", r"built_in", 1), (r"setn", r"built_in", 1), (r"setupvalue", r"built_in", 1), (r"sin", r"built_in", 1), (r"sinh", r"built_in", 1), (r"sort", r"built_in", 1), (r"sqrt", r"built_in", 1), (r"status", r"built_in", 1), (r"stderr", r"built_in", 1), (r"stdin", r"built_in", 1), (r"stdout", r"built_in", 1), (r"string", r"built_in", 1), (r"sub", r"built_in", 1), (r"table", r"built_in", 1), (r"tan", r"built_in", 1), (r"tanh", r"built_in", 1), (r"then", r"keyword", 0), (r"time", r"built_in", 1), (r"tmpfile", r"built_in", 1), (r"tmpname", r"built_in", 1), (r"tonumber", r"built_in", 1), (r"tostring", r"built_in", 1), (r"traceback", r"built_in", 1), (r"true", r"literal", 1), (r"type", r"built_in", 1), (r"unpack", r"built_in", 1), (r"until", r"keyword", 1), (r"upper", r"built_in", 1), (r"while", r"keyword", 1), (r"wrap", r"built_in", 1), (r"write", r"built_in", 1), (r"xpcall", r"built_in", 1), (r"yield", r"built_in", 1)];
+
+static KEYWORDS_40: &[(&str, &str, u8)] = &[(r"function", r"keyword", 1)];
+
+static KEYWORDS_41: &[(&str, &str, u8)] = &[(r"-include", r"keyword", 1), (r"define", r"keyword", 1), (r"else", r"keyword", 1), (r"endef", r"keyword", 1), (r"endif", r"keyword", 1), (r"export", r"keyword", 1), (r"ifdef", r"keyword", 1), (r"ifeq", r"keyword", 1), (r"ifndef", r"keyword", 1), (r"ifneq", r"keyword", 1), (r"include", r"keyword", 1), (r"override", r"keyword", 1), (r"private", r"keyword", 1), (r"sinclude", r"keyword", 1), (r"undefine", r"keyword", 1), (r"unexport", r"keyword", 1), (r"vpath", r"keyword", 1)];
+
+static KEYWORDS_42: &[(&str, &str, u8)] = &[(r"abspath", r"built_in", 1), (r"addprefix", r"built_in", 1), (r"addsuffix", r"built_in", 1), (r"and", r"built_in", 0), (r"basename", r"built_in", 1), (r"call", r"built_in", 1), (r"dir", r"built_in", 1), (r"error", r"built_in", 1), (r"eval", r"built_in", 1), (r"file", r"built_in", 1), (r"filter", r"built_in", 1), (r"filter-out", r"built_in", 1), (r"findstring", r"built_in", 1), (r"firstword", r"built_in", 1), (r"flavor", r"built_in", 1), (r"foreach", r"built_in", 1), (r"if", r"built_in", 0), (r"join", r"built_in", 1), (r"lastword", r"built_in", 1), (r"notdir", r"built_in", 1), (r"or", r"built_in", 0), (r"origin", r"built_in", 1), (r"patsubst", r"built_in", 1), (r"realpath", r"built_in", 1), (r"shell", r"built_in", 1), (r"sort", r"built_in", 1), (r"strip", r"built_in", 1), (r"subst", r"built_in", 1), (r"suffix", r"built_in", 1), (r"value", r"built_in", 0), (r"warning", r"built_in", 1), (r"wildcard", r"built_in", 1), (r"word", r"built_in", 1), (r"wordlist", r"built_in", 1)];
+
+static KEYWORDS_43: &[(&str, &str, u8)] = &[(r".PHONY", r"keyword", 1)];
+
+static KEYWORDS_44: &[(&str, &str, u8)] = &[(r"style", r"name", 1)];
+
+static KEYWORDS_45: &[(&str, &str, u8)] = &[(r"script", r"name", 1)];
+
+static KEYWORDS_46: &[(&str, &str, u8)] = &[(r"@autoreleasepool", r"keyword", 1), (r"@catch", r"keyword", 1), (r"@compatibility_alias", r"keyword", 1), (r"@defs", r"keyword", 1), (r"@dynamic", r"keyword", 1), (r"@encode", r"keyword", 1), (r"@end", r"keyword", 1), (r"@finally", r"keyword", 1), (r"@import", r"keyword", 1), (r"@optional", r"keyword", 1), (r"@package", r"keyword", 1), (r"@private", r"keyword", 1), (r"@property", r"keyword", 1), (r"@protected", r"keyword", 1), (r"@public", r"keyword", 1), (r"@required", r"keyword", 1), (r"@selector", r"keyword", 1), (r"@synchronized", r"keyword", 1), (r"@synthesize", r"keyword", 1), (r"@throw", r"keyword", 1), (r"@try", r"keyword", 1), (r"BOOL", r"type", 1), (r"FALSE", r"literal", 1), (r"IBAction", r"keyword", 1), (r"IBOutlet", r"keyword", 1), (r"NO", r"literal", 1), (r"NS_ASSUME_NONNULL_BEGIN", r"keyword", 1), (r"NS_ASSUME_NONNULL_END", r"keyword", 1), (r"NS_AVAILABLE", r"keyword", 1), (r"NS_DEPRECATED", r"keyword", 1), (r"NS_DESIGNATED_INITIALIZER", r"keyword", 1), (r"NS_DURING", r"keyword", 1), (r"NS_ENDHANDLER", r"keyword", 1), (r"NS_ENUM", r"keyword", 1), (r"NS_HANDLER", r"keyword", 1), (r"NS_INLINE", r"keyword", 1), (r"NS_OPTIONS", r"keyword", 1), (r"NS_REFINED_FOR_SWIFT", r"keyword", 1), (r"NS_REQUIRES_SUPER", r"keyword", 1), (r"NS_RETURNS_INNER_POINTER", r"keyword", 1), (r"NS_SWIFT_NAME", r"keyword", 1), (r"NS_SWIFT_NOTHROW", r"keyword", 1), (r"NS_SWIFT_UNAVAILABLE", r"keyword", 1), (r"NS_UNAVAILABLE", r"keyword", 1), (r"NS_VALUERETURN", r"keyword", 1), (r"NS_VOIDRETURN", r"keyword", 1), (r"NULL", r"literal", 1), (r"TRUE", r"literal", 1), (r"YES", r"literal", 1), (r"_Bool", r"type", 1), (r"_Nonnull", r"keyword", 1), (r"_Null_unspecified", r"keyword", 1), (r"_Nullable", r"keyword", 1), (r"__FUNCTION__", r"keyword", 1), (r"__PRETTY_FUNCTION__", r"keyword", 1), (r"__attribute__", r"keyword", 1), (r"__autoreleasing", r"keyword", 1), (r"__block", r"keyword", 1), (r"__bridge", r"keyword", 1), (r"__bridge_retain", r"keyword", 1), (r"__bridge_retained", r"keyword", 1), (r"__bridge_transfer", r"keyword", 1), (r"__contravariant", r"keyword", 1), (r"__covariant", r"keyword", 1), (r"__kindof", r"keyword", 1), (r"__strong", r"keyword", 1), (r"__weak", r"keyword", 1), (r"asm", r"keyword", 1), (r"assign", r"keyword", 1), (r"bool", r"type", 1), (r"break", r"keyword", 1), (r"bycopy", r"keyword", 1), (r"byref", r"keyword", 1), (r"case", r"keyword", 1), (r"char", r"type", 1), (r"class", r"keyword", 1), (r"const", r"keyword", 1), (r"continue", r"keyword", 1), (r"copy", r"keyword", 1), (r"default", r"keyword", 1), (r"dispatch_async", r"built_in", 1), (r"dispatch_once", r"built_in", 1), (r"dispatch_once_t", r"built_in", 1), (r"dispatch_queue_t", r"built_in", 1), (r"dispatch_sync", r"built_in", 1), (r"do", r"keyword", 1), (r"double", r"type", 1), (r"else", r"keyword", 1), (r"enum", r"keyword", 1), (r"explicit", r"keyword", 1), (r"export", r"keyword", 1), (r"extern", r"keyword", 1), (r"false", r"literal", 1), (r"float", r"type", 1), (r"for", r"keyword", 0), (r"getter", r"keyword", 1), (r"goto", r"keyword", 1), (r"id", r"type", 0), (r"if", r"keyword", 0), (r"in", r"keyword", 0), (r"inline", r"keyword", 1), (r"inout", r"keyword", 1), (r"instancetype", r"keyword", 1), (r"int", r"type", 1), (r"long", r"type", 1), (r"mutable", r"keyword", 1), (r"nil", r"literal", 1), (r"nonatomic", r"keyword", 1), (r"nonnull", r"keyword", 1), (r"null_resettable", r"keyword", 1), (r"null_unspecified", r"keyword", 1), (r"nullable", r"keyword", 1), (r"oneway", r"keyword", 1), (r"out", r"keyword", 1), (r"readonly", r"keyword", 1), (r"readwrite", r"keyword", 1), (r"register", r"keyword", 1), (r"retain", r"keyword", 1), (r"return", r"keyword", 1), (r"self", r"keyword", 1), (r"setter", r"keyword", 1), (r"short", r"type", 1), (r"signed", r"type", 1), (r"sizeof", r"keyword", 1), (r"static", r"keyword", 1), (r"strong", r"keyword", 1), (r"struct", r"keyword", 1), (r"super", r"variable.language", 1), (r"switch", r"keyword", 1), (r"this", r"variable.language", 1), (r"true", r"literal", 1), (r"typedef", r"keyword", 1), (r"typename", r"keyword", 1), (r"typeof", r"keyword", 1), (r"unichar", r"type", 1), (r"union", r"keyword", 1), (r"unsafe_unretained", r"keyword", 1), (r"unsigned", r"type", 1), (r"void", r"type", 1), (r"volatile", r"keyword", 1), (r"wchar_t", r"type", 1), (r"weak", r"keyword", 1), (r"while", r"keyword", 1)];
+
+static KEYWORDS_47: &[(&str, &str, u8)] = &[(r"define", r"keyword", 1), (r"elif", r"keyword", 1), (r"else", r"keyword", 1), (r"endif", r"keyword", 1), (r"error", r"keyword", 1), (r"if", r"keyword", 0), (r"ifdef", r"keyword", 1), (r"ifndef", r"keyword", 1), (r"include", r"keyword", 1), (r"line", r"keyword", 1), (r"pragma", r"keyword", 1), (r"undef", r"keyword", 1), (r"warning", r"keyword", 1)];
+
+static KEYWORDS_48: &[(&str, &str, u8)] = &[(r"@class", r"keyword", 1), (r"@implementation", r"keyword", 1), (r"@interface", r"keyword", 1), (r"@protocol", r"keyword", 1)];
+
+static KEYWORDS_49: &[(&str, &str, u8)] = &[(r"abs", r"keyword", 1), (r"accept", r"keyword", 1), (r"alarm", r"keyword", 1), (r"and", r"keyword", 0), (r"atan2", r"keyword", 1), (r"bind", r"keyword", 1), (r"binmode", r"keyword", 1), (r"bless", r"keyword", 1), (r"break", r"keyword", 1), (r"caller", r"keyword", 1), (r"chdir", r"keyword", 1), (r"chmod", r"keyword", 1), (r"chomp", r"keyword", 1), (r"chop", r"keyword", 1), (r"chown", r"keyword", 1), (r"chr", r"keyword", 1), (r"chroot", r"keyword", 1), (r"close", r"keyword", 1), (r"closedir", r"keyword", 1), (r"connect", r"keyword", 1), (r"continue", r"keyword", 1), (r"cos", r"keyword", 1), (r"crypt", r"keyword", 1), (r"dbmclose", r"keyword", 1), (r"dbmopen", r"keyword", 1), (r"defined", r"keyword", 1), (r"delete", r"keyword", 1), (r"die", r"keyword", 1), (r"do", r"keyword", 1), (r"dump", r"keyword", 1), (r"each", r"keyword", 1), (r"else", r"keyword", 1), (r"elsif", r"keyword", 1), (r"endgrent", r"keyword", 1), (r"endhostent", r"keyword", 1), (r"endnetent", r"keyword", 1), (r"endprotoent", r"keyword", 1), (r"endpwent", r"keyword", 1), (r"endservent", r"keyword", 1), (r"eof", r"keyword", 1), (r"eval", r"keyword", 1), (r"exec", r"keyword", 1), (r"exists", r"keyword", 1), (r"exit", r"keyword", 1), (r"exp", r"keyword", 1), (r"fcntl", r"keyword", 1), (r"fileno", r"keyword", 1), (r"flock", r"keyword", 1), (r"for", r"keyword", 0), (r"foreach", r"keyword", 1), (r"fork", r"keyword", 1), (r"format", r"keyword", 1), (r"formline", r"keyword", 1), (r"getc", r"keyword", 1), (r"getgrent", r"keyword", 1), (r"getgrgid", r"keyword", 1), (r"getgrnam", r"keyword", 1), (r"gethostbyaddr", r"keyword", 1), (r"gethostbyname", r"keyword", 1), (r"gethostent", r"keyword", 1), (r"getlogin", r"keyword", 1), (r"getnetbyaddr", r"keyword", 1), (r"getnetbyname", r"keyword", 1), (r"getnetent", r"keyword", 1), (r"getpeername", r"keyword", 1), (r"getpgrp", r"keyword", 1), (r"getpriority", r"keyword", 1), (r"getprotobyname", r"keyword", 1), (r"getprotobynumber", r"keyword", 1), (r"getprotoent", r"keyword", 1), (r"getpwent", r"keyword", 1), (r"getpwnam", r"keyword", 1), (r"getpwuid", r"keyword", 1), (r"getservbyname", r"keyword", 1), (r"getservbyport", r"keyword", 1), (r"getservent", r"keyword", 1), (r"getsockname", r"keyword", 1), (r"getsockopt", r"keyword", 1), (r"given", r"keyword", 1), (r"glob", r"keyword", 1), (r"gmtime", r"keyword", 1), (r"goto", r"keyword", 1), (r"grep", r"keyword", 1), (r"gt", r"keyword", 1), (r"hex", r"keyword", 1), (r"if", r"keyword", 0), (r"index", r"keyword", 1), (r"int", r"keyword", 1), (r"ioctl", r"keyword", 1), (r"join", r"keyword", 1), (r"keys", r"keyword", 1), (r"kill", r"keyword", 1), (r"last", r"keyword", 1), (r"lc", r"keyword", 1), (r"lcfirst", r"keyword", 1), (r"length", r"keyword", 1), (r"link", r"keyword", 1), (r"listen", r"keyword", 1), (r"local", r"keyword", 1), (r"localtime", r"keyword", 1), (r"log", r"keyword", 1), (r"lstat", r"keyword", 1), (r"lt", r"keyword", 1), (r"ma", r"keyword", 1), (r"map", r"keyword", 1), (r"mkdir", r"keyword", 1), (r"msgctl", r"keyword", 1), (r"msgget", r"keyword", 1), (r"msgrcv", r"keyword", 1), (r"msgsnd", r"keyword", 1), (r"my", r"keyword", 1), (r"ne", r"keyword", 1), (r"next", r"keyword", 1), (r"no", r"keyword", 1), (r"not", r"keyword", 0), (r"oct", r"keyword", 1), (r"open", r"keyword", 1), (r"opendir", r"keyword", 1), (r"or", r"keyword", 0), (r"ord", r"keyword", 1), (r"our", r"keyword", 1), (r"pack", r"keyword", 1), (r"package", r"keyword", 1), (r"pipe", r"keyword", 1), (r"pop", r"keyword", 1), (r"pos", r"keyword", 1), (r"print", r"keyword", 1), (r"printf", r"keyword", 1), (r"prototype", r"keyword", 1), (r"push", r"keyword", 1), (r"q", r"keyword", 0), (r"qq", r"keyword", 1), (r"quotemeta", r"keyword", 1), (r"qw", r"keyword", 1), (r"qx", r"keyword", 1), (r"rand", r"keyword", 1), (r"read", r"keyword", 1), (r"readdir", r"keyword", 1), (r"readline", r"keyword", 1), (r"readlink", r"keyword", 1), (r"readpipe", r"keyword", 1), (r"recv", r"keyword", 1), (r"redo", r"keyword", 1), (r"ref", r"keyword", 1), (r"rename", r"keyword", 1), (r"require", r"keyword", 1), (r"reset", r"keyword", 1), (r"return", r"keyword", 1), (r"reverse", r"keyword", 1), (r"rewinddir", r"keyword", 1), (r"rindex", r"keyword", 1), (r"rmdir", r"keyword", 1), (r"say", r"keyword", 1), (r"scalar", r"keyword", 1), (r"seek", r"keyword", 1), (r"seekdir", r"keyword", 1), (r"select", r"keyword", 1), (r"semctl", r"keyword", 1), (r"semget", r"keyword", 1), (r"semop", r"keyword", 1), (r"send", r"keyword", 1), (r"setgrent", r"keyword", 1), (r"sethostent", r"keyword", 1), (r"setnetent", r"keyword", 1), (r"setpgrp", r"keyword", 1), (r"setpriority", r"keyword", 1), (r"setprotoent", r"keyword", 1), (r"setpwent", r"keyword", 1), (r"setservent", r"keyword", 1), (r"setsockopt", r"keyword", 1), (r"shift", r"keyword", 1), (r"shmctl", r"keyword", 1), (r"shmget", r"keyword", 1), (r"shmread", r"keyword", 1), (r"shmwrite", r"keyword", 1), (r"shutdown", r"keyword", 1), (r"sin", r"keyword", 1), (r"sleep", r"keyword", 1), (r"socket", r"keyword", 1), (r"socketpair", r"keyword", 1), (r"sort", r"keyword", 1), (r"splice", r"keyword", 1), (r"split", r"keyword", 1), (r"sprintf", r"keyword", 1), (r"sqrt", r"keyword", 1), (r"srand", r"keyword", 1), (r"stat", r"keyword", 1), (r"state", r"keyword", 1), (r"study", r"keyword", 1), (r"sub", r"keyword", 1), (r"substr", r"keyword", 1), (r"symlink", r"keyword", 1), (r"syscall", r"keyword", 1), (r"sysopen", r"keyword", 1), (r"sysread", r"keyword", 1), (r"sysseek", r"keyword", 1), (r"system", r"keyword", 1), (r"syswrite", r"keyword", 1), (r"tell", r"keyword", 1), (r"telldir", r"keyword", 1), (r"tie", r"keyword", 1), (r"tied", r"keyword", 1), (r"time", r"keyword", 1), (r"times", r"keyword", 1), (r"tr", r"keyword", 1), (r"truncate", r"keyword", 1), (r"uc", r"keyword", 1), (r"ucfirst", r"keyword", 1), (r"umask", r"keyword", 1), (r"undef", r"keyword", 1), (r"unless", r"keyword", 1), (r"unlink", r"keyword", 1), (r"unpack", r"keyword", 1), (r"unshift", r"keyword", 1), (r"untie", r"keyword", 1), (r"until", r"keyword", 1), (r"use", r"keyword", 1), (r"utime", r"keyword", 1), (r"values", r"keyword", 1), (r"vec", r"keyword", 1), (r"wait", r"keyword", 1), (r"waitpid", r"keyword", 1), (r"wantarray", r"keyword", 1), (r"warn", r"keyword", 1), (r"when", r"keyword", 1), (r"while", r"keyword", 1), (r"write", r"keyword", 1), (r"x", r"keyword", 0), (r"xor", r"keyword", 1), (r"y", r"keyword", 0)];
+
+static KEYWORDS_50: &[(&str, &str, u8)] = &[(r"grep", r"keyword", 1), (r"print", r"keyword", 1), (r"return", r"keyword", 1), (r"reverse", r"keyword", 1), (r"split", r"keyword", 1)];
+
+static KEYWORDS_51: &[(&str, &str, u8)] = &[(r"sub", r"keyword", 1)];
+
+static KEYWORDS_52: &[(&str, &str, u8)] = &[(r"AppendIterator", r"built_in", 1), (r"ArgumentCountError", r"built_in", 1), (r"ArithmeticError", r"built_in", 1), (r"ArrayAccess", r"built_in", 1), (r"ArrayIterator", r"built_in", 1), (r"ArrayObject", r"built_in", 1), (r"AssertionError", r"built_in", 1), (r"BackedEnum", r"built_in", 1), (r"BadFunctionCallException", r"built_in", 1), (r"BadMethodCallException", r"built_in", 1), (r"CachingIterator", r"built_in", 1), (r"CallbackFilterIterator", r"built_in", 1), (r"Closure", r"built_in", 1), (r"CompileError", r"built_in", 1), (r"Countable", r"built_in", 1), (r"Directory", r"built_in", 1), (r"DirectoryIterator", r"built_in", 1), (r"DivisionByZeroError", r"built_in", 1), (r"DomainException", r"built_in", 1), (r"EmptyIterator", r"built_in", 1), (r"Error", r"built_in", 0), (r"ErrorException", r"built_in", 1), (r"Exception", r"built_in", 1), (r"FALSE", r"literal", 1), (r"Fiber", r"built_in", 1), (r"FilesystemIterator", r"built_in", 1), (r"FilterIterator", r"built_in", 1), (r"Generator", r"built_in", 1), (r"GlobIterator", r"built_in", 1), (r"InfiniteIterator", r"built_in", 1), (r"InvalidArgumentException", r"built_in", 1), (r"Iterator", r"built_in", 1), (r"IteratorAggregate", r"built_in", 1), (r"IteratorIterator", r"built_in", 1), (r"LengthException", r"built_in", 1), (r"LimitIterator", r"built_in", 1), (r"LogicException", r"built_in", 1), (r"MultipleIterator", r"built_in", 1), (r"NULL", r"literal", 1), (r"NoRewindIterator", r"built_in", 1), (r"OutOfBoundsException", r"built_in", 1), (r"OutOfRangeException", r"built_in", 1), (r"OuterIterator", r"built_in", 1), (r"OverflowException", r"built_in", 1), (r"ParentIterator", r"built_in", 1), (r"ParseError", r"built_in", 1), (r"RangeException", r"built_in", 1), (r"RecursiveArrayIterator", r"built_in", 1), (r"RecursiveCachingIterator", r"built_in", 1), (r"RecursiveCallbackFilterIterator", r"built_in", 1), (r"RecursiveDirectoryIterator", r"built_in", 1), (r"RecursiveFilterIterator", r"built_in", 1), (r"RecursiveIterator", r"built_in", 1), (r"RecursiveIteratorIterator", r"built_in", 1), (r"RecursiveRegexIterator", r"built_in", 1), (r"RecursiveTreeIterator", r"built_in", 1), (r"RegexIterator", r"built_in", 1), (r"RuntimeException", r"built_in", 1), (r"SeekableIterator", r"built_in", 1), (r"Serializable", r"built_in", 1), (r"SplDoublyLinkedList", r"built_in", 1), (r"SplFileInfo", r"built_in", 1), (r"SplFileObject", r"built_in", 1), (r"SplFixedArray", r"built_in", 1), (r"SplHeap", r"built_in", 1), (r"SplMaxHeap", r"built_in", 1), (r"SplMinHeap", r"built_in", 1), (r"SplObjectStorage", r"built_in", 1), (r"SplObserver", r"built_in", 1), (r"SplPriorityQueue", r"built_in", 1), (r"SplQueue", r"built_in", 1), (r"SplStack", r"built_in", 1), (r"SplSubject", r"built_in", 1), (r"SplTempFileObject", r"built_in", 1), (r"Stringable", r"built_in", 1), (r"TRUE", r"literal", 1), (r"Throwable", r"built_in", 1), (r"Traversable", r"built_in", 1), (r"TypeError", r"built_in", 1), (r"UnderflowException", r"built_in", 1), (r"UnexpectedValueException", r"built_in", 1), (r"UnhandledMatchError", r"built_in", 1), (r"UnitEnum", r"built_in", 1), (r"WeakMap", r"built_in", 1), (r"WeakReference", r"built_in", 1), (r"__CLASS__", r"keyword", 1), (r"__COMPILER_HALT_OFFSET__", r"keyword", 1), (r"__DIR__", r"keyword", 1), (r"__FILE__", r"keyword", 1), (r"__FUNCTION__", r"keyword", 1), (r"__LINE__", r"keyword", 1), (r"__METHOD__", r"keyword", 1), (r"__NAMESPACE__", r"keyword", 1), (r"__PHP_Incomplete_Class", r"built_in", 1), (r"__TRAIT__", r"keyword", 1), (r"abstract", r"keyword", 1), (r"and", r"keyword", 0), (r"array", r"keyword", 1), (r"as", r"keyword", 1), (r"binary", r"keyword", 1), (r"bool", r"keyword", 1), (r"boolean", r"keyword", 1), (r"break", r"keyword", 1), (r"callable", r"keyword", 1), (r"case", r"keyword", 1), (r"catch", r"keyword", 1), (r"class", r"keyword", 1), (r"clone", r"keyword", 1), (r"const", r"keyword", 1), (r"continue", r"keyword", 1), (r"declare", r"keyword", 1), (r"default", r"keyword", 1), (r"die", r"keyword", 1), (r"do", r"keyword", 1), (r"double", r"keyword", 1), (r"echo", r"keyword", 1), (r"else", r"keyword", 1), (r"elseif", r"keyword", 1), (r"empty", r"keyword", 1), (r"enddeclare", r"keyword", 1), (r"endfor", r"keyword", 1), (r"endforeach", r"keyword", 1), (r"endif", r"keyword", 1), (r"endswitch", r"keyword", 1), (r"endwhile", r"keyword", 1), (r"enum", r"keyword", 1), (r"eval", r"keyword", 1), (r"exit", r"keyword", 1), (r"extends", r"keyword", 1), (r"false", r"literal", 1), (r"final", r"keyword", 1), (r"finally", r"keyword", 1), (r"float", r"keyword", 1), (r"for", r"keyword", 0), (r"foreach", r"keyword", 1), (r"from", r"keyword", 1), (r"global", r"keyword", 1), (r"goto", r"keyword", 1), (r"if", r"keyword", 0), (r"implements", r"keyword", 1), (r"include", r"keyword", 1), (r"include_once", r"keyword", 1), (r"instanceof", r"keyword", 1), (r"insteadof", r"keyword", 1), (r"int", r"keyword", 1), (r"integer", r"keyword", 1), (r"interface", r"keyword", 1), (r"isset", r"keyword", 1), (r"iterable", r"keyword", 1), (r"list", r"keyword", 0), (r"match", r"keyword", 0), (r"mixed", r"keyword", 1), (r"never", r"keyword", 1), (r"new", r"keyword", 1), (r"null", r"literal", 1), (r"object", r"keyword", 1), (r"or", r"keyword", 0), (r"parent", r"built_in", 0), (r"php_user_filter", r"built_in", 1), (r"print", r"keyword", 1), (r"private", r"keyword", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"readonly", r"keyword", 1), (r"real", r"keyword", 1), (r"require", r"keyword", 1), (r"require_once", r"keyword", 1), (r"return", r"keyword", 1), (r"self", r"built_in", 1), (r"static", r"built_in", 1), (r"stdClass", r"built_in", 1), (r"string", r"keyword", 1), (r"switch", r"keyword", 1), (r"throw", r"keyword", 1), (r"trait", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"unset", r"keyword", 1), (r"use", r"keyword", 1), (r"var", r"keyword", 1), (r"void", r"keyword", 1), (r"while", r"keyword", 1), (r"xor", r"keyword", 1), (r"yield", r"keyword", 1)];
+
+static KEYWORDS_53: &[(&str, &str, u8)] = &[(r"array", r"keyword", 1), (r"false", r"literal", 1), (r"new", r"keyword", 1), (r"null", r"literal", 1), (r"true", r"literal", 1)];
+
+static KEYWORDS_54: &[(&str, &str, u8)] = &[(r"__halt_compiler", r"keyword", 1)];
+
+static KEYWORDS_55: &[(&str, &str, u8)] = &[(r"fn", r"keyword", 1), (r"function", r"keyword", 1)];
+
+static KEYWORDS_56: &[(&str, &str, u8)] = &[(r"enum", r"keyword", 1)];
+
+static KEYWORDS_57: &[(&str, &str, u8)] = &[(r"use", r"keyword", 1)];
+
+static KEYWORDS_58: &[(&str, &str, u8)] = &[(r"extends", r"keyword", 1), (r"implements", r"keyword", 1)];
+
+static KEYWORDS_59: &[(&str, &str, u8)] = &[(r"Any", r"type", 1), (r"Callable", r"type", 1), (r"Coroutine", r"type", 1), (r"Dict", r"type", 1), (r"Ellipsis", r"literal", 1), (r"False", r"literal", 1), (r"Generic", r"type", 1), (r"List", r"type", 0), (r"Literal", r"type", 1), (r"None", r"literal", 1), (r"NotImplemented", r"literal", 1), (r"Optional", r"type", 1), (r"Sequence", r"type", 1), (r"Set", r"type", 1), (r"True", r"literal", 1), (r"Tuple", r"type", 1), (r"Type", r"type", 1), (r"Union", r"type", 1), (r"__debug__", r"literal", 1), (r"__import__", r"built_in", 1), (r"abs", r"built_in", 1), (r"all", r"built_in", 1), (r"and", r"keyword", 0), (r"any", r"built_in", 1), (r"as", r"keyword", 1), (r"ascii", r"built_in", 1), (r"assert", r"keyword", 1), (r"async", r"keyword", 1), (r"await", r"keyword", 1), (r"bin", r"built_in", 1), (r"bool", r"built_in", 1), (r"break", r"keyword", 1), (r"breakpoint", r"built_in", 1), (r"bytearray", r"built_in", 1), (r"bytes", r"built_in", 1), (r"callable", r"built_in", 1), (r"case", r"keyword", 1), (r"chr", r"built_in", 1), (r"class", r"keyword", 1), (r"classmethod", r"built_in", 1), (r"compile", r"built_in", 1), (r"complex", r"built_in", 1), (r"continue", r"keyword", 1), (r"def", r"keyword", 1), (r"del", r"keyword", 1), (r"delattr", r"built_in", 1), (r"dict", r"built_in", 1), (r"dir", r"built_in", 1), (r"divmod", r"built_in", 1), (r"elif", r"keyword", 1), (r"else", r"keyword", 1), (r"enumerate", r"built_in", 1), (r"eval", r"built_in", 1), (r"except", r"keyword", 1), (r"exec", r"built_in", 1), (r"filter", r"built_in", 1), (r"finally", r"keyword", 1), (r"float", r"built_in", 1), (r"for", r"keyword", 0), (r"format", r"built_in", 1), (r"from", r"keyword", 1), (r"frozenset", r"built_in", 1), (r"getattr", r"built_in", 1), (r"global", r"keyword", 1), (r"globals", r"built_in", 1), (r"hasattr", r"built_in", 1), (r"hash", r"built_in", 1), (r"help", r"built_in", 1), (r"hex", r"built_in", 1), (r"id", r"built_in", 1), (r"if", r"keyword", 0), (r"import", r"keyword", 1), (r"in", r"keyword", 0), (r"input", r"built_in", 1), (r"int", r"built_in", 1), (r"is", r"keyword", 1), (r"isinstance", r"built_in", 1), (r"issubclass", r"built_in", 1), (r"iter", r"built_in", 1), (r"lambda", r"keyword", 1), (r"len", r"built_in", 1), (r"list", r"built_in", 0), (r"locals", r"built_in", 1), (r"map", r"built_in", 1), (r"match", r"keyword", 1), (r"max", r"built_in", 1), (r"memoryview", r"built_in", 1), (r"min", r"built_in", 1), (r"next", r"built_in", 1), (r"nonlocal", r"keyword", 10), (r"not", r"keyword", 0), (r"object", r"built_in", 1), (r"oct", r"built_in", 1), (r"open", r"built_in", 1), (r"or", r"keyword", 0), (r"ord", r"built_in", 1), (r"pass", r"keyword", 1), (r"pow", r"built_in", 1), (r"print", r"built_in", 1), (r"property", r"built_in", 1), (r"raise", r"keyword", 1), (r"range", r"built_in", 1), (r"repr", r"built_in", 1), (r"return", r"keyword", 1), (r"reversed", r"built_in", 1), (r"round", r"built_in", 1), (r"set", r"built_in", 1), (r"setattr", r"built_in", 1), (r"slice", r"built_in", 1), (r"sorted", r"built_in", 1), (r"staticmethod", r"built_in", 1), (r"str", r"built_in", 1), (r"sum", r"built_in", 1), (r"super", r"built_in", 1), (r"try", r"keyword", 1), (r"tuple", r"built_in", 1), (r"type", r"built_in", 1), (r"vars", r"built_in", 1), (r"while", r"keyword", 1), (r"with", r"keyword", 1), (r"yield", r"keyword", 1), (r"zip", r"built_in", 1)];
+
+static KEYWORDS_60: &[(&str, &str, u8)] = &[(r"if", r"keyword", 0)];
+
+static KEYWORDS_61: &[(&str, &str, u8)] = &[(r"Arg", r"built_in", 1), (r"Conj", r"built_in", 1), (r"F", r"built_in", 1), (r"FALSE", r"literal", 1), (r"Im", r"built_in", 1), (r"Inf", r"literal", 1), (r"LETTERS", r"built_in", 1), (r"Mod", r"built_in", 1), (r"NA", r"literal", 1), (r"NA_character_", r"literal", 10), (r"NA_complex_", r"literal", 10), (r"NA_integer_", r"literal", 10), (r"NA_real_", r"literal", 10), (r"NULL", r"literal", 1), (r"NaN", r"literal", 1), (r"Re", r"built_in", 1), (r"T", r"built_in", 1), (r"TRUE", r"literal", 1), (r"UseMethod", r"built_in", 1), (r"abs", r"built_in", 1), (r"acos", r"built_in", 1), (r"acosh", r"built_in", 1), (r"all", r"built_in", 1), (r"any", r"built_in", 1), (r"anyNA", r"built_in", 1), (r"as.call", r"built_in", 1), (r"as.character", r"built_in", 1), (r"as.complex", r"built_in", 1), (r"as.double", r"built_in", 1), (r"as.environment", r"built_in", 1), (r"as.integer", r"built_in", 1), (r"as.logical", r"built_in", 1), (r"as.null.default", r"built_in", 1), (r"as.numeric", r"built_in", 1), (r"as.raw", r"built_in", 1), (r"asin", r"built_in", 1), (r"asinh", r"built_in", 1), (r"atan", r"built_in", 1), (r"atanh", r"built_in", 1), (r"attr", r"built_in", 1), (r"attributes", r"built_in", 1), (r"baseenv", r"built_in", 1), (r"break", r"keyword", 1), (r"browser", r"built_in", 1), (r"c", r"built_in", 1), (r"call", r"built_in", 1), (r"ceiling", r"built_in", 1), (r"class", r"built_in", 1), (r"cos", r"built_in", 1), (r"cosh", r"built_in", 1), (r"cospi", r"built_in", 1), (r"cummax", r"built_in", 1), (r"cummin", r"built_in", 1), (r"cumprod", r"built_in", 1), (r"cumsum", r"built_in", 1), (r"digamma", r"built_in", 1), (r"dim", r"built_in", 1), (r"dimnames", r"built_in", 1), (r"else", r"keyword", 1), (r"emptyenv", r"built_in", 1), (r"exp", r"built_in", 1), (r"expression", r"built_in", 1), (r"floor", r"built_in", 1), (r"for", r"keyword", 0), (r"forceAndCall", r"built_in", 1), (r"function", r"keyword", 1), (r"gamma", r"built_in", 1), (r"gc.time", r"built_in", 1), (r"globalenv", r"built_in", 1), (r"if", r"keyword", 0), (r"in", r"keyword", 0), (r"interactive", r"built_in", 1), (r"invisible", r"built_in", 1), (r"is.array", r"built_in", 1), (r"is.atomic", r"built_in", 1), (r"is.call", r"built_in", 1), (r"is.character", r"built_in", 1), (r"is.complex", r"built_in", 1), (r"is.double", r"built_in", 1), (r"is.environment", r"built_in", 1), (r"is.expression", r"built_in", 1), (r"is.finite", r"built_in", 1), (r"is.function", r"built_in", 1), (r"is.infinite", r"built_in", 1), (r"is.integer", r"built_in", 1), (r"is.language", r"built_in", 1), (r"is.list", r"built_in", 1), (r"is.logical", r"built_in", 1), (r"is.matrix", r"built_in", 1), (r"is.na", r"built_in", 1), (r"is.name", r"built_in", 1), (r"is.nan", r"built_in", 1), (r"is.null", r"built_in", 1), (r"is.numeric", r"built_in", 1), (r"is.object", r"built_in", 1), (r"is.pairlist", r"built_in", 1), (r"is.raw", r"built_in", 1), (r"is.recursive", r"built_in", 1), (r"is.single", r"built_in", 1), (r"is.symbol", r"built_in", 1), (r"lazyLoadDBfetch", r"built_in", 1), (r"length", r"built_in", 1), (r"letters", r"built_in", 1), (r"lgamma", r"built_in", 1), (r"list", r"built_in", 0), (r"log", r"built_in", 1), (r"max", r"built_in", 1), (r"min", r"built_in", 1), (r"missing", r"built_in", 1), (r"month.abb", r"built_in", 1), (r"month.name", r"built_in", 1), (r"names", r"built_in", 1), (r"nargs", r"built_in", 1), (r"next", r"keyword", 1), (r"nzchar", r"built_in", 1), (r"oldClass", r"built_in", 1), (r"on.exit", r"built_in", 1), (r"pi", r"built_in", 1), (r"pos.to.env", r"built_in", 1), (r"proc.time", r"built_in", 1), (r"prod", r"built_in", 1), (r"quote", r"built_in", 1), (r"range", r"built_in", 1), (r"rep", r"built_in", 1), (r"repeat", r"keyword", 1), (r"retracemem", r"built_in", 1), (r"return", r"built_in", 1), (r"round", r"built_in", 1), (r"seq.int", r"built_in", 1), (r"seq_along", r"built_in", 1), (r"seq_len", r"built_in", 1), (r"sign", r"built_in", 1), (r"signif", r"built_in", 1), (r"sin", r"built_in", 1), (r"sinh", r"built_in", 1), (r"sinpi", r"built_in", 1), (r"sqrt", r"built_in", 1), (r"standardGeneric", r"built_in", 1), (r"substitute", r"built_in", 1), (r"sum", r"built_in", 1), (r"switch", r"built_in", 1), (r"tan", r"built_in", 1), (r"tanh", r"built_in", 1), (r"tanpi", r"built_in", 1), (r"tracemem", r"built_in", 1), (r"trigamma", r"built_in", 1), (r"trunc", r"built_in", 1), (r"unclass", r"built_in", 1), (r"untracemem", r"built_in", 1), (r"while", r"keyword", 1), (r"xtfrm", r"built_in", 1)];
+
+static KEYWORDS_62: &[(&str, &str, u8)] = &[(r"BEGIN", r"keyword", 1), (r"END", r"keyword", 1), (r"__ENCODING__", r"variable.constant", 1), (r"__FILE__", r"variable.constant", 1), (r"__LINE__", r"variable.constant", 1), (r"alias", r"keyword", 1), (r"and", r"keyword", 0), (r"attr_accessor", r"built_in", 1), (r"attr_reader", r"built_in", 1), (r"attr_writer", r"built_in", 1), (r"begin", r"keyword", 1), (r"break", r"keyword", 1), (r"case", r"keyword", 1), (r"class", r"keyword", 1), (r"define_method", r"built_in", 1), (r"defined", r"keyword", 1), (r"do", r"keyword", 1), (r"else", r"keyword", 1), (r"elsif", r"keyword", 1), (r"end", r"keyword", 1), (r"ensure", r"keyword", 1), (r"extend", r"keyword", 1), (r"false", r"literal", 1), (r"for", r"keyword", 0), (r"if", r"keyword", 0), (r"in", r"keyword", 0), (r"include", r"keyword", 1), (r"lambda", r"built_in", 1), (r"module", r"keyword", 1), (r"module_function", r"built_in", 1), (r"next", r"keyword", 1), (r"nil", r"literal", 1), (r"not", r"keyword", 0), (r"or", r"keyword", 0), (r"prepend", r"keyword", 1), (r"private", r"keyword", 1), (r"private_constant", r"built_in", 1), (r"proc", r"built_in", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"raise", r"keyword", 1), (r"redo", r"keyword", 1), (r"require", r"keyword", 1), (r"rescue", r"keyword", 1), (r"retry", r"keyword", 1), (r"return", r"keyword", 1), (r"self", r"variable.language", 1), (r"super", r"variable.language", 1), (r"then", r"keyword", 0), (r"throw", r"keyword", 1), (r"true", r"literal", 1), (r"undef", r"keyword", 1), (r"unless", r"keyword", 1), (r"until", r"keyword", 1), (r"when", r"keyword", 1), (r"while", r"keyword", 1), (r"yield", r"keyword", 1)];
+
+static KEYWORDS_63: &[(&str, &str, u8)] = &[(r"unless", r"keyword", 1)];
+
+static KEYWORDS_64: &[(&str, &str, u8)] = &[(r"AsMut", r"built_in", 1), (r"AsRef", r"built_in", 1), (r"Box", r"type", 1), (r"Clone", r"built_in", 1), (r"Copy", r"built_in", 1), (r"Debug", r"built_in", 1), (r"Default", r"built_in", 1), (r"DoubleEndedIterator", r"built_in", 1), (r"Drop", r"built_in", 1), (r"Eq", r"built_in", 1), (r"Err", r"literal", 1), (r"ExactSizeIterator", r"built_in", 1), (r"Extend", r"built_in", 1), (r"Fn", r"built_in", 1), (r"FnMut", r"built_in", 1), (r"FnOnce", r"built_in", 1), (r"From", r"built_in", 1), (r"Into", r"built_in", 1), (r"IntoIterator", r"built_in", 1), (r"Iterator", r"built_in", 1), (r"None", r"literal", 1), (r"Ok", r"literal", 1), (r"Option", r"type", 1), (r"Ord", r"built_in", 1), (r"PartialEq", r"built_in", 1), (r"PartialOrd", r"built_in", 1), (r"Result", r"type", 1), (r"Self", r"keyword", 1), (r"Send", r"built_in", 1), (r"Sized", r"built_in", 1), (r"SliceConcatExt", r"built_in", 1), (r"Some", r"literal", 1), (r"String", r"type", 1), (r"Sync", r"built_in", 1), (r"ToOwned", r"built_in", 1), (r"ToString", r"built_in", 1), (r"Vec", r"type", 1), (r"abstract", r"keyword", 1), (r"as", r"keyword", 1), (r"assert!", r"built_in", 1), (r"assert_eq!", r"built_in", 1), (r"assert_ne!", r"built_in", 1), (r"async", r"keyword", 1), (r"await", r"keyword", 1), (r"become", r"keyword", 1), (r"bitflags!", r"built_in", 1), (r"bool", r"type", 1), (r"box", r"keyword", 1), (r"break", r"keyword", 1), (r"bytes!", r"built_in", 1), (r"cfg!", r"built_in", 1), (r"char", r"type", 1), (r"col!", r"built_in", 1), (r"concat!", r"built_in", 1), (r"concat_idents!", r"built_in", 1), (r"const", r"keyword", 1), (r"continue", r"keyword", 1), (r"crate", r"keyword", 1), (r"debug_assert!", r"built_in", 1), (r"debug_assert_eq!", r"built_in", 1), (r"debug_assert_ne!", r"built_in", 1), (r"do", r"keyword", 1), (r"drop ", r"built_in", 1), (r"dyn", r"keyword", 1), (r"else", r"keyword", 1), (r"enum", r"keyword", 1), (r"env!", r"built_in", 1), (r"extern", r"keyword", 1), (r"f32", r"type", 1), (r"f64", r"type", 1), (r"false", r"literal", 1), (r"file!", r"built_in", 1), (r"final", r"keyword", 1), (r"fn", r"keyword", 1), (r"for", r"keyword", 0), (r"format!", r"built_in", 1), (r"format_args!", r"built_in", 1), (r"i128", r"type", 1), (r"i16", r"type", 1), (r"i32", r"type", 1), (r"i64", r"type", 1), (r"i8", r"type", 1), (r"if", r"keyword", 0), (r"impl", r"keyword", 1), (r"in", r"keyword", 0), (r"include_bytes!", r"built_in", 1), (r"include_str!", r"built_in", 1), (r"isize", r"type", 1), (r"let", r"keyword", 1), (r"line!", r"built_in", 1), (r"local_data_key!", r"built_in", 1), (r"loop", r"keyword", 1), (r"macro", r"keyword", 1), (r"macro_rules!", r"built_in", 1), (r"match", r"keyword", 1), (r"mod", r"keyword", 1), (r"module_path!", r"built_in", 1), (r"move", r"keyword", 1), (r"mut", r"keyword", 1), (r"option_env!", r"built_in", 1), (r"override", r"keyword", 1), (r"panic!", r"built_in", 1), (r"print!", r"built_in", 1), (r"println!", r"built_in", 1), (r"priv", r"keyword", 1), (r"pub", r"keyword", 1), (r"ref", r"keyword", 1), (r"return", r"keyword", 1), (r"select!", r"built_in", 1), (r"self", r"keyword", 1), (r"static", r"keyword", 1), (r"str", r"type", 1), (r"stringify!", r"built_in", 1), (r"struct", r"keyword", 1), (r"super", r"keyword", 1), (r"trait", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"try!", r"built_in", 1), (r"type", r"keyword", 1), (r"typeof", r"keyword", 1), (r"u128", r"type", 1), (r"u16", r"type", 1), (r"u32", r"type", 1), (r"u64", r"type", 1), (r"u8", r"type", 1), (r"unimplemented!", r"built_in", 1), (r"unreachable!", r"built_in", 1), (r"unsafe", r"keyword", 1), (r"unsized", r"keyword", 1), (r"use", r"keyword", 1), (r"usize", r"type", 1), (r"vec!", r"built_in", 1), (r"virtual", r"keyword", 1), (r"where", r"keyword", 1), (r"while", r"keyword", 1), (r"write!", r"built_in", 1), (r"writeln!", r"built_in", 1), (r"yield", r"keyword", 1)];
+
+static KEYWORDS_65: &[(&str, &str, u8)] = &[(r"AsMut", r"built_in", 1), (r"AsRef", r"built_in", 1), (r"Box", r"type", 1), (r"Clone", r"built_in", 1), (r"Copy", r"built_in", 1), (r"Debug", r"built_in", 1), (r"Default", r"built_in", 1), (r"DoubleEndedIterator", r"built_in", 1), (r"Drop", r"built_in", 1), (r"Eq", r"built_in", 1), (r"ExactSizeIterator", r"built_in", 1), (r"Extend", r"built_in", 1), (r"Fn", r"built_in", 1), (r"FnMut", r"built_in", 1), (r"FnOnce", r"built_in", 1), (r"From", r"built_in", 1), (r"Into", r"built_in", 1), (r"IntoIterator", r"built_in", 1), (r"Iterator", r"built_in", 1), (r"Option", r"type", 1), (r"Ord", r"built_in", 1), (r"PartialEq", r"built_in", 1), (r"PartialOrd", r"built_in", 1), (r"Result", r"type", 1), (r"Self", r"keyword", 1), (r"Send", r"built_in", 1), (r"Sized", r"built_in", 1), (r"SliceConcatExt", r"built_in", 1), (r"String", r"type", 1), (r"Sync", r"built_in", 1), (r"ToOwned", r"built_in", 1), (r"ToString", r"built_in", 1), (r"Vec", r"type", 1), (r"assert!", r"built_in", 1), (r"assert_eq!", r"built_in", 1), (r"assert_ne!", r"built_in", 1), (r"bitflags!", r"built_in", 1), (r"bool", r"type", 1), (r"bytes!", r"built_in", 1), (r"cfg!", r"built_in", 1), (r"char", r"type", 1), (r"col!", r"built_in", 1), (r"concat!", r"built_in", 1), (r"concat_idents!", r"built_in", 1), (r"debug_assert!", r"built_in", 1), (r"debug_assert_eq!", r"built_in", 1), (r"debug_assert_ne!", r"built_in", 1), (r"drop ", r"built_in", 1), (r"env!", r"built_in", 1), (r"f32", r"type", 1), (r"f64", r"type", 1), (r"file!", r"built_in", 1), (r"format!", r"built_in", 1), (r"format_args!", r"built_in", 1), (r"i128", r"type", 1), (r"i16", r"type", 1), (r"i32", r"type", 1), (r"i64", r"type", 1), (r"i8", r"type", 1), (r"include_bytes!", r"built_in", 1), (r"include_str!", r"built_in", 1), (r"isize", r"type", 1), (r"line!", r"built_in", 1), (r"local_data_key!", r"built_in", 1), (r"macro_rules!", r"built_in", 1), (r"module_path!", r"built_in", 1), (r"option_env!", r"built_in", 1), (r"panic!", r"built_in", 1), (r"print!", r"built_in", 1), (r"println!", r"built_in", 1), (r"select!", r"built_in", 1), (r"str", r"type", 1), (r"stringify!", r"built_in", 1), (r"try!", r"built_in", 1), (r"u128", r"type", 1), (r"u16", r"type", 1), (r"u32", r"type", 1), (r"u64", r"type", 1), (r"u8", r"type", 1), (r"unimplemented!", r"built_in", 1), (r"unreachable!", r"built_in", 1), (r"usize", r"type", 1), (r"vec!", r"built_in", 1), (r"write!", r"built_in", 1), (r"writeln!", r"built_in", 1)];
+
+static KEYWORDS_66: &[(&str, &str, u8)] = &[(r"@font-face", r"keyword", 1), (r"@page", r"keyword", 1)];
+
+static KEYWORDS_67: &[(&str, &str, u8)] = &[(r"add", r"keyword", 1), (r"all", r"keyword", 1), (r"allocate", r"keyword", 1), (r"alter", r"keyword", 1), (r"and", r"keyword", 0), (r"any", r"keyword", 1), (r"are", r"keyword", 1), (r"array", r"keyword", 1), (r"array_max_cardinality", r"keyword", 1), (r"as", r"keyword", 0), (r"asc", r"keyword", 1), (r"asensitive", r"keyword", 1), (r"asymmetric", r"keyword", 1), (r"at", r"keyword", 0), (r"atomic", r"keyword", 1), (r"authorization", r"keyword", 1), (r"begin", r"keyword", 1), (r"begin_frame", r"keyword", 1), (r"begin_partition", r"keyword", 1), (r"between", r"keyword", 1), (r"bigint", r"type", 1), (r"binary", r"type", 1), (r"blob", r"type", 1), (r"boolean", r"type", 1), (r"both", r"keyword", 1), (r"by", r"keyword", 0), (r"call", r"keyword", 1), (r"called", r"keyword", 1), (r"cardinality", r"keyword", 1), (r"cascaded", r"keyword", 1), (r"case", r"keyword", 1), (r"char", r"type", 1), (r"char_length", r"keyword", 1), (r"character", r"type", 1), (r"character_length", r"keyword", 1), (r"check", r"keyword", 1), (r"classifier", r"keyword", 1), (r"clob", r"type", 1), (r"close", r"keyword", 1), (r"collate", r"keyword", 1), (r"collation", r"keyword", 1), (r"collect", r"keyword", 1), (r"column", r"keyword", 1), (r"commit", r"keyword", 1), (r"condition", r"keyword", 1), (r"connect", r"keyword", 1), (r"constraint", r"keyword", 1), (r"contains", r"keyword", 1), (r"convert", r"keyword", 1), (r"copy", r"keyword", 1), (r"corresponding", r"keyword", 1), (r"create", r"keyword", 1), (r"cross", r"keyword", 1), (r"cube", r"keyword", 1), (r"current", r"keyword", 1), (r"current_catalog", r"built_in", 1), (r"current_date", r"built_in", 1), (r"current_default_transform_group", r"built_in", 1), (r"current_path", r"built_in", 1), (r"current_role", r"built_in", 1), (r"current_row", r"keyword", 1), (r"current_schema", r"built_in", 1), (r"current_time", r"built_in", 1), (r"current_timestamp", r"built_in", 1), (r"current_transform_group_for_type", r"built_in", 1), (r"current_user", r"built_in", 1), (r"cursor", r"keyword", 1), (r"cycle", r"keyword", 1), (r"date", r"type", 1), (r"day", r"keyword", 1), (r"deallocate", r"keyword", 1), (r"dec", r"type", 1), (r"decfloat", r"type", 1), (r"decimal", r"type", 1), (r"declare", r"keyword", 1), (r"default", r"keyword", 1), (r"define", r"keyword", 1), (r"delete", r"keyword", 1), (r"desc", r"keyword", 1), (r"describe", r"keyword", 1), (r"deterministic", r"keyword", 1), (r"disconnect", r"keyword", 1), (r"distinct", r"keyword", 1), (r"double", r"keyword", 1), (r"drop", r"keyword", 1), (r"dynamic", r"keyword", 1), (r"each", r"keyword", 1), (r"else", r"keyword", 1), (r"empty", r"keyword", 1), (r"end", r"keyword", 1), (r"end-exec", r"keyword", 1), (r"end_frame", r"keyword", 1), (r"end_partition", r"keyword", 1), (r"equals", r"keyword", 1), (r"escape", r"keyword", 1), (r"every", r"keyword", 1), (r"except", r"keyword", 1), (r"exec", r"keyword", 1), (r"execute", r"keyword", 1), (r"exists", r"keyword", 1), (r"external", r"keyword", 1), (r"false", r"literal", 1), (r"fetch", r"keyword", 1), (r"filter", r"keyword", 1), (r"final", r"keyword", 1), (r"first", r"keyword", 1), (r"float", r"type", 1), (r"for", r"keyword", 0), (r"foreign", r"keyword", 1), (r"frame_row", r"keyword", 1), (r"free", r"keyword", 1), (r"from", r"keyword", 1), (r"full", r"keyword", 1), (r"function", r"keyword", 1), (r"fusion", r"keyword", 1), (r"get", r"keyword", 1), (r"global", r"keyword", 1), (r"grant", r"keyword", 1), (r"group", r"keyword", 1), (r"grouping", r"keyword", 1), (r"groups", r"keyword", 1), (r"having", r"keyword", 1), (r"hold", r"keyword", 1), (r"hour", r"keyword", 1), (r"identity", r"keyword", 1), (r"in", r"keyword", 0), (r"indicator", r"keyword", 1), (r"initial", r"keyword", 1), (r"inner", r"keyword", 1), (r"inout", r"keyword", 1), (r"insensitive", r"keyword", 1), (r"insert", r"keyword", 1), (r"int", r"type", 1), (r"integer", r"type", 1), (r"intersect", r"keyword", 1), (r"intersection", r"keyword", 1), (r"interval", r"type", 1), (r"into", r"keyword", 1), (r"is", r"keyword", 0), (r"join", r"keyword", 1), (r"language", r"keyword", 1), (r"large", r"keyword", 1), (r"last", r"keyword", 1), (r"lateral", r"keyword", 1), (r"leading", r"keyword", 1), (r"left", r"keyword", 1), (r"like", r"keyword", 1), (r"like_regex", r"keyword", 1), (r"local", r"keyword", 1), (r"localtime", r"built_in", 1), (r"localtimestamp", r"built_in", 1), (r"match", r"keyword", 1), (r"match_number", r"keyword", 1), (r"match_recognize", r"keyword", 1), (r"matches", r"keyword", 1), (r"member", r"keyword", 1), (r"merge", r"keyword", 1), (r"method", r"keyword", 1), (r"minute", r"keyword", 1), (r"modifies", r"keyword", 1), (r"module", r"keyword", 1), (r"month", r"keyword", 1), (r"multiset", r"keyword", 1), (r"national", r"type", 1), (r"natural", r"keyword", 1), (r"nchar", r"type", 1), (r"nclob", r"type", 1), (r"new", r"keyword", 1), (r"no", r"keyword", 0), (r"none", r"keyword", 1), (r"normalize", r"keyword", 1), (r"not", r"keyword", 0), (r"null", r"keyword", 1), (r"numeric", r"type", 1), (r"occurrences_regex", r"keyword", 1), (r"octet_length", r"keyword", 1), (r"of", r"keyword", 0), (r"offset", r"keyword", 1), (r"old", r"keyword", 1), (r"omit", r"keyword", 1), (r"on", r"keyword", 0), (r"one", r"keyword", 1), (r"only", r"keyword", 1), (r"open", r"keyword", 1), (r"or", r"keyword", 0), (r"order", r"keyword", 1), (r"out", r"keyword", 1), (r"outer", r"keyword", 1), (r"over", r"keyword", 1), (r"overlaps", r"keyword", 1), (r"overlay", r"keyword", 1), (r"parameter", r"keyword", 1), (r"partition", r"keyword", 1), (r"pattern", r"keyword", 1), (r"per", r"keyword", 1), (r"percent", r"keyword", 1), (r"period", r"keyword", 1), (r"portion", r"keyword", 1), (r"precedes", r"keyword", 1), (r"precision", r"keyword", 1), (r"prepare", r"keyword", 1), (r"primary", r"keyword", 1), (r"procedure", r"keyword", 1), (r"ptf", r"keyword", 1), (r"range", r"keyword", 1), (r"reads", r"keyword", 1), (r"real", r"type", 1), (r"recursive", r"keyword", 1), (r"ref", r"keyword", 1), (r"references", r"keyword", 1), (r"referencing", r"keyword", 1), (r"release", r"keyword", 1), (r"result", r"keyword", 1), (r"return", r"keyword", 1), (r"returns", r"keyword", 1), (r"revoke", r"keyword", 1), (r"right", r"keyword", 1), (r"rollback", r"keyword", 1), (r"rollup", r"keyword", 1), (r"row", r"type", 1), (r"rows", r"keyword", 1), (r"running", r"keyword", 1), (r"savepoint", r"keyword", 1), (r"scope", r"keyword", 1), (r"scroll", r"keyword", 1), (r"search", r"keyword", 1), (r"second", r"keyword", 1), (r"seek", r"keyword", 1), (r"select", r"keyword", 1), (r"sensitive", r"keyword", 1), (r"session_user", r"built_in", 1), (r"set", r"keyword", 1), (r"show", r"keyword", 1), (r"similar", r"keyword", 1), (r"skip", r"keyword", 1), (r"smallint", r"type", 1), (r"some", r"keyword", 1), (r"specific", r"keyword", 1), (r"specifictype", r"keyword", 1), (r"sql", r"keyword", 1), (r"sqlexception", r"keyword", 1), (r"sqlstate", r"keyword", 1), (r"sqlwarning", r"keyword", 1), (r"start", r"keyword", 1), (r"static", r"keyword", 1), (r"submultiset", r"keyword", 1), (r"subset", r"keyword", 1), (r"succeeds", r"keyword", 1), (r"symmetric", r"keyword", 1), (r"system", r"keyword", 1), (r"system_time", r"built_in", 1), (r"system_user", r"built_in", 1), (r"table", r"keyword", 1), (r"tablesample", r"keyword", 1), (r"then", r"keyword", 0), (r"time", r"type", 1), (r"timestamp", r"type", 1), (r"timezone_hour", r"keyword", 1), (r"timezone_minute", r"keyword", 1), (r"to", r"keyword", 0), (r"trailing", r"keyword", 1), (r"translation", r"keyword", 1), (r"trigger", r"keyword", 1), (r"true", r"literal", 1), (r"truncate", r"keyword", 1), (r"uescape", r"keyword", 1), (r"union", r"keyword", 1), (r"unique", r"keyword", 1), (r"unknown", r"literal", 1), (r"update", r"keyword", 1), (r"user", r"keyword", 1), (r"using", r"keyword", 1), (r"value", r"keyword", 0), (r"values", r"keyword", 1), (r"varbinary", r"type", 1), (r"varchar", r"type", 1), (r"varying", r"type", 1), (r"versioning", r"keyword", 1), (r"view", r"keyword", 1), (r"when", r"keyword", 1), (r"whenever", r"keyword", 1), (r"where", r"keyword", 1), (r"window", r"keyword", 1), (r"with", r"keyword", 1), (r"within", r"keyword", 1), (r"without", r"keyword", 1), (r"year", r"keyword", 1)];
+
+static KEYWORDS_68: &[(&str, &str, u8)] = &[(r"add", r"keyword", 1), (r"add constraint", r"keyword", 1), (r"all", r"keyword", 1), (r"allocate", r"keyword", 1), (r"alter", r"keyword", 1), (r"alter table", r"keyword", 1), (r"and", r"keyword", 0), (r"any", r"keyword", 1), (r"are", r"keyword", 1), (r"array", r"keyword", 1), (r"array_max_cardinality", r"keyword", 1), (r"as", r"keyword", 1), (r"asc", r"keyword", 1), (r"asensitive", r"keyword", 1), (r"asymmetric", r"keyword", 1), (r"at", r"keyword", 1), (r"atomic", r"keyword", 1), (r"authorization", r"keyword", 1), (r"begin", r"keyword", 1), (r"begin_frame", r"keyword", 1), (r"begin_partition", r"keyword", 1), (r"between", r"keyword", 1), (r"bigint", r"type", 1), (r"binary", r"type", 1), (r"blob", r"type", 1), (r"boolean", r"type", 1), (r"both", r"keyword", 1), (r"breadth first", r"keyword", 1), (r"by", r"keyword", 1), (r"call", r"keyword", 1), (r"called", r"keyword", 1), (r"cardinality", r"keyword", 1), (r"cascaded", r"keyword", 1), (r"case", r"keyword", 1), (r"char", r"type", 1), (r"char_length", r"keyword", 1), (r"character", r"type", 1), (r"character set", r"keyword", 1), (r"character_length", r"keyword", 1), (r"check", r"keyword", 1), (r"classifier", r"keyword", 1), (r"clob", r"type", 1), (r"close", r"keyword", 1), (r"collate", r"keyword", 1), (r"collation", r"keyword", 1), (r"collect", r"keyword", 1), (r"column", r"keyword", 1), (r"commit", r"keyword", 1), (r"condition", r"keyword", 1), (r"connect", r"keyword", 1), (r"constraint", r"keyword", 1), (r"contains", r"keyword", 1), (r"convert", r"keyword", 1), (r"copy", r"keyword", 1), (r"corresponding", r"keyword", 1), (r"create", r"keyword", 1), (r"create table", r"keyword", 1), (r"cross", r"keyword", 1), (r"cube", r"keyword", 1), (r"current", r"keyword", 1), (r"current_catalog", r"keyword", 1), (r"current_date", r"keyword", 1), (r"current_default_transform_group", r"keyword", 1), (r"current_path", r"keyword", 1), (r"current_role", r"keyword", 1), (r"current_row", r"keyword", 1), (r"current_schema", r"keyword", 1), (r"current_time", r"keyword", 1), (r"current_timestamp", r"keyword", 1), (r"current_transform_group_for_type", r"keyword", 1), (r"current_user", r"keyword", 1), (r"cursor", r"keyword", 1), (r"cycle", r"keyword", 1), (r"date", r"type", 1), (r"day", r"keyword", 1), (r"deallocate", r"keyword", 1), (r"dec", r"type", 1), (r"decfloat", r"type", 1), (r"decimal", r"type", 1), (r"declare", r"keyword", 1), (r"default", r"keyword", 1), (r"define", r"keyword", 1), (r"delete", r"keyword", 1), (r"depth first", r"keyword", 1), (r"desc", r"keyword", 1), (r"describe", r"keyword", 1), (r"deterministic", r"keyword", 1), (r"disconnect", r"keyword", 1), (r"distinct", r"keyword", 1), (r"double", r"keyword", 1), (r"drop", r"keyword", 1), (r"dynamic", r"keyword", 1), (r"each", r"keyword", 1), (r"else", r"keyword", 1), (r"empty", r"keyword", 1), (r"end", r"keyword", 1), (r"end-exec", r"keyword", 1), (r"end_frame", r"keyword", 1), (r"end_partition", r"keyword", 1), (r"equals", r"keyword", 1), (r"escape", r"keyword", 1), (r"every", r"keyword", 1), (r"except", r"keyword", 1), (r"exec", r"keyword", 1), (r"execute", r"keyword", 1), (r"exists", r"keyword", 1), (r"external", r"keyword", 1), (r"false", r"literal", 1), (r"fetch", r"keyword", 1), (r"filter", r"keyword", 1), (r"final", r"keyword", 1), (r"first", r"keyword", 1), (r"float", r"type", 1), (r"for", r"keyword", 0), (r"foreign", r"keyword", 1), (r"foreign key", r"keyword", 1), (r"frame_row", r"keyword", 1), (r"free", r"keyword", 1), (r"from", r"keyword", 1), (r"full", r"keyword", 1), (r"function", r"keyword", 1), (r"fusion", r"keyword", 1), (r"get", r"keyword", 1), (r"global", r"keyword", 1), (r"grant", r"keyword", 1), (r"group", r"keyword", 1), (r"grouping", r"keyword", 1), (r"grouping sets", r"keyword", 1), (r"groups", r"keyword", 1), (r"having", r"keyword", 1), (r"hold", r"keyword", 1), (r"hour", r"keyword", 1), (r"identity", r"keyword", 1), (r"ignore nulls", r"keyword", 1), (r"in", r"keyword", 0), (r"indicator", r"keyword", 1), (r"initial", r"keyword", 1), (r"inner", r"keyword", 1), (r"inout", r"keyword", 1), (r"insensitive", r"keyword", 1), (r"insert", r"keyword", 1), (r"insert into", r"keyword", 1), (r"int", r"type", 1), (r"integer", r"type", 1), (r"intersect", r"keyword", 1), (r"intersection", r"keyword", 1), (r"interval", r"type", 1), (r"into", r"keyword", 1), (r"is", r"keyword", 1), (r"join", r"keyword", 1), (r"language", r"keyword", 1), (r"large", r"keyword", 1), (r"last", r"keyword", 1), (r"lateral", r"keyword", 1), (r"leading", r"keyword", 1), (r"left", r"keyword", 1), (r"like", r"keyword", 1), (r"like_regex", r"keyword", 1), (r"local", r"keyword", 1), (r"localtime", r"keyword", 1), (r"localtimestamp", r"keyword", 1), (r"match", r"keyword", 1), (r"match_number", r"keyword", 1), (r"match_recognize", r"keyword", 1), (r"matches", r"keyword", 1), (r"member", r"keyword", 1), (r"merge", r"keyword", 1), (r"method", r"keyword", 1), (r"minute", r"keyword", 1), (r"modifies", r"keyword", 1), (r"module", r"keyword", 1), (r"month", r"keyword", 1), (r"multiset", r"keyword", 1), (r"national", r"type", 1), (r"natural", r"keyword", 1), (r"nchar", r"type", 1), (r"nclob", r"type", 1), (r"new", r"keyword", 1), (r"no", r"keyword", 1), (r"none", r"keyword", 1), (r"normalize", r"keyword", 1), (r"not", r"keyword", 0), (r"not null", r"keyword", 1), (r"null", r"keyword", 1), (r"nulls first", r"keyword", 1), (r"nulls last", r"keyword", 1), (r"numeric", r"type", 1), (r"occurrences_regex", r"keyword", 1), (r"octet_length", r"keyword", 1), (r"of", r"keyword", 0), (r"offset", r"keyword", 1), (r"old", r"keyword", 1), (r"omit", r"keyword", 1), (r"on", r"keyword", 1), (r"on overflow", r"keyword", 1), (r"one", r"keyword", 1), (r"only", r"keyword", 1), (r"open", r"keyword", 1), (r"or", r"keyword", 0), (r"order", r"keyword", 1), (r"out", r"keyword", 1), (r"outer", r"keyword", 1), (r"over", r"keyword", 1), (r"overlaps", r"keyword", 1), (r"overlay", r"keyword", 1), (r"parameter", r"keyword", 1), (r"partition", r"keyword", 1), (r"pattern", r"keyword", 1), (r"per", r"keyword", 1), (r"percent", r"keyword", 1), (r"period", r"keyword", 1), (r"portion", r"keyword", 1), (r"precedes", r"keyword", 1), (r"precision", r"keyword", 1), (r"prepare", r"keyword", 1), (r"primary", r"keyword", 1), (r"primary key", r"keyword", 1), (r"procedure", r"keyword", 1), (r"ptf", r"keyword", 1), (r"range", r"keyword", 1), (r"reads", r"keyword", 1), (r"real", r"type", 1), (r"recursive", r"keyword", 1), (r"ref", r"keyword", 1), (r"references", r"keyword", 1), (r"referencing", r"keyword", 1), (r"release", r"keyword", 1), (r"respect nulls", r"keyword", 1), (r"result", r"keyword", 1), (r"return", r"keyword", 1), (r"returns", r"keyword", 1), (r"revoke", r"keyword", 1), (r"right", r"keyword", 1), (r"rollback", r"keyword", 1), (r"rollup", r"keyword", 1), (r"row", r"type", 1), (r"rows", r"keyword", 1), (r"running", r"keyword", 1), (r"savepoint", r"keyword", 1), (r"scope", r"keyword", 1), (r"scroll", r"keyword", 1), (r"search", r"keyword", 1), (r"second", r"keyword", 1), (r"seek", r"keyword", 1), (r"select", r"keyword", 1), (r"sensitive", r"keyword", 1), (r"session_user", r"keyword", 1), (r"set", r"keyword", 1), (r"show", r"keyword", 1), (r"similar", r"keyword", 1), (r"skip", r"keyword", 1), (r"smallint", r"type", 1), (r"some", r"keyword", 1), (r"specific", r"keyword", 1), (r"specifictype", r"keyword", 1), (r"sql", r"keyword", 1), (r"sqlexception", r"keyword", 1), (r"sqlstate", r"keyword", 1), (r"sqlwarning", r"keyword", 1), (r"start", r"keyword", 1), (r"static", r"keyword", 1), (r"submultiset", r"keyword", 1), (r"subset", r"keyword", 1), (r"succeeds", r"keyword", 1), (r"symmetric", r"keyword", 1), (r"system", r"keyword", 1), (r"system_time", r"keyword", 1), (r"system_user", r"keyword", 1), (r"table", r"keyword", 1), (r"tablesample", r"keyword", 1), (r"then", r"keyword", 0), (r"time", r"type", 1), (r"timestamp", r"type", 1), (r"timezone_hour", r"keyword", 1), (r"timezone_minute", r"keyword", 1), (r"to", r"keyword", 1), (r"trailing", r"keyword", 1), (r"translation", r"keyword", 1), (r"trigger", r"keyword", 1), (r"true", r"literal", 1), (r"truncate", r"keyword", 1), (r"uescape", r"keyword", 1), (r"union", r"keyword", 1), (r"unique", r"keyword", 1), (r"unknown", r"literal", 1), (r"update", r"keyword", 1), (r"user", r"keyword", 1), (r"using", r"keyword", 1), (r"value", r"keyword", 0), (r"values", r"keyword", 1), (r"varbinary", r"type", 1), (r"varchar", r"type", 1), (r"varying", r"type", 1), (r"versioning", r"keyword", 1), (r"view", r"keyword", 1), (r"when", r"keyword", 1), (r"whenever", r"keyword", 1), (r"where", r"keyword", 1), (r"window", r"keyword", 1), (r"with", r"keyword", 1), (r"within", r"keyword", 1), (r"without", r"keyword", 1), (r"year", r"keyword", 1)];
+
+static KEYWORDS_69: &[(&str, &str, u8)] = &[(r"abs", r"built_in", 1), (r"acos", r"built_in", 1), (r"array_agg", r"built_in", 1), (r"asin", r"built_in", 1), (r"atan", r"built_in", 1), (r"avg", r"built_in", 1), (r"cast", r"built_in", 1), (r"ceil", r"built_in", 1), (r"ceiling", r"built_in", 1), (r"coalesce", r"built_in", 1), (r"corr", r"built_in", 1), (r"cos", r"built_in", 1), (r"cosh", r"built_in", 1), (r"count", r"built_in", 1), (r"covar_pop", r"built_in", 1), (r"covar_samp", r"built_in", 1), (r"cume_dist", r"built_in", 1), (r"dense_rank", r"built_in", 1), (r"deref", r"built_in", 1), (r"element", r"built_in", 1), (r"exp", r"built_in", 1), (r"extract", r"built_in", 1), (r"first_value", r"built_in", 1), (r"floor", r"built_in", 1), (r"json_array", r"built_in", 1), (r"json_arrayagg", r"built_in", 1), (r"json_exists", r"built_in", 1), (r"json_object", r"built_in", 1), (r"json_objectagg", r"built_in", 1), (r"json_query", r"built_in", 1), (r"json_table", r"built_in", 1), (r"json_table_primitive", r"built_in", 1), (r"json_value", r"built_in", 1), (r"lag", r"built_in", 1), (r"last_value", r"built_in", 1), (r"lead", r"built_in", 1), (r"listagg", r"built_in", 1), (r"ln", r"built_in", 1), (r"log", r"built_in", 1), (r"log10", r"built_in", 1), (r"lower", r"built_in", 1), (r"max", r"built_in", 1), (r"min", r"built_in", 1), (r"mod", r"built_in", 1), (r"nth_value", r"built_in", 1), (r"ntile", r"built_in", 1), (r"nullif", r"built_in", 1), (r"percent_rank", r"built_in", 1), (r"percentile_cont", r"built_in", 1), (r"percentile_disc", r"built_in", 1), (r"position", r"built_in", 1), (r"position_regex", r"built_in", 1), (r"power", r"built_in", 1), (r"rank", r"built_in", 1), (r"regr_avgx", r"built_in", 1), (r"regr_avgy", r"built_in", 1), (r"regr_count", r"built_in", 1), (r"regr_intercept", r"built_in", 1), (r"regr_r2", r"built_in", 1), (r"regr_slope", r"built_in", 1), (r"regr_sxx", r"built_in", 1), (r"regr_sxy", r"built_in", 1), (r"regr_syy", r"built_in", 1), (r"row_number", r"built_in", 1), (r"sin", r"built_in", 1), (r"sinh", r"built_in", 1), (r"sqrt", r"built_in", 1), (r"stddev_pop", r"built_in", 1), (r"stddev_samp", r"built_in", 1), (r"substring", r"built_in", 1), (r"substring_regex", r"built_in", 1), (r"sum", r"built_in", 1), (r"tan", r"built_in", 1), (r"tanh", r"built_in", 1), (r"translate", r"built_in", 1), (r"translate_regex", r"built_in", 1), (r"treat", r"built_in", 1), (r"trim", r"built_in", 1), (r"trim_array", r"built_in", 1), (r"unnest", r"built_in", 1), (r"upper", r"built_in", 1), (r"value_of", r"built_in", 1), (r"var_pop", r"built_in", 1), (r"var_samp", r"built_in", 1), (r"width_bucket", r"built_in", 1)];
+
+static KEYWORDS_70: &[(&str, &str, u8)] = &[(r"#colorLiteral", r"keyword", 1), (r"#column", r"keyword", 1), (r"#dsohandle", r"keyword", 1), (r"#else", r"keyword", 1), (r"#elseif", r"keyword", 1), (r"#endif", r"keyword", 1), (r"#error", r"keyword", 1), (r"#file", r"keyword", 1), (r"#fileID", r"keyword", 1), (r"#fileLiteral", r"keyword", 1), (r"#filePath", r"keyword", 1), (r"#function", r"keyword", 1), (r"#if", r"keyword", 1), (r"#imageLiteral", r"keyword", 1), (r"#keyPath", r"keyword", 1), (r"#line", r"keyword", 1), (r"#selector", r"keyword", 1), (r"#sourceLocation", r"keyword", 1), (r"#warn_unqualified_access", r"keyword", 1), (r"#warning", r"keyword", 1), (r"_", r"keyword", 0), (r"actor", r"keyword", 1), (r"any", r"keyword", 1), (r"as", r"keyword", 1), (r"associatedtype", r"keyword", 1), (r"async", r"keyword", 1), (r"await", r"keyword", 1), (r"break", r"keyword", 1), (r"case", r"keyword", 1), (r"catch", r"keyword", 1), (r"class", r"keyword", 1), (r"continue", r"keyword", 1), (r"convenience", r"keyword", 1), (r"default", r"keyword", 1), (r"defer", r"keyword", 1), (r"deinit", r"keyword", 1), (r"didSet", r"keyword", 1), (r"distributed", r"keyword", 1), (r"do", r"keyword", 1), (r"dynamic", r"keyword", 1), (r"else", r"keyword", 1), (r"enum", r"keyword", 1), (r"extension", r"keyword", 1), (r"fallthrough", r"keyword", 1), (r"false", r"literal", 1), (r"fileprivate", r"keyword", 1), (r"final", r"keyword", 1), (r"for", r"keyword", 0), (r"func", r"keyword", 1), (r"get", r"keyword", 1), (r"guard", r"keyword", 1), (r"if", r"keyword", 0), (r"import", r"keyword", 1), (r"in", r"keyword", 0), (r"indirect", r"keyword", 1), (r"infix", r"keyword", 1), (r"inout", r"keyword", 1), (r"internal", r"keyword", 1), (r"is", r"keyword", 1), (r"isolated", r"keyword", 1), (r"lazy", r"keyword", 1), (r"let", r"keyword", 1), (r"mutating", r"keyword", 1), (r"nil", r"literal", 1), (r"nonisolated", r"keyword", 1), (r"nonmutating", r"keyword", 1), (r"open", r"keyword", 1), (r"operator", r"keyword", 1), (r"optional", r"keyword", 1), (r"override", r"keyword", 1), (r"postfix", r"keyword", 1), (r"precedencegroup", r"keyword", 1), (r"prefix", r"keyword", 1), (r"private", r"keyword", 1), (r"protocol", r"keyword", 1), (r"public", r"keyword", 1), (r"repeat", r"keyword", 1), (r"required", r"keyword", 1), (r"rethrows", r"keyword", 1), (r"return", r"keyword", 1), (r"set", r"keyword", 1), (r"some", r"keyword", 1), (r"static", r"keyword", 1), (r"struct", r"keyword", 1), (r"subscript", r"keyword", 1), (r"super", r"keyword", 1), (r"switch", r"keyword", 1), (r"throw", r"keyword", 1), (r"throws", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"typealias", r"keyword", 1), (r"unowned", r"keyword", 1), (r"var", r"keyword", 1), (r"weak", r"keyword", 1), (r"where", r"keyword", 1), (r"while", r"keyword", 1), (r"willSet", r"keyword", 1)];
+
+static KEYWORDS_71: &[(&str, &str, u8)] = &[(r"assignment", r"keyword", 1), (r"associativity", r"keyword", 1), (r"false", r"keyword", 1), (r"higherThan", r"keyword", 1), (r"left", r"keyword", 1), (r"lowerThan", r"keyword", 1), (r"nil", r"keyword", 1), (r"none", r"keyword", 1), (r"right", r"keyword", 1), (r"true", r"keyword", 1)];
+
+static KEYWORDS_72: &[(&str, &str, u8)] = &[(r"_", r"keyword", 0)];
+
+static KEYWORDS_73: &[(&str, &str, u8)] = &[(r"iOS", r"keyword", 1), (r"iOSApplicationExtension", r"keyword", 1), (r"macCatalyst", r"keyword", 1), (r"macCatalystApplicationExtension", r"keyword", 1), (r"macOS", r"keyword", 1), (r"macOSApplicationExtension", r"keyword", 1), (r"swift", r"keyword", 1), (r"tvOS", r"keyword", 1), (r"tvOSApplicationExtension", r"keyword", 1), (r"watchOS", r"keyword", 1), (r"watchOSApplicationExtension", r"keyword", 1)];
+
+static KEYWORDS_74: &[(&str, &str, u8)] = &[(r"Array", r"built_in", 1), (r"ArrayBuffer", r"built_in", 1), (r"AsyncFunction", r"built_in", 1), (r"Atomics", r"built_in", 1), (r"BigInt", r"built_in", 1), (r"BigInt64Array", r"built_in", 1), (r"BigUint64Array", r"built_in", 1), (r"Boolean", r"built_in", 1), (r"DataView", r"built_in", 1), (r"Date", r"built_in", 1), (r"Error", r"built_in", 1), (r"EvalError", r"built_in", 1), (r"Float32Array", r"built_in", 1), (r"Float64Array", r"built_in", 1), (r"Function", r"built_in", 1), (r"Generator", r"built_in", 1), (r"GeneratorFunction", r"built_in", 1), (r"Infinity", r"literal", 1), (r"Int16Array", r"built_in", 1), (r"Int32Array", r"built_in", 1), (r"Int8Array", r"built_in", 1), (r"InternalError", r"built_in", 1), (r"Intl", r"built_in", 1), (r"JSON", r"built_in", 1), (r"Map", r"built_in", 1), (r"Math", r"built_in", 1), (r"NaN", r"literal", 1), (r"Number", r"built_in", 1), (r"Object", r"built_in", 1), (r"Promise", r"built_in", 1), (r"Proxy", r"built_in", 1), (r"RangeError", r"built_in", 1), (r"ReferenceError", r"built_in", 1), (r"Reflect", r"built_in", 1), (r"RegExp", r"built_in", 1), (r"Set", r"built_in", 1), (r"SharedArrayBuffer", r"built_in", 1), (r"String", r"built_in", 1), (r"Symbol", r"built_in", 1), (r"SyntaxError", r"built_in", 1), (r"TypeError", r"built_in", 1), (r"URIError", r"built_in", 1), (r"Uint16Array", r"built_in", 1), (r"Uint32Array", r"built_in", 1), (r"Uint8Array", r"built_in", 1), (r"Uint8ClampedArray", r"built_in", 1), (r"WeakMap", r"built_in", 1), (r"WeakSet", r"built_in", 1), (r"WebAssembly", r"built_in", 1), (r"abstract", r"keyword", 1), (r"any", r"built_in", 1), (r"arguments", r"variable.language", 1), (r"as", r"keyword", 1), (r"async", r"keyword", 1), (r"await", r"keyword", 1), (r"bigint", r"built_in", 1), (r"boolean", r"built_in", 1), (r"break", r"keyword", 1), (r"case", r"keyword", 1), (r"catch", r"keyword", 1), (r"class", r"keyword", 1), (r"clearInterval", r"built_in", 1), (r"clearTimeout", r"built_in", 1), (r"console", r"variable.language", 1), (r"const", r"keyword", 1), (r"continue", r"keyword", 1), (r"debugger", r"keyword", 1), (r"declare", r"keyword", 1), (r"decodeURI", r"built_in", 1), (r"decodeURIComponent", r"built_in", 1), (r"default", r"keyword", 1), (r"delete", r"keyword", 1), (r"do", r"keyword", 1), (r"document", r"variable.language", 1), (r"else", r"keyword", 1), (r"encodeURI", r"built_in", 1), (r"encodeURIComponent", r"built_in", 1), (r"enum", r"keyword", 1), (r"escape", r"built_in", 1), (r"eval", r"built_in", 1), (r"export", r"keyword", 1), (r"exports", r"built_in", 1), (r"extends", r"keyword", 1), (r"false", r"literal", 1), (r"finally", r"keyword", 1), (r"for", r"keyword", 0), (r"from", r"keyword", 1), (r"function", r"keyword", 1), (r"global", r"variable.language", 1), (r"if", r"keyword", 0), (r"implements", r"keyword", 1), (r"import", r"keyword", 1), (r"in", r"keyword", 0), (r"instanceof", r"keyword", 1), (r"interface", r"keyword", 1), (r"isFinite", r"built_in", 1), (r"isNaN", r"built_in", 1), (r"let", r"keyword", 1), (r"localStorage", r"variable.language", 1), (r"module", r"variable.language", 1), (r"namespace", r"keyword", 1), (r"never", r"built_in", 1), (r"new", r"keyword", 1), (r"null", r"literal", 1), (r"number", r"built_in", 1), (r"object", r"built_in", 1), (r"of", r"keyword", 0), (r"override", r"keyword", 1), (r"parseFloat", r"built_in", 1), (r"parseInt", r"built_in", 1), (r"private", r"keyword", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"readonly", r"keyword", 1), (r"require", r"built_in", 1), (r"return", r"keyword", 1), (r"setInterval", r"built_in", 1), (r"setTimeout", r"built_in", 1), (r"static", r"keyword", 1), (r"string", r"built_in", 1), (r"super", r"variable.language", 1), (r"switch", r"keyword", 1), (r"symbol", r"built_in", 1), (r"this", r"variable.language", 1), (r"throw", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"type", r"keyword", 1), (r"typeof", r"keyword", 1), (r"undefined", r"literal", 1), (r"unescape", r"built_in", 1), (r"unknown", r"built_in", 1), (r"var", r"keyword", 1), (r"void", r"built_in", 1), (r"while", r"keyword", 1), (r"window", r"variable.language", 1), (r"with", r"keyword", 1), (r"yield", r"keyword", 1)];
+
+static KEYWORDS_75: &[(&str, &str, u8)] = &[(r"any", r"built_in", 1), (r"bigint", r"built_in", 1), (r"boolean", r"built_in", 1), (r"extends", r"keyword", 1), (r"interface", r"keyword", 1), (r"never", r"built_in", 1), (r"number", r"built_in", 1), (r"object", r"built_in", 1), (r"string", r"built_in", 1), (r"symbol", r"built_in", 1), (r"unknown", r"built_in", 1), (r"void", r"built_in", 1)];
+
+static KEYWORDS_76: &[(&str, &str, u8)] = &[(r"addhandler", r"keyword", 1), (r"addressof", r"built_in", 1), (r"aggregate", r"keyword", 1), (r"alias", r"keyword", 1), (r"and", r"built_in", 0), (r"andalso", r"built_in", 1), (r"ansi", r"keyword", 1), (r"as", r"keyword", 1), (r"assembly", r"keyword", 1), (r"async", r"keyword", 1), (r"auto", r"keyword", 1), (r"await", r"built_in", 1), (r"binary", r"keyword", 1), (r"boolean", r"type", 1), (r"by", r"keyword", 1), (r"byref", r"keyword", 1), (r"byte", r"type", 1), (r"byval", r"keyword", 1), (r"call", r"keyword", 1), (r"case", r"keyword", 1), (r"catch", r"keyword", 1), (r"cbool", r"built_in", 1), (r"cbyte", r"built_in", 1), (r"cchar", r"built_in", 1), (r"cdate", r"built_in", 1), (r"cdbl", r"built_in", 1), (r"cdec", r"built_in", 1), (r"char", r"type", 1), (r"cint", r"built_in", 1), (r"class", r"keyword", 1), (r"clng", r"built_in", 1), (r"cobj", r"built_in", 1), (r"compare", r"keyword", 1), (r"const", r"keyword", 1), (r"continue", r"keyword", 1), (r"csbyte", r"built_in", 1), (r"cshort", r"built_in", 1), (r"csng", r"built_in", 1), (r"cstr", r"built_in", 1), (r"cuint", r"built_in", 1), (r"culng", r"built_in", 1), (r"cushort", r"built_in", 1), (r"custom", r"keyword", 1), (r"date", r"type", 1), (r"decimal", r"type", 1), (r"declare", r"keyword", 1), (r"default", r"keyword", 1), (r"delegate", r"keyword", 1), (r"dim", r"keyword", 1), (r"directcast", r"built_in", 1), (r"distinct", r"keyword", 1), (r"do", r"keyword", 1), (r"double", r"type", 1), (r"each", r"keyword", 1), (r"else", r"keyword", 1), (r"elseif", r"keyword", 1), (r"end", r"keyword", 1), (r"enum", r"keyword", 1), (r"equals", r"keyword", 1), (r"erase", r"keyword", 1), (r"error", r"keyword", 1), (r"event", r"keyword", 1), (r"exit", r"keyword", 1), (r"explicit", r"keyword", 1), (r"false", r"literal", 1), (r"finally", r"keyword", 1), (r"for", r"keyword", 0), (r"friend", r"keyword", 1), (r"from", r"keyword", 1), (r"function", r"keyword", 1), (r"get", r"keyword", 1), (r"gettype", r"built_in", 1), (r"getxmlnamespace", r"built_in", 1), (r"global", r"keyword", 1), (r"goto", r"keyword", 1), (r"group", r"keyword", 1), (r"handles", r"keyword", 1), (r"if", r"keyword", 0), (r"implements", r"keyword", 1), (r"imports", r"keyword", 1), (r"in", r"keyword", 0), (r"inherits", r"keyword", 1), (r"integer", r"type", 1), (r"interface", r"keyword", 1), (r"into", r"keyword", 1), (r"is", r"built_in", 1), (r"isfalse", r"built_in", 1), (r"isnot", r"built_in", 1), (r"istrue", r"built_in", 1), (r"iterator", r"keyword", 1), (r"join", r"keyword", 1), (r"key", r"keyword", 1), (r"let", r"keyword", 1), (r"lib", r"keyword", 1), (r"like", r"built_in", 1), (r"long", r"type", 1), (r"loop", r"keyword", 1), (r"me", r"keyword", 1), (r"mid", r"keyword", 1), (r"mod", r"built_in", 1), (r"module", r"keyword", 1), (r"mustinherit", r"keyword", 1), (r"mustoverride", r"keyword", 1), (r"mybase", r"keyword", 1), (r"myclass", r"keyword", 1), (r"nameof", r"built_in", 1), (r"namespace", r"keyword", 1), (r"narrowing", r"keyword", 1), (r"new", r"built_in", 1), (r"next", r"keyword", 1), (r"not", r"built_in", 0), (r"nothing", r"literal", 1), (r"notinheritable", r"keyword", 1), (r"notoverridable", r"keyword", 1), (r"object", r"type", 1), (r"of", r"keyword", 0), (r"off", r"keyword", 1), (r"on", r"keyword", 1), (r"operator", r"keyword", 1), (r"option", r"keyword", 1), (r"optional", r"keyword", 1), (r"or", r"built_in", 0), (r"order", r"keyword", 1), (r"orelse", r"built_in", 1), (r"overloads", r"keyword", 1), (r"overridable", r"keyword", 1), (r"overrides", r"keyword", 1), (r"paramarray", r"keyword", 1), (r"partial", r"keyword", 1), (r"preserve", r"keyword", 1), (r"private", r"keyword", 1), (r"property", r"keyword", 1), (r"protected", r"keyword", 1), (r"public", r"keyword", 1), (r"raiseevent", r"keyword", 1), (r"readonly", r"keyword", 1), (r"redim", r"keyword", 1), (r"removehandler", r"keyword", 1), (r"resume", r"keyword", 1), (r"return", r"keyword", 1), (r"sbyte", r"type", 1), (r"select", r"keyword", 1), (r"set", r"keyword", 1), (r"shadows", r"keyword", 1), (r"shared", r"keyword", 1), (r"short", r"type", 1), (r"single", r"type", 1), (r"skip", r"keyword", 1), (r"static", r"keyword", 1), (r"step", r"keyword", 1), (r"stop", r"keyword", 1), (r"strict", r"keyword", 1), (r"string", r"type", 1), (r"structure", r"keyword", 1), (r"sub", r"keyword", 1), (r"synclock", r"keyword", 1), (r"take", r"keyword", 1), (r"text", r"keyword", 1), (r"then", r"keyword", 0), (r"throw", r"keyword", 1), (r"to", r"keyword", 1), (r"true", r"literal", 1), (r"try", r"keyword", 1), (r"trycast", r"built_in", 1), (r"typeof", r"built_in", 1), (r"uinteger", r"type", 1), (r"ulong", r"type", 1), (r"unicode", r"keyword", 1), (r"until", r"keyword", 1), (r"ushort", r"type", 1), (r"using", r"keyword", 1), (r"when", r"keyword", 1), (r"where", r"keyword", 1), (r"while", r"keyword", 1), (r"widening", r"keyword", 1), (r"with", r"keyword", 1), (r"withevents", r"keyword", 1), (r"writeonly", r"keyword", 1), (r"xor", r"built_in", 1), (r"yield", r"keyword", 1)];
+
+static KEYWORDS_77: &[(&str, &str, u8)] = &[(r"const", r"keyword", 1), (r"disable", r"keyword", 1), (r"else", r"keyword", 1), (r"elseif", r"keyword", 1), (r"enable", r"keyword", 1), (r"end", r"keyword", 1), (r"externalsource", r"keyword", 1), (r"if", r"keyword", 0), (r"region", r"keyword", 1), (r"then", r"keyword", 0)];
+
+static KEYWORDS_78: &[(&str, &str, u8)] = &[(r"anyfunc", r"keyword", 1), (r"block", r"keyword", 1), (r"br", r"keyword", 1), (r"br_if", r"keyword", 1), (r"br_table", r"keyword", 1), (r"call", r"keyword", 1), (r"call_indirect", r"keyword", 1), (r"data", r"keyword", 1), (r"drop", r"keyword", 1), (r"elem", r"keyword", 1), (r"else", r"keyword", 1), (r"end", r"keyword", 1), (r"export", r"keyword", 1), (r"func", r"keyword", 1), (r"get_global", r"keyword", 1), (r"get_local", r"keyword", 1), (r"global", r"keyword", 1), (r"global.get", r"keyword", 1), (r"global.set", r"keyword", 1), (r"if", r"keyword", 0), (r"import", r"keyword", 1), (r"local", r"keyword", 1), (r"local.get", r"keyword", 1), (r"local.set", r"keyword", 1), (r"local.tee", r"keyword", 1), (r"loop", r"keyword", 1), (r"memory", r"keyword", 1), (r"memory.grow", r"keyword", 1), (r"memory.size", r"keyword", 1), (r"module", r"keyword", 1), (r"mut", r"keyword", 1), (r"nop", r"keyword", 1), (r"offset", r"keyword", 1), (r"param", r"keyword", 1), (r"result", r"keyword", 1), (r"return", r"keyword", 1), (r"select", r"keyword", 1), (r"set_global", r"keyword", 1), (r"set_local", r"keyword", 1), (r"start", r"keyword", 1), (r"table", r"keyword", 1), (r"tee_local", r"keyword", 1), (r"then", r"keyword", 0), (r"type", r"keyword", 1), (r"unreachable", r"keyword", 1)];
+
+static KEYWORDS_79: &[(&str, &str, u8)] = &[(r"false", r"literal", 1), (r"no", r"literal", 1), (r"null", r"literal", 1), (r"true", r"literal", 1), (r"yes", r"literal", 1)];
+
+static KEYWORDS_80: &[(&str, &str, u8)] = &[(r"false", r"keyword", 1), (r"no", r"keyword", 1), (r"null", r"keyword", 1), (r"true", r"keyword", 1), (r"yes", r"keyword", 1)];
@@ -0,0 +1,1087 @@
+// Regular expressions of the grammars, matched like the JavaScript expressions compiled by
+// highlight.js with the `m` flag, and the `i` and `u` flags of each language.
+//
+// Expressions are parsed with the Annex B extensions of the browsers (literal `{` and `]`, octal
+// escapes, `\c` without a control letter), compiled to a program of instructions, and matched by
+// backtracking over the UTF-16 code units of the code, so positions and matches are the same as in
+// JavaScript. Lookbehinds and named groups are not supported, they are not used by the grammars.
+
+use super::grammars::PROPERTIES;
+
+#[derive(Clone, Copy)]
+pub(super) struct Flags {
+    pub(super) ignore_case: bool,
+    pub(super) unicode: bool,
+}
+
+// Match of an expression, with the range of the whole match and of each capture group.
+pub(super) type Captures = Vec<Option<(usize, usize)>>;
+
+pub(super) struct Regex {
+    program: Vec<Inst>,
+    classes: Vec<Class>,
+    // Capture groups, the whole match included.
+    groups: usize,
+    registers: usize,
+    flags: Flags,
+    // Code units a match can start with, unless it can be empty.
+    first: Option<First>,
+}
+
+impl Regex {
+    pub(super) fn new(source: &str, flags: Flags) -> Result<Regex, String> {
+        // Without the `u` flag the pattern is a sequence of UTF-16 code units, like the code.
+        let pattern: Vec<u32> = match flags.unicode {
+            true => source.chars().map(u32::from).collect(),
+            false => source.encode_utf16().map(u32::from).collect(),
+        };
+        let mut parser = Parser {
+            total: count_groups(&pattern),
+            pattern,
+            at: 0,
+            groups: 0,
+            flags,
+            classes: Vec::new(),
+        };
+        let node = parser.disjunction()?;
+        if parser.at < parser.pattern.len() {
+            return Err(format!("unmatched ')' in /{source}/"));
+        }
+        let mut regex = Regex {
+            program: Vec::new(),
+            classes: parser.classes,
+            groups: parser.total + 1,
+            registers: 0,
+            flags,
+            first: None,
+        };
+        let mut first = First {
+            ascii: 0,
+            other: false,
+            any: false,
+        };
+        if !regex.first(&node, &mut first) && !first.any {
+            regex.first = Some(first);
+        }
+        regex.compile(&node);
+        regex.program.push(Inst::Match);
+        Ok(regex)
+    }
+
+    // Matches the expression at the position.
+    pub(super) fn match_at(&self, input: &[u16], at: usize) -> Option<Captures> {
+        if !self.can_start(input, at) {
+            return None;
+        }
+        let mut slots = vec![NONE; self.groups * 3 + self.registers];
+        let mut stack = Vec::new();
+        let end = self.run(input, 0, at, &mut slots, &mut stack)?;
+        let mut captures = vec![Some((at, end))];
+        captures.extend((1..self.groups).map(|group| {
+            let (start, end) = (slots[group * 2], slots[group * 2 + 1]);
+            (start != NONE).then_some((start, end))
+        }));
+        Some(captures)
+    }
+
+    // Finds the first match from the position.
+    pub(super) fn find_at(&self, input: &[u16], from: usize) -> Option<Captures> {
+        find_any(&[self], input, from).map(|(_, captures)| captures)
+    }
+
+    fn can_start(&self, input: &[u16], at: usize) -> bool {
+        match &self.first {
+            None => true,
+            Some(first) => match input.get(at) {
+                Some(&unit) if unit < 128 => first.ascii & (1 << unit) != 0,
+                Some(_) => first.other,
+                None => false,
+            },
+        }
+    }
+
+    // Runs the program from the instruction at the position, returning the end of the match and
+    // leaving on the stack the changes to undo when backtracking.
+    fn run(
+        &self,
+        input: &[u16],
+        mut pc: usize,
+        mut pos: usize,
+        slots: &mut [usize],
+        stack: &mut Vec<Frame>,
+    ) -> Option<usize> {
+        let base = stack.len();
+        loop {
+            let matched = match self.program[pc] {
+                Inst::Char(c) => match self.next(input, pos) {
+                    Some((unit, next)) if self.canonicalize(unit) == c => {
+                        pos = next;
+                        true
+                    }
+                    _ => false,
+                },
+                Inst::Any => match self.next(input, pos) {
+                    Some((unit, next)) if !is_line_terminator(unit) => {
+                        pos = next;
+                        true
+                    }
+                    _ => false,
+                },
+                Inst::Class(class, negated) => match self.next(input, pos) {
+                    Some((unit, next)) if self.in_class(class, unit) != negated => {
+                        pos = next;
+                        true
+                    }
+                    _ => false,
+                },
+                Inst::LineStart => pos == 0 || is_line_terminator(input[pos - 1].into()),
+                Inst::LineEnd => match input.get(pos) {
+                    Some(&unit) => is_line_terminator(unit.into()),
+                    None => true,
+                },
+                Inst::Boundary(negated) => {
+                    let before = pos > 0 && self.is_word(input[pos - 1].into());
+                    let after = input
+                        .get(pos)
+                        .is_some_and(|&unit| self.is_word(unit.into()));
+                    (before != after) != negated
+                }
+                Inst::Split(first, second) => {
+                    stack.push(Frame::Alt(second, pos));
+                    pc = first;
+                    continue;
+                }
+                Inst::Jmp(to) => {
+                    pc = to;
+                    continue;
+                }
+                Inst::Open(group) => {
+                    set(slots, stack, self.groups * 2 + group, pos);
+                    true
+                }
+                Inst::Close(group) => {
+                    let start = slots[self.groups * 2 + group];
+                    set(slots, stack, group * 2, start);
+                    set(slots, stack, group * 2 + 1, pos);
+                    true
+                }
+                Inst::Clear(from, to) => {
+                    for group in from..to {
+                        if slots[group * 2] != NONE {
+                            set(slots, stack, group * 2, NONE);
+                            set(slots, stack, group * 2 + 1, NONE);
+                        }
+                    }
+                    true
+                }
+                Inst::Mark(register) => {
+                    set(slots, stack, self.groups * 3 + register, pos);
+                    true
+                }
+                // An optional iteration can't match the empty string.
+                Inst::Progress(register) => slots[self.groups * 3 + register] != pos,
+                Inst::Backref(group) => {
+                    let (start, end) = (slots[group * 2], slots[group * 2 + 1]);
+                    if start == NONE {
+                        true
+                    } else {
+                        let len = end - start;
+                        let same = input.get(pos..pos + len).is_some_and(|text| {
+                            text.iter().zip(&input[start..end]).all(|(&a, &b)| {
+                                self.canonicalize(a.into()) == self.canonicalize(b.into())
+                            })
+                        });
+                        if same {
+                            pos += len;
+                        }
+                        same
+                    }
+                }
+                Inst::Look(negated, next) => {
+                    let mark = stack.len();
+                    let found = self.run(input, pc + 1, pos, slots, stack).is_some();
+                    if found {
+                        // Lookaheads are atomic, only the captures are undone when backtracking.
+                        let mut kept = mark;
+                        for i in mark..stack.len() {
+                            if let Frame::Undo(..) = stack[i] {
+                                stack.swap(kept, i);
+                                kept += 1;
+                            }
+                        }
+                        stack.truncate(kept);
+                        if negated {
+                            undo(slots, stack, mark);
+                        }
+                    }
+                    if found != negated {
+                        pc = next;
+                        continue;
+                    }
+                    false
+                }
+                Inst::Match => return Some(pos),
+            };
+            if matched {
+                pc += 1;
+                continue;
+            }
+            // Backtracks to the last alternative, undoing the changes made after it.
+            loop {
+                if stack.len() == base {
+                    return None;
+                }
+                match stack.pop() {
+                    Some(Frame::Alt(to, at)) => {
+                        pc = to;
+                        pos = at;
+                        break;
+                    }
+                    Some(Frame::Undo(slot, value)) => slots[slot] = value,
+                    None => return None,
+                }
+            }
+        }
+    }
+
+    // Next character from the position, a code point with the `u` flag or a code unit otherwise.
+    fn next(&self, input: &[u16], pos: usize) -> Option<(u32, usize)> {
+        let unit = *input.get(pos)?;
+        if self.flags.unicode && is_lead(unit) {
+            if let Some(&trail) = input.get(pos + 1).filter(|&&trail| is_trail(trail)) {
+                let c = 0x10000 + ((u32::from(unit) - 0xD800) << 10) + u32::from(trail) - 0xDC00;
+                return Some((c, pos + 2));
+            }
+        }
+        Some((unit.into(), pos + 1))
+    }
+
+    fn canonicalize(&self, c: u32) -> u32 {
+        if !self.flags.ignore_case {
+            return c;
+        }
+        let Some(ch) = char::from_u32(c) else {
+            return c;
+        };
+        if self.flags.unicode {
+            // Simple case folding.
+            let upper = single(ch.to_uppercase()).unwrap_or(ch);
+            return single(upper.to_lowercase()).unwrap_or(upper).into();
+        }
+        match single(ch.to_uppercase()).map(u32::from) {
+            Some(upper) if upper <= 0xFFFF && (c < 128 || upper >= 128) => upper,
+            _ => c,
+        }
+    }
+
+    fn in_class(&self, class: usize, c: u32) -> bool {
+        let class = &self.classes[class];
+        if class.contains(c, self) {
+            return true;
+        }
+        if !self.flags.ignore_case {
+            return false;
+        }
+        // Any character of the class with the same canonical form.
+        let canonical = self.canonicalize(c);
+        let Some(ch) = char::from_u32(c) else {
+            return false;
+        };
+        let lower = single(ch.to_lowercase()).map(u32::from);
+        let upper = single(ch.to_uppercase()).map(u32::from);
+        let folded = char::from_u32(canonical)
+            .and_then(|ch| single(ch.to_lowercase()))
+            .map(u32::from);
+        [Some(canonical), lower, upper, folded]
+            .into_iter()
+            .flatten()
+            .any(|x| x != c && self.canonicalize(x) == canonical && class.contains(x, self))
+    }
+
+    // \w, with the characters folded to ASCII word characters when both `i` and `u` are set.
+    fn is_word(&self, c: u32) -> bool {
+        is_ascii_word(c)
+            || (self.flags.ignore_case && self.flags.unicode && matches!(c, 0x017F | 0x212A))
+    }
+
+    fn compile(&mut self, node: &Node) {
+        match node {
+            Node::Empty => {}
+            &Node::Char(c) => {
+                let c = self.canonicalize(c);
+                self.program.push(Inst::Char(c));
+            }
+            Node::Any => self.program.push(Inst::Any),
+            &Node::Class(class, negated) => self.program.push(Inst::Class(class, negated)),
+            Node::LineStart => self.program.push(Inst::LineStart),
+            Node::LineEnd => self.program.push(Inst::LineEnd),
+            &Node::Boundary(negated) => self.program.push(Inst::Boundary(negated)),
+            &Node::Backref(group) => self.program.push(Inst::Backref(group)),
+            Node::Group(group, node) => {
+                if let &Some(group) = group {
+                    self.program.push(Inst::Open(group));
+                }
+                self.compile(node);
+                if let &Some(group) = group {
+                    self.program.push(Inst::Close(group));
+                }
+            }
+            Node::Look(negated, node) => {
+                let look = self.program.len();
+                self.program.push(Inst::Look(*negated, 0));
+                self.compile(node);
+                self.program.push(Inst::Match);
+                self.program[look] = Inst::Look(*negated, self.program.len());
+            }
+            Node::Concat(nodes) => nodes.iter().for_each(|node| self.compile(node)),
+            Node::Alt(nodes) => {
+                let mut jumps = Vec::new();
+                for (i, node) in nodes.iter().enumerate() {
+                    if i + 1 == nodes.len() {
+                        self.compile(node);
+                        break;
+                    }
+                    let split = self.program.len();
+                    self.program.push(Inst::Split(split + 1, 0));
+                    self.compile(node);
+                    jumps.push(self.program.len());
+                    self.program.push(Inst::Jmp(0));
+                    self.program[split] = Inst::Split(split + 1, self.program.len());
+                }
+                let end = self.program.len();
+                for jump in jumps {
+                    self.program[jump] = Inst::Jmp(end);
+                }
+            }
+            Node::Repeat {
+                node,
+                min,
+                max,
+                greedy,
+                groups,
+            } => {
+                let clear = |program: &mut Vec<Inst>| {
+                    if groups.0 < groups.1 {
+                        program.push(Inst::Clear(groups.0, groups.1));
+                    }
+                };
+                for _ in 0..*min {
+                    clear(&mut self.program);
+                    self.compile(node);
+                }
+                let optional = |regex: &mut Regex| {
+                    let split = regex.program.len();
+                    let register = regex.registers;
+                    regex.registers += 1;
+                    regex.program.push(Inst::Split(0, 0));
+                    regex.program.push(Inst::Mark(register));
+                    clear(&mut regex.program);
+                    regex.compile(node);
+                    regex.program.push(Inst::Progress(register));
+                    split
+                };
+                let mut splits = Vec::new();
+                match max {
+                    Some(max) => {
+                        for _ in *min..*max {
+                            splits.push(optional(self));
+                        }
+                    }
+                    None => {
+                        let split = optional(self);
+                        self.program.push(Inst::Jmp(split));
+                        splits.push(split);
+                    }
+                }
+                let end = self.program.len();
+                for split in splits {
+                    self.program[split] = match greedy {
+                        true => Inst::Split(split + 1, end),
+                        false => Inst::Split(end, split + 1),
+                    };
+                }
+            }
+        }
+    }
+
+    // Adds the code units the node can start with, returning whether it can match the empty
+    // string.
+    fn first(&self, node: &Node, first: &mut First) -> bool {
+        match node {
+            Node::Empty | Node::LineStart | Node::LineEnd | Node::Boundary(_) | Node::Look(..) => {
+                true
+            }
+            &Node::Char(c) => {
+                if self.flags.ignore_case && self.flags.unicode {
+                    first.any = true;
+                } else if c < 128 {
+                    first.ascii |= 1 << c;
+                    if self.flags.ignore_case && (c as u8).is_ascii_alphabetic() {
+                        first.ascii |= 1 << (c ^ 0x20);
+                    }
+                } else {
+                    first.other = true;
+                }
+                false
+            }
+            &Node::Class(class, negated) => {
+                for c in 0..128 {
+                    if self.in_class(class, c) != negated {
+                        first.ascii |= 1 << c;
+                    }
+                }
+                first.other = true;
+                false
+            }
+            Node::Any | Node::Backref(_) => {
+                first.any = true;
+                matches!(node, Node::Backref(_))
+            }
+            Node::Group(_, node) => self.first(node, first),
+            Node::Concat(nodes) => nodes.iter().all(|node| self.first(node, first)),
+            Node::Alt(nodes) => {
+                // Every alternative adds its first units.
+                let mut empty = false;
+                for node in nodes {
+                    empty |= self.first(node, first);
+                }
+                empty
+            }
+            Node::Repeat { node, min, max, .. } => {
+                if *max == Some(0) {
+                    return true;
+                }
+                self.first(node, first) || *min == 0
+            }
+        }
+    }
+}
+
+// Finds the first match from the position of any of the expressions, all with the same flags,
+// trying them in order at each position like an alternation of all of them. Returns the index
+// of the expression and its match.
+pub(super) fn find_any(
+    regexes: &[&Regex],
+    input: &[u16],
+    from: usize,
+) -> Option<(usize, Captures)> {
+    let unicode = regexes.first()?.flags.unicode;
+    let mut at = from;
+    // With the `u` flag matching doesn't start between the code units of a surrogate pair.
+    if unicode && at > 0 && at < input.len() && is_lead(input[at - 1]) && is_trail(input[at]) {
+        at -= 1;
+    }
+    while at <= input.len() {
+        for (i, regex) in regexes.iter().enumerate() {
+            if let Some(captures) = regex.match_at(input, at) {
+                return Some((i, captures));
+            }
+        }
+        let pair = at + 1 < input.len() && is_lead(input[at]) && is_trail(input[at + 1]);
+        at += if unicode && pair { 2 } else { 1 };
+    }
+    None
+}
+
+const NONE: usize = usize::MAX;
+
+enum Inst {
+    Char(u32),
+    Any,
+    Class(usize, bool),
+    LineStart,
+    LineEnd,
+    Boundary(bool),
+    // Tries the first instruction, and the second one when backtracking.
+    Split(usize, usize),
+    Jmp(usize),
+    Open(usize),
+    Close(usize),
+    // Resets the captures of the groups in the range, at each iteration of a quantifier.
+    Clear(usize, usize),
+    Mark(usize),
+    Progress(usize),
+    Backref(usize),
+    // Lookahead, followed by its instructions up to a `Match`, and then by the instruction given.
+    Look(bool, usize),
+    Match,
+}
+
+enum Frame {
+    Alt(usize, usize),
+    Undo(usize, usize),
+}
+
+fn set(slots: &mut [usize], stack: &mut Vec<Frame>, slot: usize, value: usize) {
+    stack.push(Frame::Undo(slot, slots[slot]));
+    slots[slot] = value;
+}
+
+fn undo(slots: &mut [usize], stack: &mut Vec<Frame>, mark: usize) {
+    while stack.len() > mark {
+        if let Some(Frame::Undo(slot, value)) = stack.pop() {
+            slots[slot] = value;
+        }
+    }
+}
+
+struct First {
+    ascii: u128,
+    // Non-ASCII code units.
+    other: bool,
+    any: bool,
+}
+
+enum Node {
+    Empty,
+    Char(u32),
+    Any,
+    Class(usize, bool),
+    LineStart,
+    LineEnd,
+    Boundary(bool),
+    Backref(usize),
+    Group(Option<usize>, Box<Node>),
+    Look(bool, Box<Node>),
+    Concat(Vec<Node>),
+    Alt(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+        // Capture groups in the repeated node.
+        groups: (usize, usize),
+    },
+}
+
+struct Class {
+    items: Vec<Item>,
+}
+
+enum Item {
+    Range(u32, u32),
+    Digit(bool),
+    Word(bool),
+    Space(bool),
+    Property(&'static [(u32, u32)], bool),
+}
+
+impl Class {
+    fn contains(&self, c: u32, regex: &Regex) -> bool {
+        self.items.iter().any(|item| match *item {
+            Item::Range(from, to) => (from..=to).contains(&c),
+            Item::Digit(negated) => (0x30..=0x39).contains(&c) != negated,
+            Item::Word(negated) => regex.is_word(c) != negated,
+            Item::Space(negated) => is_space(c) != negated,
+            Item::Property(ranges, negated) => {
+                let found = ranges
+                    .binary_search_by(|&(from, to)| {
+                        if to < c {
+                            std::cmp::Ordering::Less
+                        } else if from > c {
+                            std::cmp::Ordering::Greater
+                        } else {
+                            std::cmp::Ordering::Equal
+                        }
+                    })
+                    .is_ok();
+                found != negated
+            }
+        })
+    }
+}
+
+struct Parser {
+    pattern: Vec<u32>,
+    at: usize,
+    // Capture groups parsed so far, and in the whole pattern.
+    groups: usize,
+    total: usize,
+    flags: Flags,
+    classes: Vec<Class>,
+}
+
+enum ClassAtom {
+    Char(u32),
+    Set(Item),
+}
+
+impl Parser {
+    fn peek(&self) -> Option<u32> {
+        self.pattern.get(self.at).copied()
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.peek().and_then(char::from_u32)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c.into()) {
+            self.at += 1;
+            return true;
+        }
+        false
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let units: Vec<u32> = s.chars().map(u32::from).collect();
+        if self.pattern[self.at..].starts_with(&units) {
+            self.at += units.len();
+            return true;
+        }
+        false
+    }
+
+    fn error(&self, message: &str) -> String {
+        let source: String = self
+            .pattern
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        format!("{message} at {} in /{source}/", self.at)
+    }
+
+    fn disjunction(&mut self) -> Result<Node, String> {
+        let mut alternatives = vec![self.alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.alternative()?);
+        }
+        Ok(match alternatives.len() {
+            1 => alternatives.pop().unwrap(),
+            _ => Node::Alt(alternatives),
+        })
+    }
+
+    fn alternative(&mut self) -> Result<Node, String> {
+        let mut terms = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' as u32 || c == ')' as u32 {
+                break;
+            }
+            terms.push(self.term()?);
+        }
+        Ok(match terms.len() {
+            0 => Node::Empty,
+            1 => terms.pop().unwrap(),
+            _ => Node::Concat(terms),
+        })
+    }
+
+    fn term(&mut self) -> Result<Node, String> {
+        if self.eat('^') {
+            return Ok(Node::LineStart);
+        } else if self.eat('$') {
+            return Ok(Node::LineEnd);
+        } else if self.eat_str("\\b") {
+            return Ok(Node::Boundary(false));
+        } else if self.eat_str("\\B") {
+            return Ok(Node::Boundary(true));
+        } else if self.eat_str("(?<=") || self.eat_str("(?<!") {
+            return Err(self.error("lookbehinds are not supported"));
+        }
+        let groups = self.groups;
+        let node = if self.eat_str("(?=") || self.eat_str("(?!") {
+            let negated = self.pattern[self.at - 1] == '!' as u32;
+            let node = self.disjunction()?;
+            if !self.eat(')') {
+                return Err(self.error("unterminated group"));
+            }
+            // Lookaheads can be quantified with Annex B.
+            Node::Look(negated, Box::new(node))
+        } else {
+            self.atom()?
+        };
+        self.quantifier(node, (groups + 1, self.groups + 1))
+    }
+
+    fn quantifier(&mut self, node: Node, groups: (usize, usize)) -> Result<Node, String> {
+        let start = self.at;
+        let (min, max) = if self.eat('*') {
+            (0, None)
+        } else if self.eat('+') {
+            (1, None)
+        } else if self.eat('?') {
+            (0, Some(1))
+        } else if self.eat('{') {
+            match self.braced() {
+                Some(range) => range,
+                None => {
+                    self.at = start;
+                    return Ok(node);
+                }
+            }
+        } else {
+            return Ok(node);
+        };
+        if max.is_some_and(|max| max < min) {
+            return Err(self.error("numbers out of order in quantifier"));
+        }
+        let greedy = !self.eat('?');
+        Ok(Node::Repeat {
+            node: Box::new(node),
+            min,
+            max,
+            greedy,
+            groups,
+        })
+    }
+
+    // {n}, {n,} or {n,m}, after the brace.
+    fn braced(&mut self) -> Option<(u32, Option<u32>)> {
+        let min = self.decimal()?;
+        let max = match self.eat(',') {
+            true if self.peek() == Some('}'.into()) => None,
+            true => Some(self.decimal()?),
+            false => Some(min),
+        };
+        self.eat('}').then_some((min, max))
+    }
+
+    fn decimal(&mut self) -> Option<u32> {
+        let start = self.at;
+        let mut value: u32 = 0;
+        while let Some(digit) = self.peek_char().and_then(|c| c.to_digit(10)) {
+            value = value.saturating_mul(10).saturating_add(digit);
+            self.at += 1;
+        }
+        (self.at > start).then_some(value)
+    }
+
+    fn atom(&mut self) -> Result<Node, String> {
+        let Some(c) = self.peek() else {
+            return Err(self.error("unexpected end"));
+        };
+        self.at += 1;
+        match char::from_u32(c) {
+            Some('.') => Ok(Node::Any),
+            Some('(') => {
+                let group = match self.eat_str("?:") {
+                    true => None,
+                    false if self.peek() == Some('?'.into()) => {
+                        return Err(self.error("named groups are not supported"));
+                    }
+                    false => {
+                        self.groups += 1;
+                        Some(self.groups)
+                    }
+                };
+                let node = self.disjunction()?;
+                if !self.eat(')') {
+                    return Err(self.error("unterminated group"));
+                }
+                Ok(Node::Group(group, Box::new(node)))
+            }
+            Some('[') => self.class(),
+            Some('\\') => self.atom_escape(),
+            Some('*' | '+' | '?') => Err(self.error("nothing to repeat")),
+            Some('{') if self.flags.unicode => Err(self.error("lone quantifier brackets")),
+            Some('{') => {
+                let start = self.at;
+                if self.braced().is_some() {
+                    return Err(self.error("nothing to repeat"));
+                }
+                self.at = start;
+                Ok(Node::Char(c))
+            }
+            Some(']' | '}') if self.flags.unicode => Err(self.error("lone quantifier brackets")),
+            _ => Ok(Node::Char(c)),
+        }
+    }
+
+    fn atom_escape(&mut self) -> Result<Node, String> {
+        let Some(c) = self.peek_char() else {
+            return Err(self.error("\\ at end of pattern"));
+        };
+        if let Some(item) = self.class_escape(c)? {
+            let class = self.classes.len();
+            self.classes.push(Class { items: vec![item] });
+            return Ok(Node::Class(class, false));
+        }
+        if ('1'..='9').contains(&c) {
+            let start = self.at;
+            let group = self.decimal().unwrap_or(0) as usize;
+            if group <= self.total {
+                return Ok(Node::Backref(group));
+            }
+            self.at = start;
+            if self.flags.unicode {
+                return Err(self.error("invalid escape"));
+            }
+        }
+        Ok(Node::Char(self.character_escape(false)?))
+    }
+
+    // \d, \D, \w, \W, \s, \S, and \p{..} and \P{..} with the `u` flag.
+    fn class_escape(&mut self, c: char) -> Result<Option<Item>, String> {
+        let item = match c {
+            'd' | 'D' => Item::Digit(c == 'D'),
+            'w' | 'W' => Item::Word(c == 'W'),
+            's' | 'S' => Item::Space(c == 'S'),
+            'p' | 'P' if self.flags.unicode => {
+                self.at += 1;
+                if !self.eat('{') {
+                    return Err(self.error("invalid property name"));
+                }
+                let start = self.at;
+                while self.peek().is_some_and(|c| c != '}' as u32) {
+                    self.at += 1;
+                }
+                let name: String = self.pattern[start..self.at]
+                    .iter()
+                    .filter_map(|&c| char::from_u32(c))
+                    .collect();
+                self.eat('}');
+                let Some(&(_, ranges)) = PROPERTIES.iter().find(|(property, _)| *property == name)
+                else {
+                    return Err(self.error("unknown property name"));
+                };
+                return Ok(Some(Item::Property(ranges, c == 'P')));
+            }
+            _ => return Ok(None),
+        };
+        self.at += 1;
+        Ok(Some(item))
+    }
+
+    // Escaped character after the backslash, in a class or not.
+    fn character_escape(&mut self, in_class: bool) -> Result<u32, String> {
+        let c = self.peek().unwrap_or(0);
+        self.at += 1;
+        Ok(match char::from_u32(c).unwrap_or('\0') {
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            'b' if in_class => 0x08,
+            'c' => match self.peek_char() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.at += 1;
+                    letter as u32 % 32
+                }
+                Some(letter) if in_class && (letter.is_ascii_digit() || letter == '_') => {
+                    self.at += 1;
+                    letter as u32 % 32
+                }
+                // With Annex B the backslash is a literal and `c` is matched next.
+                _ => {
+                    self.at -= 1;
+                    '\\' as u32
+                }
+            },
+            '0' if !self.peek_char().is_some_and(|c| c.is_ascii_digit()) => 0,
+            '0'..='7' if !self.flags.unicode => {
+                // Legacy octal escape, up to \377.
+                let mut value = c - '0' as u32;
+                let digits = if c <= '3' as u32 { 2 } else { 1 };
+                for _ in 0..digits {
+                    match self.peek_char().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            value = value * 8 + digit;
+                            self.at += 1;
+                        }
+                        None => break,
+                    }
+                }
+                value
+            }
+            'x' => match self.hex(2) {
+                Some(value) => value,
+                None if self.flags.unicode => return Err(self.error("invalid escape")),
+                None => c,
+            },
+            'u' => match self.unicode_escape() {
+                Some(value) => value,
+                None if self.flags.unicode => return Err(self.error("invalid unicode escape")),
+                None => c,
+            },
+            _ => c,
+        })
+    }
+
+    // \uHHHH, and \u{H..} or a pair of escaped surrogates with the `u` flag, after the `u`.
+    fn unicode_escape(&mut self) -> Option<u32> {
+        if self.flags.unicode && self.eat('{') {
+            let start = self.at;
+            let value = self.hex(usize::MAX)?;
+            if value > 0x10FFFF || !self.eat('}') {
+                self.at = start;
+                return None;
+            }
+            return Some(value);
+        }
+        let value = self.hex(4)?;
+        if self.flags.unicode && (0xD800..0xDC00).contains(&value) {
+            let start = self.at;
+            if self.eat_str("\\u") {
+                if let Some(trail) = self.hex(4).filter(|trail| (0xDC00..0xE000).contains(trail)) {
+                    return Some(0x10000 + ((value - 0xD800) << 10) + trail - 0xDC00);
+                }
+            }
+            self.at = start;
+        }
+        Some(value)
+    }
+
+    // Exactly `digits` hexadecimal digits, or any number of them if `usize::MAX`.
+    fn hex(&mut self, digits: usize) -> Option<u32> {
+        let start = self.at;
+        let mut value: u32 = 0;
+        while self.at - start < digits {
+            match self.peek_char().and_then(|c| c.to_digit(16)) {
+                Some(digit) => {
+                    value = value.saturating_mul(16).saturating_add(digit);
+                    self.at += 1;
+                }
+                None => break,
+            }
+        }
+        let count = self.at - start;
+        if count == 0 || (digits != usize::MAX && count != digits) {
+            self.at = start;
+            return None;
+        }
+        Some(value)
+    }
+
+    fn class(&mut self) -> Result<Node, String> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated character class")),
+                Some(c) if c == ']' as u32 => {
+                    self.at += 1;
+                    break;
+                }
+                _ => {}
+            }
+            let from = self.class_atom()?;
+            if self.peek() == Some('-'.into())
+                && self
+                    .pattern
+                    .get(self.at + 1)
+                    .is_some_and(|&c| c != ']' as u32)
+            {
+                self.at += 1;
+                let to = self.class_atom()?;
+                match (from, to) {
+                    (ClassAtom::Char(from), ClassAtom::Char(to)) => {
+                        if from > to {
+                            return Err(self.error("range out of order in character class"));
+                        }
+                        items.push(Item::Range(from, to));
+                    }
+                    (from, to) if !self.flags.unicode => {
+                        // With Annex B a range with a class escape is a union with `-`.
+                        for atom in [from, ClassAtom::Char('-'.into()), to] {
+                            items.push(match atom {
+                                ClassAtom::Char(c) => Item::Range(c, c),
+                                ClassAtom::Set(item) => item,
+                            });
+                        }
+                    }
+                    _ => return Err(self.error("invalid character class")),
+                }
+                continue;
+            }
+            items.push(match from {
+                ClassAtom::Char(c) => Item::Range(c, c),
+                ClassAtom::Set(item) => item,
+            });
+        }
+        let class = self.classes.len();
+        self.classes.push(Class { items });
+        Ok(Node::Class(class, negated))
+    }
+
+    fn class_atom(&mut self) -> Result<ClassAtom, String> {
+        let c = self.peek().unwrap_or(0);
+        self.at += 1;
+        if c != '\\' as u32 {
+            return Ok(ClassAtom::Char(c));
+        }
+        let Some(next) = self.peek_char() else {
+            return Err(self.error("\\ at end of pattern"));
+        };
+        if let Some(item) = self.class_escape(next)? {
+            return Ok(ClassAtom::Set(item));
+        }
+        if next == '-' {
+            self.at += 1;
+            return Ok(ClassAtom::Char(next.into()));
+        }
+        Ok(ClassAtom::Char(self.character_escape(true)?))
+    }
+}
+
+// Counts the capture groups of the pattern, which tell backreferences from octal escapes.
+fn count_groups(pattern: &[u32]) -> usize {
+    let mut count = 0;
+    let mut in_class = false;
+    let mut i = 0;
+    while i < pattern.len() {
+        match char::from_u32(pattern[i]) {
+            Some('\\') => i += 1,
+            Some('[') => in_class = true,
+            Some(']') => in_class = false,
+            Some('(') if !in_class && pattern.get(i + 1) != Some(&('?' as u32)) => count += 1,
+            // Named groups, but not lookbehinds.
+            Some('(')
+                if !in_class
+                    && pattern.get(i + 2) == Some(&('<' as u32))
+                    && !matches!(
+                        pattern.get(i + 3).and_then(|&c| char::from_u32(c)),
+                        Some('=' | '!')
+                    ) =>
+            {
+                count += 1
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    count
+}
+
+fn single(mut chars: impl ExactSizeIterator<Item = char>) -> Option<char> {
+    match chars.len() {
+        1 => chars.next(),
+        _ => None,
+    }
+}
+
+fn is_lead(unit: u16) -> bool {
+    (0xD800..0xDC00).contains(&unit)
+}
+
+fn is_trail(unit: u16) -> bool {
+    (0xDC00..0xE000).contains(&unit)
+}
+
+fn is_ascii_word(c: u32) -> bool {
+    char::from_u32(c).is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+pub(super) fn is_line_terminator(c: u32) -> bool {
+    matches!(c, 0x0A | 0x0D | 0x2028 | 0x2029)
+}
+
+// \s
+pub(super) fn is_space(c: u32) -> bool {
+    matches!(
+        c,
+        0x09..=0x0D
+            | 0x20
+            | 0xA0
+            | 0x1680
+            | 0x2000..=0x200A
+            | 0x2028
+            | 0x2029
+            | 0x202F
+            | 0x205F
+            | 0x3000
+            | 0xFEFF
+    )
+}
@@ -0,0 +1,73 @@
+//! Add a new component to put highlighted code within text.
+
+use pagetop::prelude::*;
+
+use crate::highlight::highlight;
+use crate::HljsContext;
+use crate::{HljsLang, HljsMode};
+
+#[derive(AutoDefault)]
+/// Component to put highlighted code within text, like a type name in a paragraph.
+///
+/// Unlike [`Snippet`](crate::Snippet), it renders a `code` element without a `pre` block.
+pub struct InlineSnippet {
+    language: HljsLang,
+    snippet: String,
+}
+
+impl ComponentTrait for InlineSnippet {
+    fn new() -> Self {
+        InlineSnippet::default()
+    }
+
+    fn setup_before_prepare(&mut self, cx: &mut Context) {
+        cx.add_hljs_language(self.language());
+    }
+
+    fn prepare_component(&self, cx: &mut Context) -> PrepareMarkup {
+        if cx.hljs_mode() == HljsMode::Server {
+            return PrepareMarkup::With(html! {
+                code class=(concat_string!("hljs hljs-inline language-", self.language().to_string())) {
+                    (highlight(self.language(), self.snippet()))
+                }
+            });
+        }
+        PrepareMarkup::With(html! {
+            code class=(concat_string!("hljs-inline language-", self.language().to_string())) {
+                (self.snippet())
+            }
+        })
+    }
+}
+
+impl InlineSnippet {
+    pub fn with(language: HljsLang, code: impl Into<String>) -> Self {
+        InlineSnippet::new()
+            .with_language(language)
+            .with_snippet(code)
+    }
+
+    // InlineSnippet BUILDER.
+
+    #[fn_builder]
+    pub fn set_language(&mut self, language: HljsLang) -> &mut Self {
+        self.language = language;
+        self
+    }
+
+    #[fn_builder]
+    pub fn set_snippet(&mut self, snippet: impl Into<String>) -> &mut Self {
+        self.snippet = snippet.into();
+        self
+    }
+
+    // InlineSnippet GETTERS.
+
+    pub fn language(&self) -> &HljsLang {
+        &self.language
+    }
+
+    pub fn snippet(&self) -> &String {
+        &self.snippet
+    }
+}
@@ -1,225 +1,15 @@
 use pagetop::prelude::*;
 
+use crate::error::{self, HljsError};
+
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::LazyLock;
 
-use std::fmt;
-
-/// Supported coding languages.
-///
-/// Languages are represented by *PascalCase* enums within the code and are mapped to corresponding
-/// [highlight.js](https://highlightjs.org/) language names.
-///
-/// ```rust
-/// use pagetop_hljs::HljsLang;
-///
-/// assert_eq!(HljsLang::CoffeeScript.to_string(), "coffeescript".to_string());
-/// ```
-#[derive(AutoDefault, Clone, Copy, Debug, Eq, PartialEq, Hash)]
-pub enum HljsLang {
-    // Common languages.
-    Bash,
-    C,
-    Cpp,
-    Csharp,
-    CSS,
-    Diff,
-    Go,
-    GraphQL,
-    HTML,
-    INI,
-    Java,
-    JavaScript,
-    JSON,
-    Kotlin,
-    Less,
-    Lua,
-    Makefile,
-    Markdown,
-    ObjectiveC,
-    Perl,
-    PHP,
-    PHPTemplate,
-    #[default]
-    Plaintext,
-    Python,
-    PythonREPL,
-    R,
-    Ruby,
-    Rust,
-    SCSS,
-    ShellSession,
-    SQL,
-    Swift,
-    TOML,
-    TypeScript,
-    VisualBasicNET,
-    WebAssembly,
-    XML,
-    /// Enum variants for languages ranging from `Bash` to `YAML` are all preloaded in the
-    /// ***common*** mode. To include additional languages, use the default ***core*** mode.
-    ///
-    /// See [`config::SETTINGS.hljs.mode`](crate::config::Hljs#structfield.mode).
-    YAML,
-    // Additional languages.
-    ActionScript,
-    Ada,
-    Apache,
-    AppleScript,
-    Arduino,
-    ARMAssembly,
-    AsciiDoc,
-    AspectJ,
-    AutoHotkey,
-    AVRAssembly,
-    Awk,
-    BASIC,
-    Clojure,
-    ClojureREPL,
-    CMake,
-    CoffeeScript,
-    Crystal,
-    D,
-    Dart,
-    Delphy,
-    Django,
-    DNSZone,
-    Dockerfile,
-    DOS,
-    Elixir,
-    Elm,
-    ERB,
-    Erlang,
-    ErlangREPL,
-    Fortran,
-    Fsharp,
-    Handlebars,
-    Haskell,
-    HTTP,
-    Julia,
-    JuliaREPL,
-    LaTeX,
-    Lisp,
-    LLVMIR,
-    Matlab,
-    Nginx,
-    NodeREPL,
-    Ocaml,
-    PostgreSQL,
-    PowerShell,
-    Prolog,
-    Properties,
-    Scala,
-    Scheme,
-    Scilab,
-    Smalltalk,
-    Tcl,
-    Twig,
-    VBScript,
-    X86Asm,
-}
-
-static HLJS_LANGS: LazyLock<HashMap<HljsLang, &'static str>> = LazyLock::new(|| {
-    use HljsLang::*;
-    kv![
-        // Common languages.
-        Bash           => "bash",
-        C              => "c",
-        Cpp            => "cpp",
-        Csharp         => "csharp",
-        CSS            => "css",
-        Diff           => "diff",
-        Go             => "go",
-        GraphQL        => "graphql",
-        HTML           => "html,xml",
-        INI            => "ini",
-        Java           => "java",
-        JavaScript     => "javascript",
-        JSON           => "json",
-        Kotlin         => "kotlin",
-        Less           => "less",
-        Lua            => "lua",
-        Makefile       => "makefile",
-        Markdown       => "markdown",
-        ObjectiveC     => "objectivec",
-        Perl           => "perl",
-        PHP            => "php",
-        PHPTemplate    => "php-template",
-        Plaintext      => "plaintext",
-        Python         => "python",
-        PythonREPL     => "python-repl",
-        R              => "r",
-        Ruby           => "ruby",
-        Rust           => "rust",
-        SCSS           => "scss",
-        ShellSession   => "shell",
-        SQL            => "sql",
-        Swift          => "swift",
-        TOML           => "toml,ini",
-        TypeScript     => "typescript",
-        VisualBasicNET => "vbnet",
-        WebAssembly    => "wasm",
-        XML            => "xml",
-        YAML           => "yaml",
-        // Additional languages.
-        ActionScript   => "actionscript",
-        Ada            => "ada",
-        Apache         => "apache",
-        AppleScript    => "applescript",
-        Arduino        => "arduino",
-        ARMAssembly    => "armasm",
-        AsciiDoc       => "asciidoc",
-        AspectJ        => "aspectj",
-        AutoHotkey     => "autohotkey",
-        AVRAssembly    => "avrasm",
-        Awk            => "awk",
-        BASIC          => "basic",
-        Clojure        => "clojure",
-        ClojureREPL    => "clojure-repl",
-        CMake          => "cmake",
-        CoffeeScript   => "coffeescript",
-        Crystal        => "crystal",
-        D              => "d",
-        Dart           => "dart",
-        Delphy         => "delphy",
-        Django         => "django",
-        DNSZone        => "dns",
-        Dockerfile     => "dockerfile",
-        DOS            => "dos",
-        Elixir         => "elixir",
-        Elm            => "elm",
-        ERB            => "erb",
-        Erlang         => "erlang",
-        ErlangREPL     => "erlang-repl",
-        Fortran        => "fortran",
-        Fsharp         => "fsharp",
-        Handlebars     => "handlebars",
-        Haskell        => "haskell",
-        HTTP           => "http",
-        Julia          => "julia",
-        JuliaREPL      => "julia-repl",
-        LaTeX          => "latex",
-        Lisp           => "lisp",
-        LLVMIR         => "llvm",
-        Matlab         => "matlab",
-        Nginx          => "nginx",
-        NodeREPL       => "node-repl",
-        Ocaml          => "ocaml",
-        PostgreSQL     => "pgsql",
-        PowerShell     => "powershell",
-        Prolog         => "prolog",
-        Properties     => "properties",
-        Scala          => "scala",
-        Scheme         => "scheme",
-        Scilab         => "scilab",
-        Smalltalk      => "smalltalk",
-        Tcl            => "tcl",
-        Twig           => "twig",
-        VBScript       => "vbscript",
-        X86Asm         => "x86asm",
-    ]
-});
+// The `HljsLang` enum and its `HLJS_LANGS`, `HLJS_SCRIPTS`, `HLJS_REQUIRES`, `HLJS_ALIASES` and
+// `HLJS_LANG_NAMES` maps are generated by `build.rs` from the scripts in `static/js/languages`,
+// with the overrides in `build/languages.toml`.
+include!(concat!(env!("OUT_DIR"), "/lang.rs"));
 
 impl ToString for HljsLang {
     fn to_string(&self) -> String {
@@ -227,20 +17,288 @@ impl ToString for HljsLang {
     }
 }
 
+/// Parses the name of a language or any of its highlight.js aliases, ignoring case.
+///
+/// ```rust
+/// use pagetop_hljs::HljsLang;
+///
+/// assert_eq!("rust".parse::<HljsLang>(), Ok(HljsLang::Rust));
+/// assert_eq!("RS".parse::<HljsLang>(), Ok(HljsLang::Rust));
+/// assert_eq!("yml".parse::<HljsLang>(), Ok(HljsLang::YAML));
+/// assert_eq!("typescipt".parse::<HljsLang>().unwrap_err().suggestions(), ["typescript"]);
+/// ```
 impl FromStr for HljsLang {
-    type Err = fmt::Error;
+    type Err = HljsError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase();
         HLJS_LANGS
             .iter()
-            .find_map(|(&key, &value)| if value == s { Some(key) } else { None })
-            .ok_or_else(|| fmt::Error)
+            .find_map(|(&key, &value)| if value == name { Some(key) } else { None })
+            .or_else(|| {
+                HLJS_ALIASES.iter().find_map(|(&key, aliases)| {
+                    if aliases.contains(&name.as_str()) {
+                        Some(key)
+                    } else {
+                        None
+                    }
+                })
+            })
+            .ok_or_else(|| HljsError::Language {
+                value: s.to_string(),
+                suggestions: error::suggestions(
+                    s,
+                    HLJS_LANGS
+                        .values()
+                        .chain(HLJS_ALIASES.values().flatten())
+                        .copied(),
+                ),
+            })
     }
 }
 
 impl HljsLang {
-    pub(crate) fn to_url(language: impl Into<String>) -> String {
-        let language = language.into();
-        concat_string!("/hljs/js/languages/", language, ".min.js")
+    /// Returns all the languages sorted by name.
+    pub fn all() -> Vec<HljsLang> {
+        let mut languages: Vec<HljsLang> = HLJS_LANGS.keys().copied().collect();
+        languages.sort_by_key(|language| HLJS_LANGS.get(language).unwrap());
+        languages
+    }
+
+    /// Returns the languages whose grammars are embedded in the grammar of the language, so they
+    /// must be loaded first.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsLang;
+    ///
+    /// assert_eq!(HljsLang::PHPTemplate.requires(), vec![HljsLang::XML, HljsLang::PHP]);
+    /// assert!(HljsLang::Rust.requires().is_empty());
+    /// ```
+    pub fn requires(&self) -> Vec<HljsLang> {
+        HLJS_REQUIRES.get(self).cloned().unwrap_or_default()
+    }
+
+    /// Returns the given languages and all the languages they require, each one only once and
+    /// after the languages it requires.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsLang;
+    ///
+    /// assert_eq!(
+    ///     HljsLang::load_order(&[HljsLang::PHPTemplate, HljsLang::Twig, HljsLang::PHP]),
+    ///     vec![HljsLang::XML, HljsLang::PHP, HljsLang::PHPTemplate, HljsLang::Twig],
+    /// );
+    /// ```
+    pub fn load_order(languages: &[HljsLang]) -> Vec<HljsLang> {
+        fn visit(language: HljsLang, ordered: &mut Vec<HljsLang>) {
+            if !ordered.contains(&language) {
+                for required in language.requires() {
+                    visit(required, ordered);
+                }
+                ordered.push(language);
+            }
+        }
+        let mut ordered = Vec::new();
+        for &language in languages {
+            visit(language, &mut ordered);
+        }
+        ordered
+    }
+
+    /// Returns the human-readable name of the language.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsLang;
+    ///
+    /// assert_eq!(HljsLang::Cpp.name(), "C++");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        HLJS_LANG_NAMES.get(self).unwrap()
+    }
+
+    /// Returns the highlight.js aliases of the language, also accepted when parsing it.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsLang;
+    ///
+    /// assert_eq!(HljsLang::Rust.aliases(), vec!["rs"]);
+    /// ```
+    pub fn aliases(&self) -> Vec<&'static str> {
+        HLJS_ALIASES.get(self).cloned().unwrap_or_default()
+    }
+
+    /// Infers the language from a file name extension, with or without the leading dot and
+    /// ignoring case.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsLang;
+    ///
+    /// assert_eq!(HljsLang::from_extension("rs"), Some(HljsLang::Rust));
+    /// assert_eq!(HljsLang::from_extension(".PYW"), Some(HljsLang::Python));
+    /// assert_eq!(HljsLang::from_extension("zip"), None);
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<HljsLang> {
+        use HljsLang::*;
+        let extension = extension.trim_start_matches('.').to_ascii_lowercase();
+        // Extensions that aren't the name or an alias of the language.
+        let language = match extension.as_str() {
+            "adb" | "ads" => Ada,
+            "cfg" => INI,
+            "cljc" | "cljs" => Clojure,
+            "fsi" | "fsx" => Fsharp,
+            "hrl" => Erlang,
+            "htm" | "xhtml" => HTML,
+            "j2" | "jinja2" => Django,
+            "jl" => Julia,
+            "json5" | "jsonc" => JSON,
+            "ksh" => Bash,
+            "ll" => LLVMIR,
+            "lsp" => Lisp,
+            "m" => ObjectiveC,
+            "mli" => Ocaml,
+            "mts" | "cts" => TypeScript,
+            "phtml" => PHPTemplate,
+            "pro" => Prolog,
+            "psm1" => PowerShell,
+            "pyi" | "pyw" => Python,
+            "scpt" => AppleScript,
+            "ss" => Scheme,
+            "asm" | "nasm" => X86Asm,
+            "wast" | "wat" => WebAssembly,
+            extension => return HljsLang::from_str(extension).ok(),
+        };
+        Some(language)
+    }
+
+    /// Infers the language from a file name or path, given by well-known file names like
+    /// `Makefile`, `Dockerfile` or `.bashrc`, or by the file name extension.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsLang;
+    ///
+    /// assert_eq!(HljsLang::from_filename("src/main.rs"), Some(HljsLang::Rust));
+    /// assert_eq!(HljsLang::from_filename("Makefile"), Some(HljsLang::Makefile));
+    /// assert_eq!(HljsLang::from_filename("docker/Dockerfile.dev"), Some(HljsLang::Dockerfile));
+    /// assert_eq!(HljsLang::from_filename("/home/user/.bashrc"), Some(HljsLang::Bash));
+    /// assert_eq!(HljsLang::from_filename("index.html.hbs"), Some(HljsLang::Handlebars));
+    /// assert_eq!(HljsLang::from_filename("LICENSE"), None);
+    /// ```
+    pub fn from_filename(filename: &str) -> Option<HljsLang> {
+        use HljsLang::*;
+        let name = filename.rsplit(['/', '\\']).next().unwrap_or(filename);
+        let lowercase = name.to_ascii_lowercase();
+        let language = match lowercase.as_str() {
+            "makefile" | "gnumakefile" | "bsdmakefile" => Some(Makefile),
+            "dockerfile" | "containerfile" => Some(Dockerfile),
+            "cmakelists.txt" => Some(CMake),
+            "gemfile" | "rakefile" | "guardfile" | "podfile" | "vagrantfile" => Some(Ruby),
+            "pkgbuild" | ".profile" | ".bashrc" | ".bash_profile" | ".bash_login"
+            | ".bash_logout" | ".bash_aliases" | ".zshrc" | ".zshenv" | ".zprofile" | ".zlogin"
+            | ".kshrc" => Some(Bash),
+            ".editorconfig" | ".gitconfig" | ".gitmodules" | ".npmrc" => Some(INI),
+            ".htaccess" | "httpd.conf" | "apache2.conf" => Some(Apache),
+            "nginx.conf" => Some(Nginx),
+            "cargo.lock" | "pipfile" | "poetry.lock" => Some(TOML),
+            _ if lowercase.starts_with("dockerfile.") => Some(Dockerfile),
+            _ => None,
+        };
+        // Compound extensions first, like "html.hbs" before "hbs".
+        language.or_else(|| {
+            name.match_indices('.')
+                .filter(|&(i, _)| i > 0)
+                .find_map(|(i, _)| HljsLang::from_extension(&name[i + 1..]))
+        })
+    }
+
+    /// Infers the language from the interpreter given in the shebang line of a script, like
+    /// `#!/bin/bash` or `#!/usr/bin/env python3`.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsLang;
+    ///
+    /// assert_eq!(HljsLang::from_shebang("#!/bin/sh\necho Hi"), Some(HljsLang::Bash));
+    /// assert_eq!(HljsLang::from_shebang("#!/usr/bin/env python3.12"), Some(HljsLang::Python));
+    /// let deno = "#!/usr/bin/env -S deno run";
+    /// assert_eq!(HljsLang::from_shebang(deno), Some(HljsLang::JavaScript));
+    /// assert_eq!(HljsLang::from_shebang("echo Hi"), None);
+    /// ```
+    pub fn from_shebang(code: &str) -> Option<HljsLang> {
+        use HljsLang::*;
+        let line = code.lines().next()?.strip_prefix("#!")?;
+        let mut words = line.split_whitespace();
+        let mut interpreter = words.next()?.rsplit('/').next()?;
+        if interpreter == "env" {
+            interpreter = words.find(|word| !word.starts_with('-') && !word.contains('='))?;
+        }
+        // Without the version, like "python" for "python3.12".
+        let interpreter = interpreter
+            .trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')
+            .to_ascii_lowercase();
+        let language = match interpreter.as_str() {
+            "ash" | "dash" | "ksh" => Bash,
+            "pypy" => Python,
+            "bun" | "deno" | "node" | "nodejs" => JavaScript,
+            "ts-node" => TypeScript,
+            "jruby" => Ruby,
+            "luajit" => Lua,
+            "rscript" => R,
+            "gawk" | "mawk" | "nawk" => Awk,
+            "tclsh" | "wish" => Tcl,
+            "powershell" => PowerShell,
+            "escript" => Erlang,
+            interpreter => return HljsLang::from_str(interpreter).ok(),
+        };
+        Some(language)
+    }
+
+    // Returns the name of the highlight.js script that highlights the language.
+    pub(crate) fn script(&self) -> &'static str {
+        HLJS_SCRIPTS
+            .get(self)
+            .or(HLJS_LANGS.get(self))
+            .copied()
+            .unwrap()
+    }
+
+    // Returns the URLs of the scripts that load the given languages in the *core* mode, in order
+    // and without duplicates.
+    pub(crate) fn script_urls(languages: &[HljsLang]) -> Vec<String> {
+        let mut urls: Vec<String> = Vec::new();
+        for language in HljsLang::load_order(languages) {
+            let url = language.to_url();
+            if !urls.contains(&url) {
+                urls.push(url);
+            }
+        }
+        urls
+    }
+
+    pub(crate) fn to_url(self) -> String {
+        concat_string!("/hljs/js/languages/", self.script(), ".min.js")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::Path;
+
+    #[test]
+    fn every_language_maps_to_its_script() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("static");
+        for language in HljsLang::all() {
+            let url = language.to_url();
+            let file = dir.join(url.strip_prefix("/hljs/").unwrap());
+            assert!(file.is_file(), "HljsLang::{language:?} => {url} is missing");
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn former_language_names_are_still_accepted() {
+        assert_eq!(HljsLang::Delphy, HljsLang::Delphi);
+        assert_eq!("delphy".parse::<HljsLang>(), Ok(HljsLang::Delphi));
+        assert_eq!(HljsLang::Delphi.to_string(), "delphi");
     }
 }
@@ -74,13 +74,32 @@
 
 use pagetop::prelude::*;
 
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::context::{
+    PARAM_HLJS_COPY, PARAM_HLJS_SCOPED_THEMES, PARAM_HLJS_SWITCHER, PARAM_HLJS_TABS,
+};
+
 pub mod config;
 
+mod error;
+pub use error::{Base16Error, HljsError};
+
 mod lang;
 pub use lang::HljsLang;
 
 mod theme;
-pub use theme::HljsTheme;
+pub use theme::{HljsCustomTheme, HljsTheme, HljsThemeSource};
+
+mod base16;
+pub use base16::Base16Scheme;
+
+mod contrast;
+pub use contrast::{ContrastAudit, TokenContrast, WcagLevel};
+
+mod token;
+pub use token::HljsToken;
 
 mod mode;
 pub use mode::HljsMode;
@@ -88,12 +107,37 @@ pub use mode::HljsMode;
 mod context;
 pub use context::HljsContext;
 
+mod highlight;
+
+mod css;
+
+mod gallery;
+
 mod snippet;
 pub use snippet::Snippet;
 
+mod snippet_group;
+pub use snippet_group::SnippetGroup;
+
+mod inline_snippet;
+pub use inline_snippet::InlineSnippet;
+
+mod theme_switcher;
+pub use theme_switcher::ThemeSwitcher;
+
+#[doc(hidden)]
+pub mod source;
+
 /// The package Prelude.
 pub mod prelude {
-    pub use crate::{config, HljsContext, HljsLang, HljsMode, HljsTheme, Snippet};
+    pub use crate::{config, HljsContext, HljsLang, HljsMode, HljsTheme};
+    pub use crate::{HljsError, HljsThemeSource, HljsToken};
+
+    pub use crate::{Base16Error, Base16Scheme};
+    pub use crate::{ContrastAudit, TokenContrast, WcagLevel};
+    pub use crate::{InlineSnippet, Snippet, SnippetGroup, ThemeSwitcher};
+
+    pub use crate::include_snippet;
 }
 
 static_locales!(LOCALES_HLJS);
@@ -103,6 +147,9 @@ static_files!(hljs);
 // Highlight.js library version.
 const HLJS_VERSION: &str = "11.7.0";
 
+// Package version, used to version the assets of the snippet components.
+const PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 /// Implements [`PackageTrait`].
 pub struct HighlightJS;
 
@@ -111,21 +158,116 @@ impl PackageTrait for HighlightJS {
         L10n::t("hljs_description", &LOCALES_HLJS)
     }
 
+    fn init(&self) {
+        // Loading the settings registers the custom themes and reports invalid values on startup.
+        let _ = &config::SETTINGS.hljs;
+    }
+
     fn actions(&self) -> Vec<ActionBox> {
         actions![action::page::AfterPrepareBody::new(after_prepare_body)]
     }
 
     fn configure_service(&self, cfg: &mut service::web::ServiceConfig) {
+        // Routes must be registered before the static files to take precedence over them.
+        if config::SETTINGS.hljs.gallery {
+            cfg.route("/hljs/gallery", service::web::get().to(gallery::gallery));
+            cfg.route(
+                "/hljs/gallery/{theme}",
+                service::web::get().to(gallery::gallery_sample),
+            );
+        }
+        cfg.route("/hljs/css/tokens.css", service::web::get().to(token_colors));
+        cfg.route(
+            "/hljs/css/scoped/{theme}.css",
+            service::web::get().to(scoped_theme),
+        );
+        cfg.route(
+            "/hljs/css/custom/{theme}.css",
+            service::web::get().to(custom_theme),
+        );
+        cfg.route(
+            "/hljs/css/{scheme:light|dark}/{theme}.css",
+            service::web::get().to(color_scheme_theme),
+        );
         service_for_static_files!(cfg, hljs => "/hljs");
     }
 }
 
-// Sets up page assets depending on whether the 'core' or 'common' highlight.js library is used.
+// Serves the CSS overriding the colors of the tokens given in the query string, like
+// `?keyword=%23e63946`. The `v` parameter is the version of the package.
+async fn token_colors(
+    query: service::web::Query<HashMap<String, String>>,
+) -> service::HttpResponse {
+    let mut colors = Vec::new();
+    for (token, color) in query.iter().filter(|(token, _)| *token != "v") {
+        match HljsToken::from_str(token) {
+            Ok(token) if HljsToken::is_valid_color(color) => colors.push((token, color.to_owned())),
+            _ => return service::HttpResponse::BadRequest().finish(),
+        }
+    }
+    colors.sort_by_key(|(token, _)| HljsToken::all().iter().position(|t| t == token));
+    service::HttpResponse::Ok()
+        .content_type("text/css; charset=utf-8")
+        .body(HljsToken::overrides_css(&colors))
+}
+
+// Serves the CSS of a bundled theme scoped under the `hljs-scope-<theme>` class, used by snippets
+// with their own theme.
+async fn scoped_theme(theme: service::web::Path<String>) -> service::HttpResponse {
+    let theme = theme.into_inner();
+    let css = css::theme_css(&theme)
+        .map(str::to_owned)
+        .or_else(|| HljsTheme::custom_css(&theme));
+    match css {
+        Some(css) => service::HttpResponse::Ok()
+            .content_type("text/css; charset=utf-8")
+            .body(css::scoped_css(&css, &concat_string!("hljs-scope-", theme))),
+        None => service::HttpResponse::NotFound().finish(),
+    }
+}
+
+// Serves the CSS of a custom theme registered with its stylesheet contents.
+async fn custom_theme(theme: service::web::Path<String>) -> service::HttpResponse {
+    match HljsTheme::custom_css(&theme) {
+        Some(css) => service::HttpResponse::Ok()
+            .content_type("text/css; charset=utf-8")
+            .body(css),
+        None => service::HttpResponse::NotFound().finish(),
+    }
+}
+
+// Serves the CSS of a theme enclosed in a media rule that only applies it when the reader prefers
+// the light or the dark color scheme, used to pair the default and the dark themes.
+async fn color_scheme_theme(path: service::web::Path<(String, String)>) -> service::HttpResponse {
+    let (scheme, theme) = path.into_inner();
+    let media = match scheme.as_str() {
+        "dark" => "(prefers-color-scheme: dark)",
+        _ => "not all and (prefers-color-scheme: dark)",
+    };
+    let css = match css::theme_css(&theme)
+        .map(str::to_owned)
+        .or_else(|| HljsTheme::custom_css(&theme))
+    {
+        Some(css) => concat_string!("@media ", media, "{", css.replace("url(./", "url(../"), "}"),
+        None if theme.parse::<HljsTheme>().is_ok() => {
+            // Custom themes linked by URL can only be imported.
+            let url = HljsTheme::to_url(theme);
+            concat_string!("@import url(\"", url, "\") ", media, ";")
+        }
+        None => return service::HttpResponse::NotFound().finish(),
+    };
+    service::HttpResponse::Ok()
+        .content_type("text/css; charset=utf-8")
+        .body(css)
+}
+
+// Sets up page assets depending on whether the 'core' or 'common' highlight.js library is used, or
+// the code is highlighted on the server.
 fn after_prepare_body(page: &mut Page) {
     let cx = page.context();
 
     if cx.is_hljs_enabled() {
-        if let Some(languages) = cx.hljs_languages() {
+        if let Some(languages) = cx.hljs_ordered_languages() {
             match cx.hljs_mode() {
                 HljsMode::Core => {
                     cx.set_assets(AssetsOp::AddJavaScript(
@@ -133,39 +275,260 @@ fn after_prepare_body(page: &mut Page) {
                             .with_version(HLJS_VERSION)
                             .with_mode(ModeJS::Normal),
                     ));
-                    for l in languages {
+                    // Required grammars are loaded before the languages that embed them.
+                    for url in HljsLang::script_urls(&languages) {
                         cx.set_assets(AssetsOp::AddJavaScript(
-                            JavaScript::at(HljsLang::to_url(l))
+                            JavaScript::at(url)
                                 .with_version(HLJS_VERSION)
                                 .with_mode(ModeJS::Normal),
                         ));
                     }
                 }
-                _ => {
+                HljsMode::Common => {
                     cx.set_assets(AssetsOp::AddJavaScript(
                         JavaScript::at("/hljs/js/highlight.min.js")
                             .with_version(HLJS_VERSION)
                             .with_mode(ModeJS::Normal),
                     ));
                 }
+                HljsMode::Server => {}
             }
 
-            // Configure highlight.js (disabling language autodetection).
-            #[rustfmt::skip]
-            cx.set_assets(AssetsOp::AddHeadScript(
-                HeadScript::named("highlight.js").with_code(concat_string!("
-                    hljs.configure({
-                        tabReplace: '", " ".repeat(config::SETTINGS.hljs.tabsize), "',
-                        languages: [],
-                    });
-                    hljs.highlightAll();
-                ")),
-            ));
+            if cx.hljs_mode() != HljsMode::Server {
+                // Configure highlight.js (disabling language autodetection) and highlight both code
+                // blocks and inline snippets.
+                #[rustfmt::skip]
+                cx.set_assets(AssetsOp::AddHeadScript(
+                    HeadScript::named("highlight.js").with_code(concat_string!("
+                        hljs.configure({
+                            tabReplace: '", " ".repeat(config::SETTINGS.hljs.tabsize), "',
+                            languages: [],
+                        });
+                        hljs.highlightAll();
+                        function hljsHighlightInline() {
+                            document.querySelectorAll('code.hljs-inline').forEach(function (code) {
+                                hljs.highlightElement(code);
+                            });
+                        }
+                        if (document.readyState === 'loading') {
+                            document.addEventListener('DOMContentLoaded', hljsHighlightInline);
+                        } else {
+                            hljsHighlightInline();
+                        }
+                    ")),
+                ));
+            }
+
+            add_theme_assets(cx);
+
+            if let Ok(themes) = cx.get_param::<String>(PARAM_HLJS_SCOPED_THEMES) {
+                let themes: HashSet<&str> = themes.split(';').collect();
+                for theme in themes {
+                    cx.set_assets(AssetsOp::AddStyleSheet(
+                        StyleSheet::at(concat_string!("/hljs/css/scoped/", theme, ".css"))
+                            .with_version(PKG_VERSION),
+                    ));
+                }
+            }
+
+            add_font_assets(cx);
+
+            // Token colors are layered after the theme stylesheets to override them.
+            let token_colors = cx.hljs_token_colors();
+            if !token_colors.is_empty() {
+                cx.set_assets(AssetsOp::AddStyleSheet(versioned_stylesheet(
+                    HljsToken::overrides_url(&token_colors),
+                    PKG_VERSION,
+                )));
+            }
 
             cx.set_assets(AssetsOp::AddStyleSheet(
-                StyleSheet::at(HljsTheme::to_url(cx.hljs_theme().to_string()))
-                    .with_version(HLJS_VERSION),
+                StyleSheet::at("/hljs/snippet/snippet.css").with_version(PKG_VERSION),
             ));
+
+            if cx.get_param::<bool>(PARAM_HLJS_COPY).unwrap_or(false) {
+                cx.set_assets(AssetsOp::AddJavaScript(
+                    JavaScript::at("/hljs/snippet/copy.js")
+                        .with_version(PKG_VERSION)
+                        .with_mode(ModeJS::Defer),
+                ));
+            }
+
+            if cx.get_param::<bool>(PARAM_HLJS_TABS).unwrap_or(false) {
+                // Tabs fall back to stacked snippets when JavaScript is not available.
+                cx.set_assets(AssetsOp::AddHeadScript(
+                    HeadScript::named("hljs-tabs")
+                        .with_code("document.documentElement.classList.add('hljs-js');"),
+                ));
+                cx.set_assets(AssetsOp::AddJavaScript(
+                    JavaScript::at("/hljs/snippet/tabs.js")
+                        .with_version(PKG_VERSION)
+                        .with_mode(ModeJS::Defer),
+                ));
+            }
+
+            if cx.get_param::<bool>(PARAM_HLJS_SWITCHER).unwrap_or(false) {
+                cx.set_assets(AssetsOp::AddJavaScript(
+                    JavaScript::at("/hljs/snippet/theme-switcher.js")
+                        .with_version(PKG_VERSION)
+                        .with_mode(ModeJS::Defer),
+                ));
+            }
+        }
+    }
+}
+
+// Adds the theme stylesheet, or both the light and dark theme stylesheets if there is a dark theme.
+fn add_theme_assets(cx: &mut Context) {
+    let theme = cx.hljs_theme();
+    match cx.hljs_dark_theme().filter(|dark| *dark != theme) {
+        None => {
+            cx.set_assets(AssetsOp::AddStyleSheet(versioned_stylesheet(
+                HljsTheme::to_url(theme.to_string()),
+                HLJS_VERSION,
+            )));
+        }
+        Some(dark) => {
+            // Each theme of the pair is served enclosed in the media rule of its color scheme.
+            for (scheme, theme) in [("light", theme), ("dark", dark)] {
+                cx.set_assets(AssetsOp::AddStyleSheet(
+                    StyleSheet::at(concat_string!(
+                        "/hljs/css/",
+                        scheme,
+                        "/",
+                        theme.to_string(),
+                        ".css"
+                    ))
+                    .with_version(HLJS_VERSION),
+                ));
+            }
+            if config::SETTINGS.hljs.data_theme {
+                cx.set_assets(AssetsOp::AddJavaScript(
+                    JavaScript::at("/hljs/snippet/data-theme.js")
+                        .with_version(PKG_VERSION)
+                        .with_mode(ModeJS::Defer),
+                ));
+            }
+        }
+    }
+}
+
+// Returns the stylesheet with the version in the query string, appended to the query string of
+// the URL if it already has one.
+fn versioned_stylesheet(url: String, version: &'static str) -> StyleSheet {
+    if url.contains('?') {
+        StyleSheet::at(concat_string!(url, "&v=", version))
+    } else {
+        StyleSheet::at(url).with_version(version)
+    }
+}
+
+// Adds the font settings of the code, if any, and the stylesheet declaring the web fonts.
+fn add_font_assets(cx: &mut Context) {
+    if let Some(url) = &config::SETTINGS.hljs.font_url {
+        cx.set_assets(AssetsOp::AddStyleSheet(StyleSheet::at(url)));
+    }
+
+    let mut blocks = String::new();
+    let mut code = String::new();
+    if let Some(family) = cx.hljs_font_family() {
+        code.push_str(&concat_string!("font-family:", family, ";"));
+    }
+    if let Some(ligatures) = cx.hljs_ligatures() {
+        code.push_str(if ligatures {
+            "font-variant-ligatures:normal;font-feature-settings:normal;"
+        } else {
+            "font-variant-ligatures:none;font-feature-settings:\"liga\" 0,\"calt\" 0;"
+        });
+    }
+    if let Some(size) = cx.hljs_font_size() {
+        blocks.push_str(&concat_string!("font-size:", size, ";"));
+    }
+    if let Some(height) = cx.hljs_line_height() {
+        blocks.push_str(&concat_string!("line-height:", height, ";"));
+    }
+    if code.is_empty() && blocks.is_empty() {
+        return;
+    }
+
+    // The gutter and the marks of the snippets share the font settings to keep lines aligned.
+    let mut styles = String::new();
+    if !code.is_empty() {
+        styles.push_str(&concat_string!(
+            ".hljs-snippet pre,.hljs-snippet pre code,code.hljs-inline{",
+            code,
+            "}"
+        ));
+    }
+    if !blocks.is_empty() {
+        styles.push_str(&concat_string!(
+            ".hljs-snippet pre{",
+            blocks,
+            "}",
+            ".hljs-snippet pre code{font-size:inherit;line-height:inherit}"
+        ));
+    }
+    cx.set_assets(AssetsOp::AddHeadStyles(
+        HeadStyles::named("hljs-font").with_styles(styles),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[pagetop::test]
+    async fn every_asset_url_is_served() {
+        let app = service::test::init_service(
+            service::App::new().configure(|cfg| HighlightJS.configure_service(cfg)),
+        )
+        .await;
+
+        let languages = HljsLang::all()
+            .into_iter()
+            .map(|language| language.to_url());
+        let themes = HljsTheme::all().into_iter().flat_map(|theme| {
+            let images = theme.assets().into_iter();
+            let schemes = ["light", "dark"]
+                .map(|scheme| concat_string!("/hljs/css/", scheme, "/", theme.to_string(), ".css"));
+            std::iter::once(HljsTheme::to_url(theme.to_string()))
+                .chain(schemes)
+                .chain(images.map(|image| concat_string!("/hljs/css/", image)))
+        });
+        for url in languages.chain(themes) {
+            let request = service::test::TestRequest::get().uri(&url).to_request();
+            let response = service::test::call_service(&app, request).await;
+            assert!(
+                response.status().is_success(),
+                "{url} returned {}",
+                response.status()
+            );
+        }
+    }
+
+    #[pagetop::test]
+    async fn token_colors_are_served_as_stylesheet() {
+        let app = service::test::init_service(
+            service::App::new().configure(|cfg| HighlightJS.configure_service(cfg)),
+        )
+        .await;
+
+        let colors = [
+            (HljsToken::Keyword, String::from("#e63946")),
+            (HljsToken::Comment, String::from("rgb(106, 115, 125)")),
+        ];
+        let url = concat_string!(HljsToken::overrides_url(&colors), "&v=", PKG_VERSION);
+        let request = service::test::TestRequest::get().uri(&url).to_request();
+        let body = service::test::call_and_read_body(&app, request).await;
+        assert_eq!(body, HljsToken::overrides_css(&colors).as_bytes());
+
+        for url in [
+            "/hljs/css/tokens.css?unknown=%23e63946",
+            "/hljs/css/tokens.css?keyword=red%3Bcolor",
+        ] {
+            let request = service::test::TestRequest::get().uri(url).to_request();
+            let response = service::test::call_service(&app, request).await;
+            assert!(response.status().is_client_error(), "{url}");
         }
     }
 }
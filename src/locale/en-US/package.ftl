@@ -1 +1,8 @@
 hljs_description = Display beautiful code snippets on web pages using the highlight.js library.
+
+hljs_copy = Copy
+hljs_copied = Copied!
+
+hljs_theme = Code theme
+hljs_gallery = Gallery of code themes
+hljs_gallery_show = Show
@@ -1 +1,8 @@
 hljs_description = Incorpora fragmentos de código elegantes en páginas web usando la biblioteca highlight.js.
+
+hljs_copy = Copiar
+hljs_copied = ¡Copiado!
+
+hljs_theme = Tema del código
+hljs_gallery = Galería de temas para el código
+hljs_gallery_show = Mostrar
@@ -1,12 +1,16 @@
+use crate::error::{self, HljsError};
+
 use serde::{Deserialize, Deserializer};
 
-use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum HljsMode {
     Core,
     Common,
+    /// Highlight code on the server, so no JavaScript is sent to the browser. The languages of the
+    /// *common* library are supported, other languages are displayed as plain text.
+    Server,
 }
 
 impl ToString for HljsMode {
@@ -14,18 +18,23 @@ impl ToString for HljsMode {
         String::from(match self {
             HljsMode::Core => "core",
             HljsMode::Common => "common",
+            HljsMode::Server => "server",
         })
     }
 }
 
 impl FromStr for HljsMode {
-    type Err = fmt::Error;
+    type Err = HljsError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "core" => Ok(HljsMode::Core),
             "common" => Ok(HljsMode::Common),
-            _ => Err(fmt::Error),
+            "server" => Ok(HljsMode::Server),
+            _ => Err(HljsError::Mode {
+                value: s.to_string(),
+                suggestions: error::suggestions(s, ["core", "common", "server"]),
+            }),
         }
     }
 }
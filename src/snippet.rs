@@ -2,14 +2,34 @@
 
 use pagetop::prelude::*;
 
+use crate::context::{PARAM_HLJS_COPY, PARAM_HLJS_SCOPED_THEMES};
+use crate::css;
+use crate::highlight::highlight;
 use crate::HljsContext;
-use crate::HljsLang;
+use crate::{config, LOCALES_HLJS};
+use crate::{HljsLang, HljsMode, HljsTheme};
+
+use std::collections::BTreeSet;
+
+// Marker that highlights the line of code where it is found.
+const HIGHLIGHT_MARKER: &str = "[!hl]";
 
 #[derive(AutoDefault)]
 /// Component to put code snippets on web pages.
 pub struct Snippet {
-    language: HljsLang,
+    language: Option<HljsLang>,
+    // Language inferred from the file name or the shebang line, used when no language is set.
+    inferred_language: HljsLang,
     snippet: String,
+    title: Option<String>,
+    filename: Option<String>,
+    caption: Option<String>,
+    line_numbers: Option<bool>,
+    line_start: Option<usize>,
+    highlight_lines: String,
+    copy_button: Option<bool>,
+    theme: Option<HljsTheme>,
+    verbatim: bool,
 }
 
 impl ComponentTrait for Snippet {
@@ -19,16 +39,20 @@ impl ComponentTrait for Snippet {
 
     fn setup_before_prepare(&mut self, cx: &mut Context) {
         cx.add_hljs_language(self.language());
+        if self.copy_button() {
+            cx.set_param::<bool>(PARAM_HLJS_COPY, &true);
+        }
+        if let Some(theme) = self.theme() {
+            let themes = match cx.get_param::<String>(PARAM_HLJS_SCOPED_THEMES) {
+                Ok(previous) => concat_string!(previous, ";", theme.to_string()),
+                _ => theme.to_string(),
+            };
+            cx.set_param::<String>(PARAM_HLJS_SCOPED_THEMES, &themes);
+        }
     }
 
-    fn prepare_component(&self, _cx: &mut Context) -> PrepareMarkup {
-        PrepareMarkup::With(html! {
-            pre {
-                code class=(concat_string!("language-", self.language().to_string())) {
-                    (self.snippet())
-                }
-            }
-        })
+    fn prepare_component(&self, cx: &mut Context) -> PrepareMarkup {
+        PrepareMarkup::With(self.markup(cx))
     }
 }
 
@@ -41,23 +65,530 @@ impl Snippet {
 
     #[fn_builder]
     pub fn set_language(&mut self, language: HljsLang) -> &mut Self {
-        self.language = language;
+        self.language = Some(language);
         self
     }
 
+    /// Code of the snippet. Unless [`with_verbatim()`](Self::with_verbatim) is enabled, line
+    /// endings are normalized, leading and trailing blank lines are removed, and so is the
+    /// indentation common to all the lines. Tabs are also expanded to spaces if
+    /// [`config::SETTINGS.hljs.expand_tabs`](crate::config::Hljs#structfield.expand_tabs) is
+    /// enabled.
     #[fn_builder]
     pub fn set_snippet(&mut self, snippet: impl Into<String>) -> &mut Self {
-        self.snippet = snippet.into().trim().to_string();
+        self.snippet = snippet.into();
+        self.infer_language();
+        self
+    }
+
+    /// Display the code exactly as given, without any normalization. Line endings are kept as
+    /// they are, and so are the `[!hl]` markers, so lines can only be highlighted with
+    /// [`with_highlight_lines()`](Self::with_highlight_lines).
+    #[fn_builder]
+    pub fn set_verbatim(&mut self, verbatim: bool) -> &mut Self {
+        self.verbatim = verbatim;
+        self
+    }
+
+    /// Title shown in a header bar above the code, along with the snippet language.
+    #[fn_builder]
+    pub fn set_title(&mut self, title: impl Into<String>) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Name of the file the snippet belongs to. It is shown in the header bar if there is no
+    /// [`title()`](Self::title), and the snippet language is inferred from the file name when no
+    /// language is set.
+    #[fn_builder]
+    pub fn set_filename(&mut self, filename: impl Into<String>) -> &mut Self {
+        self.filename = Some(filename.into());
+        self.infer_language();
+        self
+    }
+
+    /// Caption shown below the code.
+    #[fn_builder]
+    pub fn set_caption(&mut self, caption: impl Into<String>) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Show or hide the line-number gutter for this snippet, overriding the
+    /// [`config::SETTINGS.hljs.line_numbers`](crate::config::Hljs#structfield.line_numbers)
+    /// configuration setting.
+    #[fn_builder]
+    pub fn set_line_numbers(&mut self, line_numbers: bool) -> &mut Self {
+        self.line_numbers = Some(line_numbers);
+        self
+    }
+
+    /// Number of the first line in the gutter. Default value: *1*.
+    #[fn_builder]
+    pub fn set_line_start(&mut self, line_start: usize) -> &mut Self {
+        self.line_start = Some(line_start);
+        self
+    }
+
+    /// Highlight lines of the snippet given by their positions, starting at 1 regardless of
+    /// [`line_start()`](Self::line_start), as a list of numbers and ranges like `"3,7-10"`. Invalid
+    /// entries and lines beyond the end of the snippet are ignored, and reversed ranges like
+    /// `"10-7"` are taken as `"7-10"`.
+    ///
+    /// Lines can also be highlighted by ending them with a `[!hl]` marker comment, like
+    /// `// [!hl]` or `# [!hl]`, which is removed from the rendered code.
+    #[fn_builder]
+    pub fn set_highlight_lines(&mut self, lines: impl AsRef<str>) -> &mut Self {
+        self.highlight_lines = lines.as_ref().to_string();
+        self
+    }
+
+    /// Show or hide the button to copy the code to the clipboard, overriding the
+    /// [`config::SETTINGS.hljs.copy_button`](crate::config::Hljs#structfield.copy_button)
+    /// configuration setting.
+    #[fn_builder]
+    pub fn set_copy_button(&mut self, copy_button: bool) -> &mut Self {
+        self.copy_button = Some(copy_button);
+        self
+    }
+
+    /// Displays the snippet with its own theme, instead of the one given by
+    /// [`hljs_theme()`](crate::HljsContext::hljs_theme) to all snippets in the context.
+    ///
+    /// The theme stylesheet is scoped to the snippet, so custom themes given by URL can't be used
+    /// and are ignored with a warning.
+    #[fn_builder]
+    pub fn set_theme(&mut self, theme: HljsTheme) -> &mut Self {
+        let name = theme.to_string();
+        if css::theme_css(&name).is_none() && HljsTheme::custom_css(&name).is_none() {
+            trace::warn!("Theme \"{name}\" ignored, custom themes given by URL can't be scoped");
+            return self;
+        }
+        self.theme = Some(theme);
         self
     }
 
     // Hljs GETTERS.
 
+    /// Returns the language given to the snippet, the one inferred from the
+    /// [`filename()`](Self::filename) or from the shebang line of the snippet, or
+    /// [`HljsLang::Plaintext`] otherwise.
     pub fn language(&self) -> &HljsLang {
-        &self.language
+        self.language.as_ref().unwrap_or(&self.inferred_language)
     }
 
     pub fn snippet(&self) -> &String {
         &self.snippet
     }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    pub fn caption(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
+
+    pub fn line_numbers(&self) -> bool {
+        self.line_numbers
+            .unwrap_or(config::SETTINGS.hljs.line_numbers)
+    }
+
+    pub fn line_start(&self) -> usize {
+        self.line_start.unwrap_or(1)
+    }
+
+    /// Returns the positions of the lines given by
+    /// [`with_highlight_lines()`](Self::with_highlight_lines), within the lines of the snippet.
+    pub fn highlight_lines(&self) -> BTreeSet<usize> {
+        parse_lines(&self.highlight_lines, self.code().lines().count())
+    }
+
+    pub fn verbatim(&self) -> bool {
+        self.verbatim
+    }
+
+    pub fn copy_button(&self) -> bool {
+        self.copy_button
+            .unwrap_or(config::SETTINGS.hljs.copy_button)
+    }
+
+    pub fn theme(&self) -> Option<HljsTheme> {
+        self.theme
+    }
+
+    // Snippet PRIVATE.
+
+    fn infer_language(&mut self) {
+        self.inferred_language = self
+            .filename()
+            .and_then(HljsLang::from_filename)
+            .or_else(|| HljsLang::from_shebang(self.snippet()))
+            .unwrap_or_default();
+    }
+
+    pub(crate) fn markup(&self, cx: &mut Context) -> Markup {
+        match self.theme() {
+            Some(theme) => html! {
+                div class=(concat_string!("hljs-scope hljs-scope-", theme.to_string())) {
+                    (self.figure(cx))
+                }
+            },
+            None => self.figure(cx),
+        }
+    }
+
+    fn figure(&self, cx: &mut Context) -> Markup {
+        let header = self.title().or(self.filename());
+        if header.is_none() && self.caption().is_none() {
+            return self.code_block(cx);
+        }
+        html! {
+            figure class="hljs-figure" {
+                @if let Some(header) = header {
+                    div class="hljs hljs-header" {
+                        span class="hljs-filename" { (header) }
+                        span class="hljs-language" { (self.language().name()) }
+                    }
+                }
+                (self.code_block(cx))
+                @if let Some(caption) = self.caption() {
+                    figcaption { (caption) }
+                }
+            }
+        }
+    }
+
+    // Label to identify the snippet, like in the tabs of a SnippetGroup.
+    pub(crate) fn label(&self) -> String {
+        match self.title().or(self.filename()) {
+            Some(label) => label.to_string(),
+            None => self.language().name().to_string(),
+        }
+    }
+
+    fn code_block(&self, cx: &mut Context) -> Markup {
+        let (code, marks) = self.marked_lines();
+        let lines = code.lines().count().max(1);
+        html! {
+            div class="hljs hljs-snippet" {
+                @if self.line_numbers() {
+                    pre class="hljs-gutter" aria-hidden="true" {
+                        (self.gutter(lines))
+                    }
+                }
+                div class="hljs-code" {
+                    @if !marks.is_empty() {
+                        pre class="hljs-marks" aria-hidden="true" {
+                            @for line in 1..=lines {
+                                span class=[marks.contains(&line).then_some("hljs-mark")] {
+                                    "\u{200b}"
+                                }
+                            }
+                        }
+                    }
+                    pre {
+                        @if cx.hljs_mode() == HljsMode::Server {
+                            code class=(concat_string!("hljs language-", self.language().to_string())) {
+                                (highlight(self.language(), &code))
+                            }
+                        } @else {
+                            code class=(concat_string!("language-", self.language().to_string())) {
+                                (code)
+                            }
+                        }
+                    }
+                }
+                @if self.copy_button() {
+                    @let copy = L10n::t("hljs_copy", &LOCALES_HLJS).using(cx.langid());
+                    @let copied = L10n::t("hljs_copied", &LOCALES_HLJS).using(cx.langid());
+                    button
+                        type="button"
+                        class="hljs-copy"
+                        data-copy=[copy.as_ref()]
+                        data-copied=[copied]
+                    {
+                        (copy.unwrap_or_default())
+                    }
+                }
+            }
+        }
+    }
+
+    // Returns the code to display, normalized unless verbatim is enabled.
+    fn code(&self) -> String {
+        if self.verbatim() {
+            return self.snippet().to_owned();
+        }
+        let tabsize = config::SETTINGS
+            .hljs
+            .expand_tabs
+            .then_some(config::SETTINGS.hljs.tabsize);
+        dedent(self.snippet(), tabsize)
+    }
+
+    // Returns the code without highlight markers, along with the lines to highlight. Verbatim code
+    // is returned untouched.
+    fn marked_lines(&self) -> (String, BTreeSet<usize>) {
+        let code = self.code();
+        let mut marks = parse_lines(&self.highlight_lines, code.lines().count());
+        if self.verbatim() {
+            return (code, marks);
+        }
+        let code = code
+            .lines()
+            .enumerate()
+            .map(|(n, line)| match strip_marker(line) {
+                Some(line) => {
+                    marks.insert(n + 1);
+                    line
+                }
+                None => line.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        (code, marks)
+    }
+
+    fn gutter(&self, lines: usize) -> String {
+        let first = self.line_start();
+        (first..first + lines)
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+// Normalizes line endings, expands tabs if a tab size is given, removes leading and trailing blank
+// lines, and removes the indentation common to all the lines.
+fn dedent(code: &str, tabsize: Option<usize>) -> String {
+    let code = code.replace("\r\n", "\n").replace('\r', "\n");
+    let lines: Vec<String> = code
+        .lines()
+        .map(|line| match tabsize {
+            Some(tabsize) => expand_tabs(line, tabsize),
+            None => line.to_string(),
+        })
+        .collect();
+    let is_blank = |line: &&String| line.trim().is_empty();
+    let (Some(first), Some(last)) = (
+        lines.iter().position(|line| !is_blank(&line)),
+        lines.iter().rposition(|line| !is_blank(&line)),
+    ) else {
+        return String::new();
+    };
+    let lines = &lines[first..=last];
+    let indent = lines
+        .iter()
+        .filter(|line| !is_blank(line))
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .reduce(common_prefix)
+        .unwrap_or_default();
+    lines
+        .iter()
+        .map(|line| {
+            if is_blank(&line) {
+                ""
+            } else {
+                &line[indent.len()..]
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, ca), cb)| ca != cb)
+        .map(|((i, _), _)| i)
+        .unwrap_or(a.len().min(b.len()));
+    &a[..len]
+}
+
+fn expand_tabs(line: &str, tabsize: usize) -> String {
+    let tabsize = tabsize.max(1);
+    let mut expanded = String::with_capacity(line.len());
+    let mut column = 0;
+    for c in line.chars() {
+        if c == '\t' {
+            let spaces = tabsize - column % tabsize;
+            expanded.push_str(&" ".repeat(spaces));
+            column += spaces;
+        } else {
+            expanded.push(c);
+            column += 1;
+        }
+    }
+    expanded
+}
+
+// Parses line specs like "3,7-10" into the set of line positions, from 1 to the given number of
+// lines. Reversed ranges are swapped.
+fn parse_lines(spec: &str, count: usize) -> BTreeSet<usize> {
+    let mut lines = BTreeSet::new();
+    for item in spec.split(',').map(str::trim) {
+        let range: Option<(usize, usize)> = match item.split_once('-') {
+            Some((from, to)) => from.trim().parse().ok().zip(to.trim().parse().ok()),
+            None => item.parse().ok().map(|line| (line, line)),
+        };
+        if let Some((from, to)) = range {
+            let (from, to) = (from.min(to), from.max(to));
+            lines.extend(from.max(1)..=to.min(count));
+        }
+    }
+    lines
+}
+
+// Removes the highlight marker from the line, along with the comment delimiters around it.
+fn strip_marker(line: &str) -> Option<String> {
+    let (before, after) = line.split_once(HIGHLIGHT_MARKER)?;
+    let mut before = before.trim_end();
+    for opener in ["<!--", "//", "/*", "--", "#", ";", "%"] {
+        if let Some(code) = before.strip_suffix(opener) {
+            before = code.trim_end();
+            break;
+        }
+    }
+    let mut after = after.trim_start();
+    for closer in ["-->", "*/"] {
+        if let Some(code) = after.strip_prefix(closer) {
+            after = code;
+            break;
+        }
+    }
+    Some(concat_string!(before, after).trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::HljsThemeSource;
+
+    #[pagetop::test]
+    async fn renders_line_number_gutter() {
+        let mut page = Page::new(service::test::TestRequest::default().to_http_request());
+        let snippet = Snippet::with(HljsLang::Rust, "fn main() {\n}\n")
+            .with_line_numbers(true)
+            .with_line_start(9);
+
+        let markup = snippet.markup(page.context()).into_string();
+        assert!(markup.contains("<pre class=\"hljs-gutter\" aria-hidden=\"true\">9\n10</pre>"));
+        assert!(markup.contains(r#"<div class="hljs-code"><pre><code class="language-rust">"#));
+
+        let markup = snippet
+            .with_line_numbers(false)
+            .markup(page.context())
+            .into_string();
+        assert!(!markup.contains("hljs-gutter"));
+    }
+
+    #[test]
+    fn parses_highlighted_lines() {
+        let lines = |spec, count| parse_lines(spec, count).into_iter().collect::<Vec<usize>>();
+        assert_eq!(lines("3, 7-9", 10), [3, 7, 8, 9]);
+        assert_eq!(lines("9-7,2", 10), [2, 7, 8, 9]);
+        assert_eq!(lines("0,4-,x,-2,5-y", 10), Vec::<usize>::new());
+        assert_eq!(lines("8-12,15", 10), [8, 9, 10]);
+        assert_eq!(lines("1-18446744073709551615", 3), [1, 2, 3]);
+        assert_eq!(lines("18446744073709551615-1", 2), [1, 2]);
+        assert_eq!(lines("0-2", 5), [1, 2]);
+        assert_eq!(lines("", 5), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn dedents_code() {
+        let code = "\n\n    fn main() {\n        \n\n        run();\n    }\n  \n";
+        assert_eq!(dedent(code, None), "fn main() {\n\n\n    run();\n}");
+        assert_eq!(dedent("  a\r\n    b\r\n\r\n  c\r", None), "a\n  b\n\nc");
+        assert_eq!(dedent(" \n\t\n", None), "");
+    }
+
+    #[test]
+    fn dedents_mixed_tabs_and_spaces() {
+        // Tabs and spaces only match as the same indentation once tabs are expanded.
+        assert_eq!(dedent("\tone\n    two", None), "\tone\n    two");
+        assert_eq!(dedent("\tone\n    two", Some(4)), "one\ntwo");
+        assert_eq!(dedent("\t  one\n\t two", None), " one\ntwo");
+        assert_eq!(dedent("  \tone\n    two", Some(4)), "one\ntwo");
+        assert_eq!(dedent("\t\tone\n\ttwo", Some(2)), "  one\ntwo");
+    }
+
+    #[test]
+    fn expands_tabs_to_tab_stops() {
+        assert_eq!(expand_tabs("\ta", 4), "    a");
+        assert_eq!(expand_tabs("ab\tc", 4), "ab  c");
+        assert_eq!(expand_tabs("abcd\te", 4), "abcd    e");
+        assert_eq!(expand_tabs("ñ\tx", 2), "ñ x");
+        assert_eq!(expand_tabs("\ta", 0), " a");
+    }
+
+    #[test]
+    fn finds_common_prefix() {
+        assert_eq!(common_prefix("    ", "  \t"), "  ");
+        assert_eq!(common_prefix("\t", "\t\t"), "\t");
+        assert_eq!(common_prefix("", "  "), "");
+    }
+
+    #[test]
+    fn verbatim_code_is_not_processed() {
+        let code = "\r\n    let\tx = 1;  \r\n\r\n";
+        let snippet = Snippet::with(HljsLang::Rust, code);
+        assert_eq!(snippet.code(), "let\tx = 1;  ");
+        assert_eq!(snippet.with_verbatim(true).code(), code);
+    }
+
+    #[pagetop::test]
+    async fn renders_verbatim_code_as_given() {
+        let mut page = Page::new(service::test::TestRequest::default().to_http_request());
+        let snippet = Snippet::with(HljsLang::Rust, "\tx // [!hl]\r\n\r\n")
+            .with_verbatim(true)
+            .with_highlight_lines("2");
+
+        let markup = snippet.markup(page.context()).into_string();
+        assert!(markup.contains("<code class=\"language-rust\">\tx // [!hl]\r\n\r\n</code>"));
+        assert!(markup.contains("<span>\u{200b}</span><span class=\"hljs-mark\">\u{200b}</span>"));
+    }
+
+    #[test]
+    fn url_themes_are_not_scoped() {
+        let source = HljsThemeSource::Url("/scoped.css".to_string());
+        let url_theme = HljsTheme::register_custom("test-scoped-url", source).unwrap();
+        let source = HljsThemeSource::Css(".hljs{color:red}".to_string());
+        let css_theme = HljsTheme::register_custom("test-scoped-css", source).unwrap();
+
+        let snippet = Snippet::new().with_theme(HljsTheme::Github);
+        assert_eq!(snippet.theme(), Some(HljsTheme::Github));
+        assert_eq!(snippet.with_theme(css_theme).theme(), Some(css_theme));
+        assert_eq!(Snippet::new().with_theme(url_theme).theme(), None);
+    }
+
+    #[test]
+    fn strips_highlight_markers() {
+        assert_eq!(
+            strip_marker("let x = 1; // [!hl]").as_deref(),
+            Some("let x = 1;")
+        );
+        assert_eq!(strip_marker("x = 1  # [!hl]").as_deref(), Some("x = 1"));
+        assert_eq!(
+            strip_marker("<p>Hi</p> <!-- [!hl] -->").as_deref(),
+            Some("<p>Hi</p>")
+        );
+        assert_eq!(
+            strip_marker("int x; /* [!hl] */").as_deref(),
+            Some("int x;")
+        );
+        assert_eq!(
+            strip_marker("SELECT 1; -- [!hl]").as_deref(),
+            Some("SELECT 1;")
+        );
+        assert_eq!(strip_marker("[!hl]").as_deref(), Some(""));
+        assert_eq!(strip_marker("let x = 1; // hl"), None);
+    }
 }
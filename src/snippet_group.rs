@@ -0,0 +1,177 @@
+//! Add a new component to group code snippets in tabs.
+
+use pagetop::prelude::*;
+
+use crate::context::PARAM_HLJS_TABS;
+use crate::Snippet;
+
+#[derive(AutoDefault)]
+/// Component to group several [`Snippet`]s as tabs, like the same example written in different
+/// languages.
+///
+/// Without JavaScript the snippets are displayed one after another.
+///
+/// Groups sharing the same [`sync_key()`](Self::sync_key) switch together when a reader selects a
+/// tab in any of them, and the selection is remembered for the next visits.
+pub struct SnippetGroup {
+    id: OptionId,
+    snippets: Vec<Snippet>,
+    sync_key: Option<String>,
+}
+
+impl ComponentTrait for SnippetGroup {
+    fn new() -> Self {
+        SnippetGroup::default()
+    }
+
+    fn id(&self) -> Option<String> {
+        self.id.get()
+    }
+
+    fn setup_before_prepare(&mut self, cx: &mut Context) {
+        for snippet in self.snippets.iter_mut() {
+            snippet.setup_before_prepare(cx);
+        }
+        cx.set_param::<bool>(PARAM_HLJS_TABS, &true);
+    }
+
+    fn prepare_component(&self, cx: &mut Context) -> PrepareMarkup {
+        if self.snippets().is_empty() {
+            return PrepareMarkup::None;
+        }
+        let id = cx.required_id::<Self>(self.id());
+        let selected = self.selected(cx);
+        PrepareMarkup::With(html! {
+            div id=(id) class="hljs-tabs" data-hljs-sync=[self.sync_key()] {
+                div class="hljs-tablist" role="tablist" {
+                    @for (index, snippet) in self.snippets().iter().enumerate() {
+                        @let active = index == selected;
+                        button
+                            type="button"
+                            role="tab"
+                            id=(concat_string!(id, "-tab-", index.to_string()))
+                            class=[active.then_some("hljs-active")]
+                            data-hljs-value=(snippet.language().to_string())
+                            aria-selected=(active)
+                            aria-controls=(concat_string!(id, "-panel-", index.to_string()))
+                            tabindex=(if active { "0" } else { "-1" })
+                        {
+                            (snippet.label())
+                        }
+                    }
+                }
+                @for (index, snippet) in self.snippets().iter().enumerate() {
+                    div
+                        role="tabpanel"
+                        id=(concat_string!(id, "-panel-", index.to_string()))
+                        class=(if index == selected { "hljs-tabpanel hljs-active" } else { "hljs-tabpanel" })
+                        aria-labelledby=(concat_string!(id, "-tab-", index.to_string()))
+                    {
+                        (snippet.markup(cx))
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl SnippetGroup {
+    // SnippetGroup BUILDER.
+
+    #[fn_builder]
+    pub fn set_id(&mut self, id: impl Into<String>) -> &mut Self {
+        self.id.alter_value(id);
+        self
+    }
+
+    /// Add a new snippet to the group, displayed in its own tab.
+    #[fn_builder]
+    pub fn add_snippet(&mut self, snippet: Snippet) -> &mut Self {
+        self.snippets.push(snippet);
+        self
+    }
+
+    /// Key shared by the groups whose tabs are selected together. The selection is persisted in
+    /// the browser and in a `hljs-tab-<key>` cookie, so the preferred tab is already selected when
+    /// the page is rendered. Keys can only contain ASCII letters, digits, `_` and `-`, like
+    /// `"lang"`. Invalid keys are ignored.
+    #[fn_builder]
+    pub fn set_sync_key(&mut self, key: impl Into<String>) -> &mut Self {
+        let key = key.into();
+        let valid = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+        self.sync_key = (!key.is_empty() && key.chars().all(valid)).then_some(key);
+        self
+    }
+
+    // SnippetGroup GETTERS.
+
+    pub fn snippets(&self) -> &Vec<Snippet> {
+        &self.snippets
+    }
+
+    pub fn sync_key(&self) -> Option<&str> {
+        self.sync_key.as_deref()
+    }
+
+    // SnippetGroup PRIVATE.
+
+    // Index of the tab selected by the reader in a previous visit, if any.
+    fn selected(&self, cx: &Context) -> usize {
+        self.sync_key()
+            .and_then(|key| cx.request().cookie(&concat_string!("hljs-tab-", key)))
+            .and_then(|cookie| {
+                let value = decode_uri_component(cookie.value());
+                self.snippets()
+                    .iter()
+                    .position(|snippet| snippet.language().to_string() == value)
+            })
+            .unwrap_or(0)
+    }
+}
+
+// Decodes a value encoded by the JavaScript `encodeURIComponent()` function, like the values of the
+// cookies written by `tabs.js`.
+fn decode_uri_component(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let hex = |i: usize| bytes.get(i).and_then(|&b| char::from(b).to_digit(16));
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match (bytes[i], hex(i + 1), hex(i + 2)) {
+            (b'%', Some(high), Some(low)) => {
+                decoded.push((high * 16 + low) as u8);
+                i += 3;
+            }
+            (byte, _, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_cookie_values() {
+        assert_eq!(decode_uri_component("rust"), "rust");
+        assert_eq!(decode_uri_component("Cargo%20%26%20npm"), "Cargo & npm");
+        assert_eq!(decode_uri_component("Espa%C3%B1ol"), "Español");
+        assert_eq!(decode_uri_component("100%"), "100%");
+        assert_eq!(decode_uri_component("%zz%2"), "%zz%2");
+    }
+
+    #[test]
+    fn ignores_invalid_sync_keys() {
+        assert_eq!(
+            SnippetGroup::new().with_sync_key("lang_1-a").sync_key(),
+            Some("lang_1-a")
+        );
+        assert_eq!(SnippetGroup::new().with_sync_key("my key").sync_key(), None);
+        assert_eq!(SnippetGroup::new().with_sync_key("clé").sync_key(), None);
+        assert_eq!(SnippetGroup::new().with_sync_key("").sync_key(), None);
+    }
+}
@@ -0,0 +1,180 @@
+//! Support for the [`include_snippet!`](crate::include_snippet) macro.
+
+use pagetop::prelude::*;
+
+use crate::{HljsLang, Snippet};
+
+const ANCHOR: &str = "ANCHOR:";
+const ANCHOR_END: &str = "ANCHOR_END:";
+
+/// Embeds a source file at compile time into a new [`Snippet`], inferring the [`HljsLang`] from
+/// the file name.
+///
+/// The path is relative to the file where the macro is called. Optionally, only a named region of
+/// the file can be embedded, delimited by `ANCHOR: <name>` and `ANCHOR_END: <name>` markers,
+/// usually written as comments. Anchor lines are always removed from the snippet. A missing file
+/// or region is a compile error.
+///
+/// ```rust,ignore
+/// use pagetop_hljs::prelude::*;
+///
+/// // The whole file.
+/// let main = include_snippet!("../examples/main.rs");
+///
+/// // Only the lines between `// ANCHOR: setup` and `// ANCHOR_END: setup`.
+/// let setup = include_snippet!("../examples/main.rs", "setup");
+/// ```
+#[macro_export]
+macro_rules! include_snippet {
+    ( $file:literal ) => {
+        $crate::source::snippet($file, include_str!($file), None)
+    };
+    ( $file:literal, $region:literal ) => {{
+        const _: () = assert!(
+            $crate::source::has_region(include_str!($file), $region),
+            concat!("region `", $region, "` not found in ", $file),
+        );
+        $crate::source::snippet($file, include_str!($file), Some($region))
+    }};
+}
+
+#[doc(hidden)]
+pub fn snippet(filename: &str, source: &str, region: Option<&str>) -> Snippet {
+    let snippet = Snippet::new().with_snippet(extract(source, region));
+    match HljsLang::from_filename(filename) {
+        Some(language) => snippet.with_language(language),
+        None => snippet,
+    }
+}
+
+#[doc(hidden)]
+pub const fn has_region(source: &str, region: &str) -> bool {
+    has_anchor(source.as_bytes(), ANCHOR.as_bytes(), region.as_bytes())
+        && has_anchor(source.as_bytes(), ANCHOR_END.as_bytes(), region.as_bytes())
+}
+
+// Returns the lines of the region, or the whole source if no region is given, removing all the
+// anchor lines.
+fn extract(source: &str, region: Option<&str>) -> String {
+    let mut inside = region.is_none();
+    let mut lines = Vec::new();
+    for line in source.lines() {
+        if let Some(name) = anchor_name(line, ANCHOR) {
+            if region == Some(name) {
+                inside = true;
+            }
+        } else if let Some(name) = anchor_name(line, ANCHOR_END) {
+            if region == Some(name) {
+                inside = false;
+            }
+        } else if inside {
+            lines.push(line);
+        }
+    }
+    lines.join("\n")
+}
+
+fn anchor_name<'a>(line: &'a str, anchor: &str) -> Option<&'a str> {
+    let (_, name) = line.split_once(anchor)?;
+    name.split_whitespace().next()
+}
+
+// Checks at compile time whether the source has the anchor followed by the region name.
+const fn has_anchor(source: &[u8], anchor: &[u8], name: &[u8]) -> bool {
+    let mut i = 0;
+    while i + anchor.len() <= source.len() {
+        if starts_with(source, i, anchor) {
+            let mut j = i + anchor.len();
+            while j < source.len() && (source[j] == b' ' || source[j] == b'\t') {
+                j += 1;
+            }
+            if starts_with(source, j, name) {
+                let end = j + name.len();
+                if end == source.len() || source[end].is_ascii_whitespace() {
+                    return true;
+                }
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn starts_with(source: &[u8], at: usize, prefix: &[u8]) -> bool {
+    if at + prefix.len() > source.len() {
+        return false;
+    }
+    let mut k = 0;
+    while k < prefix.len() {
+        if source[at + k] != prefix[k] {
+            return false;
+        }
+        k += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+use std::io;
+// ANCHOR: all
+// ANCHOR: main
+fn main() {
+    // ANCHOR: body
+    println!(\"Hello\");
+    // ANCHOR_END: body
+}
+// ANCHOR_END: main
+// ANCHOR: helper
+fn helper() {}
+// ANCHOR_END: all
+fn other() {}
+// ANCHOR_END: helper
+";
+
+    #[test]
+    fn extracts_regions_without_anchor_lines() {
+        assert_eq!(
+            extract(SOURCE, None),
+            "use std::io;\nfn main() {\n    println!(\"Hello\");\n}\nfn helper() {}\nfn other() {}"
+        );
+        assert_eq!(
+            extract(SOURCE, Some("main")),
+            "fn main() {\n    println!(\"Hello\");\n}"
+        );
+        assert_eq!(extract(SOURCE, Some("body")), "    println!(\"Hello\");");
+    }
+
+    #[test]
+    fn extracts_overlapping_regions() {
+        assert_eq!(
+            extract(SOURCE, Some("all")),
+            "fn main() {\n    println!(\"Hello\");\n}\nfn helper() {}"
+        );
+        assert_eq!(
+            extract(SOURCE, Some("helper")),
+            "fn helper() {}\nfn other() {}"
+        );
+    }
+
+    #[test]
+    fn joins_regions_opened_several_times() {
+        let source =
+            "// ANCHOR: a\none\n// ANCHOR_END: a\ntwo\n// ANCHOR: a\nthree\n// ANCHOR_END: a";
+        assert_eq!(extract(source, Some("a")), "one\nthree");
+    }
+
+    #[test]
+    fn detects_missing_regions() {
+        assert!(has_region(SOURCE, "main"));
+        assert!(has_region(SOURCE, "body"));
+        assert!(!has_region(SOURCE, "mai"));
+        assert!(!has_region(SOURCE, "missing"));
+        assert!(!has_region("// ANCHOR: open\nfn main() {}", "open"));
+        assert!(!has_region("// ANCHOR_END: close", "close"));
+        assert_eq!(extract(SOURCE, Some("missing")), "");
+    }
+}
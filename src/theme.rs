@@ -1,244 +1,145 @@
 use pagetop::prelude::*;
 
+use crate::config;
+use crate::contrast::{self, ContrastAudit, WcagLevel};
+use crate::css;
+use crate::error::{self, HljsError};
+
 use serde::{Deserialize, Deserializer};
 
 use std::collections::HashMap;
 use std::str::FromStr;
-use std::sync::LazyLock;
-
-use std::fmt;
-
-/// Supported themes.
-///
-/// Themes are defined as *PascalCase* enums in the code and correspond to *kebab-case* string
-/// identifiers.
-///
-/// ```rust
-/// use pagetop_hljs::HljsTheme;
-///
-/// assert_eq!(HljsTheme::AtelierPlateauLight.to_string(), "atelier-plateau-light".to_string());
-/// ```
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-pub enum HljsTheme {
-    A11yDark,
-    A11yLight,
-    Agate,
-    AnOldHope,
-    Androidstudio,
-    ArduinoLight,
-    Arta,
-    Ascetic,
-    AtelierCave,
-    AtelierCaveLight,
-    AtelierDune,
-    AtelierDuneLight,
-    AtelierEstuary,
-    AtelierEstuaryLight,
-    AtelierForest,
-    AtelierForestLight,
-    AtelierHeath,
-    AtelierHeathLight,
-    AtelierLakeside,
-    AtelierLakesideLight,
-    AtelierPlateau,
-    AtelierPlateauLight,
-    AtelierSavanna,
-    AtelierSavannaLight,
-    AtelierSeaside,
-    AtelierSeasideLight,
-    AtelierSulphurpool,
-    AtelierSulphurpoolLight,
-    AtomOneDark,
-    AtomOneDarkReasonable,
-    AtomOneLight,
-    BrownPaper,
-    CodepenEmbed,
-    ColorBrewer,
-    Darcula,
-    Dark,
-    Default,
-    Devibeans,
-    Docco,
-    Dracula,
-    Far,
-    Foundation,
-    Framer,
-    Gigavolt,
-    Github,
-    Gml,
-    Googlecode,
-    GradientDark,
-    GradientLight,
-    Grayscale,
-    GruvboxDarkHard,
-    GruvboxLightHard,
-    Hopscotch,
-    Hybrid,
-    Idea,
-    IrBlack,
-    KimbieDark,
-    KimbieLight,
-    Lightfair,
-    Lioshi,
-    Magula,
-    MonoBlue,
-    MonokaiSublime,
-    NightOwl,
-    NnfxDark,
-    NnfxLight,
-    Obsidian,
-    Ocean,
-    Oceanicnext,
-    PandaSyntaxDark,
-    PandaSyntaxLight,
-    Pojoaque,
-    Purebasic,
-    QtcreatorDark,
-    QtcreatorLight,
-    Railcasts,
-    Rainbow,
-    Routeros,
-    SchoolBook,
-    ShapesOfPurple,
-    SolarizedDark,
-    SolarizedLight,
-    Srcery,
-    StackoverflowDark,
-    StackoverflowLight,
-    Sunburst,
-    TokioNightDark,
-    TokioNightLight,
-    Tomorrow,
-    TomorrowNight,
-    TomorrowNightBlue,
-    TomorrowNightBright,
-    Vs,
-    Vs2015,
-    Xcode,
-    Xt256,
-    Zenburn,
+use std::sync::{LazyLock, RwLock};
+
+// The `HljsTheme` enum and the `HLJS_THEMES` map are generated by `build.rs` from the stylesheets
+// in `static/css`, with the overrides in `build/themes.toml`.
+include!(concat!(env!("OUT_DIR"), "/theme.rs"));
+
+/// Stylesheet of a custom theme.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HljsThemeSource {
+    /// Path or URL to the stylesheet, like `"/css/brand-theme.css"`.
+    Url(String),
+    /// CSS of the theme, served by the package at `/hljs/css/custom/<name>.css`.
+    Css(String),
+}
+
+// Theme metadata derived from the theme stylesheet.
+struct Metadata {
+    display_name: String,
+    dark: bool,
+    base16: bool,
+    background: Option<String>,
+    foreground: Option<String>,
+    assets: Vec<String>,
+    audit: Option<ContrastAudit>,
 }
 
-static HLJS_THEMES: LazyLock<HashMap<HljsTheme, &'static str>> = LazyLock::new(|| {
-    use HljsTheme::*;
-    kv![
-        A11yDark                => "a11y-dark",
-        A11yLight               => "a11y-light",
-        Agate                   => "agate",
-        AnOldHope               => "an-old-hope",
-        Androidstudio           => "androidstudio",
-        ArduinoLight            => "arduino-light",
-        Arta                    => "arta",
-        Ascetic                 => "ascetic",
-        AtelierCave             => "atelier-cave",                 // base16
-        AtelierCaveLight        => "atelier-cave-light",           // base16
-        AtelierDune             => "atelier-dune",                 // base16
-        AtelierDuneLight        => "atelier-dune-light",           // base16
-        AtelierEstuary          => "atelier-estuary",              // base16
-        AtelierEstuaryLight     => "atelier-estuary-light",        // base16
-        AtelierForest           => "atelier-forest",               // base16
-        AtelierForestLight      => "atelier-forest-light",         // base16
-        AtelierHeath            => "atelier-heath",                // base16
-        AtelierHeathLight       => "atelier-heath-light",          // base16
-        AtelierLakeside         => "atelier-lakeside",             // base16
-        AtelierLakesideLight    => "atelier-lakeside-light",       // base16
-        AtelierPlateau          => "atelier-plateau",              // base16
-        AtelierPlateauLight     => "atelier-plateau-light",        // base16
-        AtelierSavanna          => "atelier-savanna",              // base16
-        AtelierSavannaLight     => "atelier-savanna-light",        // base16
-        AtelierSeaside          => "atelier-seaside",              // base16
-        AtelierSeasideLight     => "atelier-seaside-light",        // base16
-        AtelierSulphurpool      => "atelier-sulphurpool",          // base16
-        AtelierSulphurpoolLight => "atelier-sulphurpool-light",    // base16
-        AtomOneDark             => "atom-one-dark",
-        AtomOneDarkReasonable   => "atom-one-dark-reasonable",
-        AtomOneLight            => "atom-one-light",
-        BrownPaper              => "brown-paper",
-        CodepenEmbed            => "codepen-embed",
-        ColorBrewer             => "color-brewer",
-        Darcula                 => "darcula",                      // base16
-        Dark                    => "dark",
-        Default                 => "default",
-        Devibeans               => "devibeans",
-        Docco                   => "docco",
-        Dracula                 => "dracula",                      // base16
-        Far                     => "far",
-        Foundation              => "foundation",
-        Framer                  => "framer",                       // base16
-        Gigavolt                => "gigavolt",                     // base16
-        Github                  => "github",
-        Gml                     => "gml",
-        Googlecode              => "googlecode",
-        GradientDark            => "gradient-dark",
-        GradientLight           => "gradient-light",
-        Grayscale               => "grayscale",
-        GruvboxDarkHard         => "gruvbox-dark-hard",            // base16
-        GruvboxLightHard        => "gruvbox-light-hard",           // base16
-        Hopscotch               => "hopscotch",                    // base16
-        Hybrid                  => "hybrid",
-        Idea                    => "idea",
-        IrBlack                 => "ir-black",
-        KimbieDark              => "kimbie-dark",
-        KimbieLight             => "kimbie-light",
-        Lightfair               => "lightfair",
-        Lioshi                  => "lioshi",
-        Magula                  => "magula",
-        MonoBlue                => "mono-blue",
-        MonokaiSublime          => "monokai-sublime",
-        NightOwl                => "night-owl",
-        NnfxDark                => "nnfx-dark",
-        NnfxLight               => "nnfx-light",
-        Obsidian                => "obsidian",
-        Ocean                   => "ocean",                        // base16
-        Oceanicnext             => "oceanicnext",                  // base16
-        PandaSyntaxDark         => "panda-syntax-dark",
-        PandaSyntaxLight        => "panda-syntax-light",
-        Pojoaque                => "pojoaque",
-        Purebasic               => "purebasic",
-        QtcreatorDark           => "qtcreator-dark",
-        QtcreatorLight          => "qtcreator-light",
-        Railcasts               => "railcasts",                    // base16
-        Rainbow                 => "rainbow",
-        Routeros                => "routeros",
-        SchoolBook              => "school-book",
-        ShapesOfPurple          => "shapes-of-purple",
-        SolarizedDark           => "solarized-dark",               // base16
-        SolarizedLight          => "solarized-light",              // base16
-        Srcery                  => "srcery",
-        StackoverflowDark       => "stackoverflow-dark",
-        StackoverflowLight      => "stackoverflow-light",
-        Sunburst                => "sunburst",
-        TokioNightDark          => "tokio-night-dark",
-        TokioNightLight         => "tokio-night-light",
-        Tomorrow                => "tomorrow",                     // base16
-        TomorrowNight           => "tomorrow-night",               // base16
-        TomorrowNightBlue       => "tomorrow-night-blue",
-        TomorrowNightBright     => "tomorrow-night-bright",
-        Vs                      => "vs",
-        Vs2015                  => "vs2015",
-        Xcode                   => "xcode",
-        Xt256                   => "xt256",
-        Zenburn                 => "zenburn",                      // base16
-    ]
+impl Metadata {
+    fn from_css(name: &str, css: &str) -> Self {
+        let mut background = None;
+        let mut foreground = None;
+        for (property, value) in css::declarations(css, ".hljs") {
+            match property.as_str() {
+                "background" | "background-color" => background = css::color(&value).or(background),
+                "color" => foreground = css::color(&value).or(foreground),
+                _ => {}
+            }
+        }
+        let luminance = |color: &Option<String>| color.as_deref().and_then(css::luminance);
+        Metadata {
+            display_name: css::header_field(css, "Theme").unwrap_or_else(|| display_name(name)),
+            dark: match (luminance(&background), luminance(&foreground)) {
+                (Some(background), Some(foreground)) => background < foreground,
+                (Some(background), None) => background < 0.5,
+                _ => false,
+            },
+            base16: css.to_ascii_lowercase().contains("base16"),
+            background,
+            foreground,
+            assets: css::assets(css),
+            audit: contrast::audit(css),
+        }
+    }
+}
+
+static HLJS_METADATA: LazyLock<HashMap<&'static str, Metadata>> = LazyLock::new(|| {
+    css::THEME_CSS
+        .iter()
+        .map(|&(name, css)| (name, Metadata::from_css(name, css)))
+        .collect()
 });
 
+// Display name from the kebab-case name, like "Atom One Dark" for "atom-one-dark".
+fn display_name(name: &str) -> String {
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => concat_string!(first.to_uppercase().to_string(), chars.as_str()),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Handle of a custom theme, given by its position in the registry of custom themes (see
+/// [`HljsTheme::register_custom()`]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct HljsCustomTheme(usize);
+
+// Registered custom themes, by name and stylesheet. Themes are never removed, so the position of a
+// theme is a stable handle.
+static CUSTOM_THEMES: LazyLock<RwLock<Vec<(String, HljsThemeSource)>>> =
+    LazyLock::new(|| RwLock::new(Vec::new()));
+
+// Returns the result of applying the function to the stylesheet of the registered custom theme.
+fn custom_source<R>(name: &str, f: impl FnOnce(&HljsThemeSource) -> R) -> Option<R> {
+    CUSTOM_THEMES
+        .read()
+        .unwrap()
+        .iter()
+        .find(|(custom, _)| custom == name)
+        .map(|(_, source)| f(source))
+}
+
 impl ToString for HljsTheme {
     fn to_string(&self) -> String {
-        String::from(*HLJS_THEMES.get(self).unwrap())
+        match self {
+            HljsTheme::Custom(custom) => CUSTOM_THEMES.read().unwrap()[custom.0].0.to_owned(),
+            theme => String::from(*HLJS_THEMES.get(theme).unwrap()),
+        }
     }
 }
 
 impl FromStr for HljsTheme {
-    type Err = fmt::Error;
+    type Err = HljsError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         HLJS_THEMES
             .iter()
+            .chain(
+                HLJS_THEME_ALIASES
+                    .iter()
+                    .map(|(alias, theme)| (theme, alias)),
+            )
             .find_map(|(&key, &value)| if value == s { Some(key) } else { None })
-            .ok_or_else(|| fmt::Error)
+            .or_else(|| {
+                CUSTOM_THEMES
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .position(|(name, _)| name == s)
+                    .map(|index| HljsTheme::Custom(HljsCustomTheme(index)))
+            })
+            .ok_or_else(|| HljsTheme::unknown(s))
     }
 }
 
+// Only bundled and registered themes are accepted, so custom themes given in the configuration
+// settings are registered before the themes using them are deserialized.
 impl<'de> Deserialize<'de> for HljsTheme {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -249,9 +150,362 @@ impl<'de> Deserialize<'de> for HljsTheme {
     }
 }
 
+fn is_kebab_case(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
 impl HljsTheme {
+    /// Returns all the bundled themes sorted by name.
+    pub fn all() -> Vec<HljsTheme> {
+        let mut themes: Vec<HljsTheme> = HLJS_THEMES.keys().copied().collect();
+        themes.sort_by_key(|theme| HLJS_THEMES.get(theme).unwrap());
+        themes
+    }
+
+    /// Returns the bundled themes with a dark background, sorted by name.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsTheme;
+    ///
+    /// assert!(HljsTheme::dark_themes().contains(&HljsTheme::Obsidian));
+    /// assert!(!HljsTheme::dark_themes().contains(&HljsTheme::Github));
+    /// ```
+    pub fn dark_themes() -> Vec<HljsTheme> {
+        Self::all()
+            .into_iter()
+            .filter(|theme| theme.is_dark())
+            .collect()
+    }
+
+    /// Returns the bundled themes with a light background, sorted by name.
+    pub fn light_themes() -> Vec<HljsTheme> {
+        Self::all()
+            .into_iter()
+            .filter(|theme| !theme.is_dark())
+            .collect()
+    }
+
+    /// Returns the bundled themes based on a base16 color scheme, sorted by name.
+    pub fn base16_themes() -> Vec<HljsTheme> {
+        Self::all()
+            .into_iter()
+            .filter(|theme| theme.is_base16())
+            .collect()
+    }
+
+    /// Returns the bundled themes whose token colors reach the given WCAG contrast level against
+    /// the theme background, sorted by name.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::{HljsTheme, WcagLevel};
+    ///
+    /// let themes = HljsTheme::accessible_themes(WcagLevel::AAA);
+    /// assert!(themes.iter().all(|theme| theme.passes_contrast(WcagLevel::AAA)));
+    /// assert!(themes.iter().all(|theme| theme.passes_contrast(WcagLevel::AA)));
+    /// ```
+    pub fn accessible_themes(level: WcagLevel) -> Vec<HljsTheme> {
+        Self::all()
+            .into_iter()
+            .filter(|theme| theme.passes_contrast(level))
+            .collect()
+    }
+
+    // HljsTheme METADATA.
+
+    /// Returns the human-readable name of the theme.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsTheme;
+    ///
+    /// assert_eq!(HljsTheme::Github.display_name(), "GitHub");
+    /// assert_eq!(HljsTheme::AtomOneDark.display_name(), "Atom One Dark");
+    /// ```
+    pub fn display_name(&self) -> String {
+        self.metadata(|metadata| metadata.display_name.to_owned())
+            .unwrap_or_else(|| display_name(&self.to_string()))
+    }
+
+    /// Returns `true` if the theme has a dark background, or `false` if the stylesheet of a custom
+    /// theme is not known.
+    pub fn is_dark(&self) -> bool {
+        self.metadata(|metadata| metadata.dark).unwrap_or(false)
+    }
+
+    /// Returns `true` if the theme is based on a base16 color scheme.
+    pub fn is_base16(&self) -> bool {
+        self.metadata(|metadata| metadata.base16).unwrap_or(false)
+    }
+
+    /// Returns the background color of the code as `#rrggbb`.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsTheme;
+    ///
+    /// assert_eq!(HljsTheme::Ocean.background().as_deref(), Some("#2b303b"));
+    /// assert_eq!(HljsTheme::Ocean.foreground().as_deref(), Some("#c0c5ce"));
+    /// ```
+    pub fn background(&self) -> Option<String> {
+        self.metadata(|metadata| metadata.background.to_owned())
+            .flatten()
+    }
+
+    /// Returns the default text color of the code as `#rrggbb`.
+    pub fn foreground(&self) -> Option<String> {
+        self.metadata(|metadata| metadata.foreground.to_owned())
+            .flatten()
+    }
+
+    /// Returns the file names of the images required by the theme, served next to its stylesheet.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::HljsTheme;
+    ///
+    /// assert_eq!(HljsTheme::Pojoaque.assets(), vec!["pojoaque.jpg"]);
+    /// ```
+    pub fn assets(&self) -> Vec<String> {
+        self.metadata(|metadata| metadata.assets.to_owned())
+            .unwrap_or_default()
+    }
+
+    /// Returns the contrast of every token color against the theme background, or `None` if the
+    /// stylesheet of a custom theme is not known or has no background color.
+    pub fn contrast_audit(&self) -> Option<ContrastAudit> {
+        self.metadata(|metadata| metadata.audit.to_owned())
+            .flatten()
+    }
+
+    /// Returns `true` if every token color reaches the given WCAG contrast level.
+    pub fn passes_contrast(&self, level: WcagLevel) -> bool {
+        self.metadata(|metadata| {
+            metadata
+                .audit
+                .as_ref()
+                .is_some_and(|audit| audit.passes(level))
+        })
+        .unwrap_or(false)
+    }
+
+    // Returns `true` if the theme can be used given the `hljs.accessible_only` setting.
+    pub(crate) fn is_allowed(&self) -> bool {
+        !config::SETTINGS.hljs.accessible_only || self.passes_contrast(WcagLevel::AA)
+    }
+
+    // Returns the error for a theme that can't be used with the `hljs.accessible_only` setting,
+    // either because it doesn't reach the WCAG AA contrast level or because its contrast can't be
+    // checked, as for custom themes given by URL.
+    pub(crate) fn check_contrast(&self) -> Result<(), HljsError> {
+        if self.contrast_audit().is_none() {
+            return Err(HljsError::Unaudited {
+                value: self.to_string(),
+            });
+        }
+        if !self.passes_contrast(WcagLevel::AA) {
+            let name = self.to_string();
+            let accessible: Vec<String> = HljsTheme::accessible_themes(WcagLevel::AA)
+                .iter()
+                .map(HljsTheme::to_string)
+                .collect();
+            return Err(HljsError::Contrast {
+                suggestions: error::suggestions(&name, accessible.iter().map(String::as_str)),
+                value: name,
+            });
+        }
+        Ok(())
+    }
+
+    fn metadata<R>(&self, f: impl FnOnce(&Metadata) -> R) -> Option<R> {
+        let name = self.to_string();
+        match self {
+            HljsTheme::Custom(_) => {
+                HljsTheme::custom_css(&name).map(|css| f(&Metadata::from_css(&name, &css)))
+            }
+            _ => HLJS_METADATA.get(name.as_str()).map(f),
+        }
+    }
+
+    /// Registers a custom theme with the given *kebab-case* name, which can't be the name, or a
+    /// former name, of a bundled theme. Registering the same name again replaces its stylesheet.
+    ///
+    /// ```rust
+    /// use pagetop_hljs::{HljsError, HljsTheme, HljsThemeSource};
+    ///
+    /// let source = HljsThemeSource::Url("/css/brand.css".into());
+    /// let brand = HljsTheme::register_custom("brand", source.clone()).unwrap();
+    /// assert_eq!(brand.to_string(), "brand");
+    /// assert_eq!("brand".parse::<HljsTheme>(), Ok(brand));
+    ///
+    /// let error = HljsTheme::register_custom("github", source).unwrap_err();
+    /// assert!(matches!(error, HljsError::CustomTheme { .. }));
+    /// ```
+    pub fn register_custom(
+        name: impl Into<String>,
+        source: HljsThemeSource,
+    ) -> Result<HljsTheme, HljsError> {
+        let name = name.into();
+        let mut bundled = HLJS_THEMES
+            .values()
+            .chain(HLJS_THEME_ALIASES.iter().map(|(alias, _)| alias));
+        if !is_kebab_case(&name) || bundled.any(|&theme| theme == name) {
+            return Err(HljsError::CustomTheme { value: name });
+        }
+        let mut custom_themes = CUSTOM_THEMES.write().unwrap();
+        let index = match custom_themes.iter().position(|(custom, _)| *custom == name) {
+            Some(index) => {
+                custom_themes[index].1 = source;
+                index
+            }
+            None => {
+                custom_themes.push((name, source));
+                custom_themes.len() - 1
+            }
+        };
+        Ok(HljsTheme::Custom(HljsCustomTheme(index)))
+    }
+
+    // Returns the names of all the available themes, bundled and registered, sorted by name.
+    pub(crate) fn available_names() -> Vec<String> {
+        let mut names: Vec<String> = HLJS_THEMES
+            .values()
+            .map(|theme| theme.to_string())
+            .chain(
+                CUSTOM_THEMES
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .map(|(name, _)| name.to_owned()),
+            )
+            .collect();
+        names.sort();
+        names
+    }
+
+    // Returns the error for an unknown theme, suggesting the most similar available themes.
+    pub(crate) fn unknown(name: &str) -> HljsError {
+        let names = HljsTheme::available_names();
+        HljsError::Theme {
+            value: name.to_string(),
+            suggestions: error::suggestions(name, names.iter().map(String::as_str)),
+        }
+    }
+
+    // Returns the CSS of a custom theme given by its stylesheet contents.
+    pub(crate) fn custom_css(theme: &str) -> Option<String> {
+        custom_source(theme, |source| match source {
+            HljsThemeSource::Css(css) => Some(css.to_owned()),
+            HljsThemeSource::Url(_) => None,
+        })
+        .flatten()
+    }
+
     pub(crate) fn to_url(theme: impl Into<String>) -> String {
         let theme = theme.into();
-        concat_string!("/hljs/css/", theme, ".min.css")
+        match custom_source(&theme, HljsThemeSource::clone) {
+            Some(HljsThemeSource::Url(url)) => url,
+            Some(HljsThemeSource::Css(_)) => concat_string!("/hljs/css/custom/", theme, ".css"),
+            None => concat_string!("/hljs/css/", theme, ".min.css"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::Path;
+
+    #[test]
+    fn every_theme_maps_to_its_stylesheet() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("static");
+        for theme in HljsTheme::all() {
+            let url = HljsTheme::to_url(theme.to_string());
+            let file = dir.join(url.strip_prefix("/hljs/").unwrap());
+            assert!(file.is_file(), "HljsTheme::{theme:?} => {url} is missing");
+            for asset in theme.assets() {
+                assert!(
+                    dir.join("css").join(&asset).is_file(),
+                    "HljsTheme::{theme:?} => {asset} is missing"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_theme_has_metadata() {
+        for theme in HljsTheme::all() {
+            assert!(theme.background().is_some(), "HljsTheme::{theme:?}");
+            assert!(theme.foreground().is_some(), "HljsTheme::{theme:?}");
+            assert!(theme.contrast_audit().is_some(), "HljsTheme::{theme:?}");
+        }
+    }
+
+    #[test]
+    fn custom_themes_are_registered_once_by_name() {
+        let source = |url: &str| HljsThemeSource::Url(url.to_string());
+        let first = HljsTheme::register_custom("test-custom", source("/first.css")).unwrap();
+        let again = HljsTheme::register_custom("test-custom", source("/again.css")).unwrap();
+        assert_eq!(first, again);
+        assert_eq!(HljsTheme::from_str("test-custom"), Ok(first));
+        assert_eq!(HljsTheme::to_url(first.to_string()), "/again.css");
+
+        for name in ["github", "Test Custom", ""] {
+            assert_eq!(
+                HljsTheme::register_custom(name, source("/invalid.css")),
+                Err(HljsError::CustomTheme {
+                    value: name.to_string()
+                }),
+            );
+        }
+    }
+
+    #[test]
+    fn contrast_of_url_themes_is_not_checked() {
+        let source = HljsThemeSource::Url("/unaudited.css".to_string());
+        let theme = HljsTheme::register_custom("test-unaudited", source).unwrap();
+        assert_eq!(
+            theme.check_contrast(),
+            Err(HljsError::Unaudited {
+                value: "test-unaudited".to_string()
+            })
+        );
+        for theme in HljsTheme::accessible_themes(WcagLevel::AA) {
+            assert_eq!(theme.check_contrast(), Ok(()), "HljsTheme::{theme:?}");
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn former_theme_names_are_still_accepted() {
+        assert_eq!(HljsTheme::Railcasts, HljsTheme::Railscasts);
+        assert_eq!(HljsTheme::TokioNightDark, HljsTheme::TokyoNightDark);
+        assert_eq!(
+            HljsTheme::from_str("shapes-of-purple"),
+            Ok(HljsTheme::ShadesOfPurple)
+        );
+        assert_eq!(HljsTheme::ShadesOfPurple.to_string(), "shades-of-purple");
+        assert!(HljsTheme::register_custom(
+            "tokio-night-light",
+            HljsThemeSource::Css(String::new())
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn unknown_themes_are_rejected_when_deserialized() {
+        use serde::de::value::{Error, StrDeserializer};
+
+        let deserialize = |s| HljsTheme::deserialize(StrDeserializer::<Error>::new(s));
+        assert_eq!(deserialize("github").ok(), Some(HljsTheme::Github));
+        assert_eq!(
+            deserialize("githib").unwrap_err().to_string(),
+            "unknown theme \"githib\", did you mean \"github\"?"
+        );
+        assert_eq!(
+            deserialize("zzz").unwrap_err().to_string(),
+            "unknown theme \"zzz\", the available themes are listed by HljsTheme::all() and \
+            shown in the /hljs/gallery page when hljs.gallery is enabled"
+        );
     }
 }
@@ -0,0 +1,92 @@
+//! Add a new component to let readers choose the theme of the code snippets.
+
+use pagetop::prelude::*;
+
+use crate::context::PARAM_HLJS_SWITCHER;
+use crate::HljsContext;
+use crate::HljsTheme;
+use crate::LOCALES_HLJS;
+
+#[derive(AutoDefault)]
+/// Component to let readers choose the theme used to display the code snippets on the page.
+///
+/// The new theme is applied immediately and persisted in a `hljs-theme` cookie, which takes
+/// precedence over [`set_hljs_theme()`](crate::HljsContext::set_hljs_theme) and
+/// [`set_hljs_dark_theme()`](crate::HljsContext::set_hljs_dark_theme) on the next pages rendering a
+/// `ThemeSwitcher`. Pages without a `ThemeSwitcher` keep the theme of their context.
+pub struct ThemeSwitcher {
+    id: OptionId,
+    themes: Vec<HljsTheme>,
+}
+
+impl ComponentTrait for ThemeSwitcher {
+    fn new() -> Self {
+        ThemeSwitcher::default()
+    }
+
+    fn id(&self) -> Option<String> {
+        self.id.get()
+    }
+
+    fn setup_before_prepare(&mut self, cx: &mut Context) {
+        cx.set_param::<bool>(PARAM_HLJS_SWITCHER, &true);
+    }
+
+    fn prepare_component(&self, cx: &mut Context) -> PrepareMarkup {
+        let id = cx.required_id::<Self>(self.id());
+        let current = cx.hljs_theme();
+        PrepareMarkup::With(html! {
+            div class="hljs-theme-switcher" {
+                label for=(id) {
+                    (L10n::t("hljs_theme", &LOCALES_HLJS).using(cx.langid()).unwrap_or_default())
+                }
+                select id=(id) data-hljs-theme-switcher {
+                    @for theme in self.themes() {
+                        option
+                            value=(theme.to_string())
+                            data-hljs-url=(HljsTheme::to_url(theme.to_string()))
+                            selected[theme == current]
+                        {
+                            (theme.display_name())
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl ThemeSwitcher {
+    // ThemeSwitcher BUILDER.
+
+    #[fn_builder]
+    pub fn set_id(&mut self, id: impl Into<String>) -> &mut Self {
+        self.id.alter_value(id);
+        self
+    }
+
+    /// Add a new theme to choose from. If no themes are added, all of them are listed.
+    #[fn_builder]
+    pub fn add_theme(&mut self, theme: HljsTheme) -> &mut Self {
+        if !self.themes.contains(&theme) {
+            self.themes.push(theme);
+        }
+        self
+    }
+
+    // ThemeSwitcher GETTERS.
+
+    /// Returns the themes to choose from, only those reaching the WCAG AA contrast level if the
+    /// [`accessible_only`](crate::config::Hljs#structfield.accessible_only) setting is enabled.
+    pub fn themes(&self) -> Vec<HljsTheme> {
+        let themes = if self.themes.is_empty() {
+            HljsTheme::all()
+        } else {
+            self.themes.clone()
+        };
+        themes
+            .into_iter()
+            .filter(|theme| theme.is_allowed())
+            .collect()
+    }
+}
@@ -0,0 +1,186 @@
+use crate::error::{self, HljsError};
+
+use serde::{Deserialize, Deserializer};
+
+use std::str::FromStr;
+
+/// Token colors that can be overridden on top of any theme.
+///
+/// Tokens are defined as *PascalCase* enums in the code and correspond to *kebab-case* string
+/// identifiers, used as the names of the CSS custom properties `--hljs-<token>`.
+///
+/// ```rust
+/// use pagetop_hljs::HljsToken;
+///
+/// assert_eq!(HljsToken::BuiltIn.to_string(), "built-in");
+/// assert_eq!(HljsToken::BuiltIn.selectors(), &[".hljs-built_in"]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum HljsToken {
+    /// Background of the code.
+    Background,
+    /// Default color of the code.
+    Text,
+    Keyword,
+    BuiltIn,
+    Type,
+    Literal,
+    Number,
+    String,
+    Regexp,
+    Comment,
+    Title,
+    Function,
+    Attribute,
+    Variable,
+    Tag,
+    Meta,
+    Symbol,
+    Addition,
+    Deletion,
+}
+
+impl HljsToken {
+    /// Returns all the tokens.
+    pub fn all() -> Vec<HljsToken> {
+        use HljsToken::*;
+        vec![
+            Background, Text, Keyword, BuiltIn, Type, Literal, Number, String, Regexp, Comment,
+            Title, Function, Attribute, Variable, Tag, Meta, Symbol, Addition, Deletion,
+        ]
+    }
+
+    /// Returns the highlight.js class selectors colored by the token.
+    pub fn selectors(&self) -> &'static [&'static str] {
+        match self {
+            HljsToken::Background | HljsToken::Text => &[".hljs"],
+            HljsToken::Keyword => &[".hljs-keyword", ".hljs-selector-tag"],
+            HljsToken::BuiltIn => &[".hljs-built_in"],
+            HljsToken::Type => &[".hljs-type", ".hljs-title.class_"],
+            HljsToken::Literal => &[".hljs-literal"],
+            HljsToken::Number => &[".hljs-number"],
+            HljsToken::String => &[".hljs-string"],
+            HljsToken::Regexp => &[".hljs-regexp"],
+            HljsToken::Comment => &[".hljs-comment", ".hljs-quote"],
+            HljsToken::Title => &[".hljs-title", ".hljs-section"],
+            HljsToken::Function => &[".hljs-title.function_"],
+            HljsToken::Attribute => &[".hljs-attr", ".hljs-attribute"],
+            HljsToken::Variable => &[".hljs-variable", ".hljs-template-variable"],
+            HljsToken::Tag => &[".hljs-tag", ".hljs-name"],
+            HljsToken::Meta => &[".hljs-meta"],
+            HljsToken::Symbol => &[".hljs-symbol", ".hljs-bullet"],
+            HljsToken::Addition => &[".hljs-addition"],
+            HljsToken::Deletion => &[".hljs-deletion"],
+        }
+    }
+
+    // Returns the CSS that applies the custom properties of the given token colors.
+    pub(crate) fn overrides_css(colors: &[(HljsToken, String)]) -> String {
+        let mut properties = String::new();
+        let mut rules = String::new();
+        for (token, color) in colors {
+            let name = token.to_string();
+            properties.push_str(&format!("--hljs-{name}:{color};"));
+            let property = match token {
+                HljsToken::Background => "background",
+                _ => "color",
+            };
+            let selectors: Vec<String> = match token {
+                // Higher specificity than the theme rules, like `.hljs-meta .hljs-keyword`.
+                HljsToken::Background | HljsToken::Text => vec![String::from(":root .hljs")],
+                _ => token
+                    .selectors()
+                    .iter()
+                    .map(|selector| format!(":root .hljs {selector}"))
+                    .collect(),
+            };
+            rules.push_str(&format!(
+                "{}{{{property}:var(--hljs-{name})}}",
+                selectors.join(",")
+            ));
+        }
+        format!(":root{{{properties}}}{rules}")
+    }
+
+    // Returns `true` if the color can be safely used as the value of a CSS property.
+    pub(crate) fn is_valid_color(color: &str) -> bool {
+        !color.is_empty()
+            && color
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || " #(),.%-+/".contains(c))
+    }
+
+    // Returns the URL of the stylesheet with the given token colors, served by the package.
+    pub(crate) fn overrides_url(colors: &[(HljsToken, String)]) -> String {
+        let query: Vec<String> = colors
+            .iter()
+            .map(|(token, color)| {
+                let mut value = String::new();
+                for byte in color.trim().bytes() {
+                    match byte {
+                        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' => {
+                            value.push(byte as char)
+                        }
+                        _ => value.push_str(&format!("%{byte:02X}")),
+                    }
+                }
+                format!("{}={value}", token.to_string())
+            })
+            .collect();
+        format!("/hljs/css/tokens.css?{}", query.join("&"))
+    }
+}
+
+impl ToString for HljsToken {
+    fn to_string(&self) -> String {
+        String::from(match self {
+            HljsToken::Background => "background",
+            HljsToken::Text => "text",
+            HljsToken::Keyword => "keyword",
+            HljsToken::BuiltIn => "built-in",
+            HljsToken::Type => "type",
+            HljsToken::Literal => "literal",
+            HljsToken::Number => "number",
+            HljsToken::String => "string",
+            HljsToken::Regexp => "regexp",
+            HljsToken::Comment => "comment",
+            HljsToken::Title => "title",
+            HljsToken::Function => "function",
+            HljsToken::Attribute => "attribute",
+            HljsToken::Variable => "variable",
+            HljsToken::Tag => "tag",
+            HljsToken::Meta => "meta",
+            HljsToken::Symbol => "symbol",
+            HljsToken::Addition => "addition",
+            HljsToken::Deletion => "deletion",
+        })
+    }
+}
+
+impl FromStr for HljsToken {
+    type Err = HljsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = HljsToken::all();
+        match tokens.iter().find(|token| token.to_string() == s) {
+            Some(token) => Ok(*token),
+            None => {
+                let names: Vec<String> = tokens.iter().map(HljsToken::to_string).collect();
+                Err(HljsError::Token {
+                    value: s.to_string(),
+                    suggestions: error::suggestions(s, names.iter().map(String::as_str)),
+                })
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HljsToken {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        HljsToken::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
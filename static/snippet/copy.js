@@ -0,0 +1,17 @@
+// Copies the code of a PageTop HighlightJS snippet to the clipboard.
+document.addEventListener('click', function (event) {
+    const button = event.target.closest('.hljs-copy');
+    if (!button || !navigator.clipboard) {
+        return;
+    }
+    const code = button.closest('.hljs-snippet').querySelector('.hljs-code code');
+    navigator.clipboard.writeText(code.innerText).then(function () {
+        button.textContent = button.dataset.copied;
+        button.classList.add('hljs-copied');
+        clearTimeout(button.hljsTimeout);
+        button.hljsTimeout = setTimeout(function () {
+            button.textContent = button.dataset.copy;
+            button.classList.remove('hljs-copied');
+        }, 2000);
+    });
+});
@@ -0,0 +1,41 @@
+// Follows the data-theme attribute of the html element to switch between the light and dark
+// highlight.js themes, or the color scheme preferred by the reader if there is no attribute.
+(function () {
+    const media = new Map();
+
+    function apply() {
+        const theme = document.documentElement.dataset.theme;
+        Array.from(document.styleSheets).forEach(function (sheet) {
+            const scheme = /\/hljs\/css\/(light|dark)\//.exec(sheet.href || '');
+            if (!scheme) {
+                return;
+            }
+            let rules;
+            try {
+                rules = sheet.cssRules;
+            } catch (e) {
+                return;
+            }
+            // The theme is enclosed in a media rule, or imported for themes linked by URL.
+            Array.from(rules).forEach(function (rule) {
+                if (!(rule instanceof CSSMediaRule) && !(rule instanceof CSSImportRule)) {
+                    return;
+                }
+                if (!media.has(rule)) {
+                    media.set(rule, rule.media.mediaText);
+                }
+                if (theme === 'dark' || theme === 'light') {
+                    rule.media.mediaText = theme === scheme[1] ? 'all' : 'not all';
+                } else {
+                    rule.media.mediaText = media.get(rule);
+                }
+            });
+        });
+    }
+
+    new MutationObserver(apply).observe(document.documentElement, {
+        attributes: true,
+        attributeFilter: ['data-theme'],
+    });
+    apply();
+})();
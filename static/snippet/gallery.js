@@ -0,0 +1,8 @@
+// Shows the gallery of PageTop HighlightJS themes in the language chosen by the reader.
+(function () {
+    document.querySelectorAll('[data-hljs-gallery-lang]').forEach(function (select) {
+        select.addEventListener('change', function () {
+            select.form.submit();
+        });
+    });
+})();
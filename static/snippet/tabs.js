@@ -0,0 +1,94 @@
+// Switches the tabs of PageTop HighlightJS snippet groups.
+(function () {
+    const STORAGE_PREFIX = 'hljs-tab:';
+    const COOKIE_PREFIX = 'hljs-tab-';
+
+    function select(tab) {
+        const tablist = tab.closest('.hljs-tablist');
+        tablist.querySelectorAll('[role="tab"]').forEach(function (item) {
+            const active = item === tab;
+            item.classList.toggle('hljs-active', active);
+            item.setAttribute('aria-selected', active);
+            item.tabIndex = active ? 0 : -1;
+            document.getElementById(item.getAttribute('aria-controls'))
+                .classList.toggle('hljs-active', active);
+        });
+    }
+
+    // Selects the tab with the given value in every group sharing the sync key.
+    function sync(key, value) {
+        document.querySelectorAll('.hljs-tabs[data-hljs-sync]').forEach(function (group) {
+            if (group.dataset.hljsSync !== key) {
+                return;
+            }
+            group.querySelectorAll('.hljs-tablist [role="tab"]').forEach(function (tab) {
+                if (tab.dataset.hljsValue === value && tab.closest('.hljs-tabs') === group) {
+                    select(tab);
+                }
+            });
+        });
+    }
+
+    function activate(tab) {
+        const group = tab.closest('.hljs-tabs');
+        const key = group.dataset.hljsSync;
+        if (!key) {
+            select(tab);
+            return;
+        }
+        const value = tab.dataset.hljsValue;
+        sync(key, value);
+        try {
+            localStorage.setItem(STORAGE_PREFIX + key, value);
+        } catch (e) {
+            // Storage may be disabled, the cookie still keeps the selection.
+        }
+        document.cookie = COOKIE_PREFIX + key + '=' + encodeURIComponent(value)
+            + '; path=/; max-age=31536000; SameSite=Lax';
+    }
+
+    document.addEventListener('click', function (event) {
+        const tab = event.target.closest('.hljs-tablist [role="tab"]');
+        if (tab) {
+            activate(tab);
+        }
+    });
+
+    document.addEventListener('keydown', function (event) {
+        const tab = event.target.closest('.hljs-tablist [role="tab"]');
+        if (!tab) {
+            return;
+        }
+        const tabs = Array.from(tab.closest('.hljs-tablist').querySelectorAll('[role="tab"]'));
+        const index = tabs.indexOf(tab);
+        let next = null;
+        switch (event.key) {
+            case 'ArrowLeft': next = tabs[(index - 1 + tabs.length) % tabs.length]; break;
+            case 'ArrowRight': next = tabs[(index + 1) % tabs.length]; break;
+            case 'Home': next = tabs[0]; break;
+            case 'End': next = tabs[tabs.length - 1]; break;
+        }
+        if (next) {
+            event.preventDefault();
+            activate(next);
+            next.focus();
+        }
+    });
+
+    // Restores the selection stored in previous visits.
+    const keys = new Set();
+    document.querySelectorAll('.hljs-tabs[data-hljs-sync]').forEach(function (group) {
+        keys.add(group.dataset.hljsSync);
+    });
+    keys.forEach(function (key) {
+        let value = null;
+        try {
+            value = localStorage.getItem(STORAGE_PREFIX + key);
+        } catch (e) {
+            return;
+        }
+        if (value) {
+            sync(key, value);
+        }
+    });
+})();
@@ -0,0 +1,45 @@
+// Applies the theme chosen by the reader with a PageTop HighlightJS theme switcher.
+(function () {
+    const COOKIE = 'hljs-theme';
+
+    function path(href) {
+        return href.split('?')[0];
+    }
+
+    function apply(select) {
+        const theme = select.value;
+        const url = select.selectedOptions[0].dataset.hljsUrl;
+        const urls = Array.from(select.options).map(function (option) {
+            return option.dataset.hljsUrl;
+        });
+        urls.push('/hljs/css/' + theme + '.min.css');
+        const link = Array.from(document.querySelectorAll('link[rel="stylesheet"]')).find(function (item) {
+            return urls.indexOf(path(item.getAttribute('href'))) !== -1
+                || /^\/hljs\/css\/[^/]+\.min\.css$/.test(path(item.getAttribute('href')));
+        });
+        if (link) {
+            link.setAttribute('href', url);
+        } else {
+            const created = document.createElement('link');
+            created.rel = 'stylesheet';
+            created.href = url;
+            document.head.appendChild(created);
+        }
+        // The chosen theme replaces the light and dark themes paired by color scheme.
+        document.querySelectorAll('link[rel="stylesheet"]').forEach(function (item) {
+            if (/^\/hljs\/css\/(light|dark)\//.test(path(item.getAttribute('href')))) {
+                item.remove();
+            }
+        });
+        document.querySelectorAll('select[data-hljs-theme-switcher]').forEach(function (item) {
+            item.value = theme;
+        });
+        document.cookie = COOKIE + '=' + encodeURIComponent(theme) + '; path=/; max-age=31536000; SameSite=Lax';
+    }
+
+    document.addEventListener('change', function (event) {
+        if (event.target.matches('select[data-hljs-theme-switcher]')) {
+            apply(event.target);
+        }
+    });
+})();
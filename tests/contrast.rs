@@ -0,0 +1,63 @@
+use pagetop_hljs::{HljsTheme, WcagLevel};
+
+#[test]
+fn contrast_ratios_are_in_range() {
+    for theme in HljsTheme::all() {
+        let Some(audit) = theme.contrast_audit() else {
+            continue;
+        };
+        for token in audit.tokens() {
+            assert!(
+                (1.0..=21.0).contains(&token.ratio()),
+                "{}: {} has a contrast ratio of {}",
+                theme.to_string(),
+                token.selector(),
+                token.ratio()
+            );
+        }
+    }
+}
+
+#[test]
+fn accessible_themes_pass_their_level() {
+    for level in [WcagLevel::AA, WcagLevel::AAA] {
+        let themes = HljsTheme::accessible_themes(level);
+        assert!(!themes.is_empty());
+        for theme in themes {
+            let audit = theme.contrast_audit().unwrap();
+            assert!(audit.failures(level).is_empty(), "{}", theme.to_string());
+            assert!(audit.min_ratio() >= level.min_ratio());
+            assert!(audit.level() >= Some(level));
+        }
+    }
+}
+
+#[test]
+fn aaa_themes_also_pass_aa() {
+    let aa = HljsTheme::accessible_themes(WcagLevel::AA);
+    for theme in HljsTheme::accessible_themes(WcagLevel::AAA) {
+        assert!(aa.contains(&theme), "{}", theme.to_string());
+    }
+}
+
+#[test]
+fn a11y_themes_are_accessible() {
+    assert_eq!(
+        HljsTheme::A11yDark.contrast_audit().unwrap().level(),
+        Some(WcagLevel::AAA)
+    );
+    assert_eq!(
+        HljsTheme::A11yLight.contrast_audit().unwrap().level(),
+        Some(WcagLevel::AA)
+    );
+}
+
+#[test]
+fn low_contrast_tokens_are_reported() {
+    let audit = HljsTheme::Zenburn.contrast_audit().unwrap();
+    assert!(!audit.passes(WcagLevel::AA));
+    assert!(audit
+        .failures(WcagLevel::AA)
+        .iter()
+        .all(|token| token.ratio() < WcagLevel::AA.min_ratio()));
+}
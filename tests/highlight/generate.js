@@ -0,0 +1,29 @@
+// Regenerates the expected markup of the server-side highlighting tests with the bundled
+// highlight.js library: `node tests/highlight/generate.js`.
+//
+// Each `<language>/<case>.txt` input is highlighted with the grammar of its directory into
+// `<language>/<case>.html`, the same way the *common* library highlights code in the browser.
+
+const fs = require('fs');
+const path = require('path');
+const vm = require('vm');
+
+const js = path.join(__dirname, '..', '..', 'static', 'js');
+const context = {};
+vm.createContext(context);
+vm.runInContext(
+  fs.readFileSync(path.join(js, 'highlight.min.js'), 'utf8') + ';this.hljs = hljs;',
+  context,
+);
+
+for (const language of fs.readdirSync(__dirname)) {
+  const dir = path.join(__dirname, language);
+  if (!fs.statSync(dir).isDirectory()) {
+    continue;
+  }
+  for (const input of fs.readdirSync(dir).filter((file) => file.endsWith('.txt'))) {
+    const code = fs.readFileSync(path.join(dir, input), 'utf8');
+    const html = context.hljs.highlight(code, { language, ignoreIllegals: true }).value;
+    fs.writeFileSync(path.join(dir, input.replace(/\.txt$/, '.html')), html);
+  }
+}